@@ -299,6 +299,12 @@ pub struct CompileArgs {
     #[arg(long = "ppi", default_value_t = 144.0)]
     pub ppi: f32,
 
+    /// A directory (relative to the HTML output) to which images are written
+    /// in HTML export. When unspecified, images are embedded into the HTML
+    /// file instead.
+    #[arg(long = "html-image-dir", value_name = "DIR")]
+    pub html_image_dir: Option<String>,
+
     /// File path to which a Makefile with the current compilation's
     /// dependencies will be written.
     #[clap(long = "make-deps", value_name = "PATH", hide = true)]
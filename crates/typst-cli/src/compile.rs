@@ -15,7 +15,7 @@ use typst::diag::{
 use typst::foundations::{Datetime, Smart};
 use typst::layout::{Page, PageRanges, PagedDocument};
 use typst::syntax::{FileId, Lines, Span};
-use typst_html::HtmlDocument;
+use typst_html::{HtmlDocument, HtmlOptions};
 use typst_pdf::{PdfOptions, PdfStandards, Timestamp};
 
 use crate::args::{
@@ -74,6 +74,8 @@ pub struct CompileConfig {
     pub deps_format: DepsFormat,
     /// The PPI (pixels per inch) to use for PNG export.
     pub ppi: f32,
+    /// Settings for HTML export.
+    pub html_options: HtmlOptions,
     /// The export cache for images, used for caching output files in `typst
     /// watch` sessions with images.
     pub export_cache: ExportCache,
@@ -197,6 +199,14 @@ impl CompileConfig {
             );
         }
 
+        if args.html_image_dir.is_some() && matches!(output, Output::Stdout) {
+            bail!("cannot write images to separate files when writing HTML to stdout");
+        }
+
+        let html_options = HtmlOptions {
+            image_dir: args.html_image_dir.as_deref().map(Into::into),
+        };
+
         match (&output, &deps, watch) {
             (Output::Stdout, _, Some(_)) => {
                 bail!("cannot write document to stdout in watch mode");
@@ -221,6 +231,7 @@ impl CompileConfig {
             tagged,
             creation_timestamp: args.world.creation_timestamp,
             ppi: args.ppi,
+            html_options,
             diagnostic_format: args.process.diagnostic_format,
             open: args.open.clone(),
             export_cache: ExportCache::new(),
@@ -318,7 +329,26 @@ fn compile_and_export(
 
 /// Export to HTML.
 fn export_html(document: &HtmlDocument, config: &CompileConfig) -> SourceResult<()> {
-    let html = typst_html::html(document)?;
+    let output = typst_html::html_with_options(document, &config.html_options)?;
+    let html = output.html;
+
+    if let Output::Path(path) = &config.output {
+        let dir = path.parent().unwrap_or(Path::new(""));
+        for asset in &output.assets {
+            let asset_path = dir.join(asset.path.as_str());
+            if let Some(parent) = asset_path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|err| {
+                        eco_format!("failed to create image directory ({err})")
+                    })
+                    .at(Span::detached())?;
+            }
+            std::fs::write(&asset_path, &asset.data)
+                .map_err(|err| eco_format!("failed to write image file ({err})"))
+                .at(Span::detached())?;
+        }
+    }
+
     let result = config.output.write(html.as_bytes());
 
     #[cfg(feature = "http-server")]
@@ -1,23 +1,45 @@
+use comemo::Tracked;
 use ecow::eco_format;
 use typst_library::diag::{At, Hint, SourceResult, Trace, Tracepoint, bail};
-use typst_library::foundations::{Dict, Value};
+use typst_library::engine::Engine;
+use typst_library::foundations::{Binding, Context, Dict, Value};
 use typst_syntax::ast::{self, AstNode};
 
 use crate::{Eval, Vm, call_method_access, is_accessor_method};
 
 /// Access an expression mutably.
-pub(crate) trait Access {
+pub(crate) trait Access: Sized {
     /// Access the expression's evaluated value mutably.
-    fn access<'a>(self, vm: &'a mut Vm) -> SourceResult<&'a mut Value>;
+    fn access<'a>(self, vm: &'a mut Vm) -> SourceResult<&'a mut Value> {
+        self.place(vm).map(|place| place.value)
+    }
+
+    /// Access the expression's evaluated value mutably, while keeping the
+    /// engine and context usable.
+    fn place<'a, 'v>(self, vm: &'a mut Vm<'v>) -> SourceResult<Place<'a, 'v>>;
+}
+
+/// A mutably accessed value together with the parts of the VM that are not
+/// borrowed by it.
+///
+/// This allows methods that call back into user code to mutate their target
+/// in place.
+pub(crate) struct Place<'a, 'v> {
+    /// The accessed value.
+    pub value: &'a mut Value,
+    /// The underlying virtual typesetter.
+    pub engine: &'a mut Engine<'v>,
+    /// The context of the access.
+    pub context: Tracked<'v, Context<'v>>,
 }
 
 impl Access for ast::Expr<'_> {
-    fn access<'a>(self, vm: &'a mut Vm) -> SourceResult<&'a mut Value> {
+    fn place<'a, 'v>(self, vm: &'a mut Vm<'v>) -> SourceResult<Place<'a, 'v>> {
         match self {
-            Self::Ident(v) => v.access(vm),
-            Self::Parenthesized(v) => v.access(vm),
-            Self::FieldAccess(v) => v.access(vm),
-            Self::FuncCall(v) => v.access(vm),
+            Self::Ident(v) => v.place(vm),
+            Self::Parenthesized(v) => v.place(vm),
+            Self::FieldAccess(v) => v.place(vm),
+            Self::FuncCall(v) => v.place(vm),
             _ => {
                 let _ = self.eval(vm)?;
                 bail!(self.span(), "cannot mutate a temporary value");
@@ -27,44 +49,50 @@ impl Access for ast::Expr<'_> {
 }
 
 impl Access for ast::Ident<'_> {
-    fn access<'a>(self, vm: &'a mut Vm) -> SourceResult<&'a mut Value> {
+    fn place<'a, 'v>(self, vm: &'a mut Vm<'v>) -> SourceResult<Place<'a, 'v>> {
         let span = self.span();
         if vm.inspected == Some(span)
             && let Ok(binding) = vm.scopes.get(&self)
         {
             vm.trace(binding.read().clone());
         }
-        vm.scopes
+        let Vm { scopes, engine, context, .. } = vm;
+        let value = scopes
             .get_mut(&self)
             .and_then(|b| b.write().map_err(Into::into))
-            .at(span)
+            .at(span)?;
+        Ok(Place { value, engine, context: *context })
     }
 }
 
 impl Access for ast::Parenthesized<'_> {
-    fn access<'a>(self, vm: &'a mut Vm) -> SourceResult<&'a mut Value> {
-        self.expr().access(vm)
+    fn place<'a, 'v>(self, vm: &'a mut Vm<'v>) -> SourceResult<Place<'a, 'v>> {
+        self.expr().place(vm)
     }
 }
 
 impl Access for ast::FieldAccess<'_> {
-    fn access<'a>(self, vm: &'a mut Vm) -> SourceResult<&'a mut Value> {
-        access_dict(vm, self)?.at_mut(self.field().get()).at(self.span())
+    fn place<'a, 'v>(self, vm: &'a mut Vm<'v>) -> SourceResult<Place<'a, 'v>> {
+        let place = self.target().place(vm)?;
+        let dict = as_dict(place.value, self)?;
+        let value = dict.at_mut(self.field().get()).at(self.span())?;
+        Ok(Place { value, ..place })
     }
 }
 
 impl Access for ast::FuncCall<'_> {
-    fn access<'a>(self, vm: &'a mut Vm) -> SourceResult<&'a mut Value> {
+    fn place<'a, 'v>(self, vm: &'a mut Vm<'v>) -> SourceResult<Place<'a, 'v>> {
         if let ast::Expr::FieldAccess(access) = self.callee() {
             let method = access.field();
             if is_accessor_method(&method) {
                 let span = self.span();
                 let world = vm.world();
                 let args = self.args().eval(vm)?.spanned(span);
-                let value = access.target().access(vm)?;
-                let result = call_method_access(value, &method, args, span);
+                let place = access.target().place(vm)?;
+                let result = call_method_access(place.value, &method, args, span);
                 let point = || Tracepoint::Call(Some(method.get().clone()));
-                return result.trace(world, point, span);
+                let value = result.trace(world, point, span)?;
+                return Ok(Place { value, ..place });
             }
         }
 
@@ -73,11 +101,35 @@ impl Access for ast::FuncCall<'_> {
     }
 }
 
+/// Looks up the value that accessing an expression would yield, without
+/// evaluating it.
+///
+/// Returns `None` for expressions that can only be resolved by evaluation.
+pub(crate) fn peek<'a>(expr: ast::Expr, vm: &'a Vm) -> Option<&'a Value> {
+    match expr {
+        ast::Expr::Ident(v) => vm.scopes.get(&v).ok().map(Binding::read),
+        ast::Expr::Parenthesized(v) => peek(v.expr(), vm),
+        ast::Expr::FieldAccess(v) => match peek(v.target(), vm)? {
+            Value::Dict(dict) => dict.get(v.field().get()).ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 pub(crate) fn access_dict<'a>(
     vm: &'a mut Vm,
     access: ast::FieldAccess,
 ) -> SourceResult<&'a mut Dict> {
-    match access.target().access(vm)? {
+    as_dict(access.target().access(vm)?, access)
+}
+
+/// Ensures that the target of a field access is a dictionary.
+fn as_dict<'a>(
+    value: &'a mut Value,
+    access: ast::FieldAccess,
+) -> SourceResult<&'a mut Dict> {
+    match value {
         Value::Dict(dict) => Ok(dict),
         value => {
             let ty = value.ty();
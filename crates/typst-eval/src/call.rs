@@ -17,7 +17,9 @@ use typst_syntax::ast::{self, AstNode, Ident};
 use typst_syntax::{Span, Spanned, SyntaxNode};
 use typst_utils::LazyHash;
 
-use crate::{Access, Eval, FlowEvent, Route, Vm, call_method_mut, is_mutating_method};
+use crate::{
+    Access, Eval, FlowEvent, Place, Route, Vm, call_method_mut, is_mutating_method, peek,
+};
 
 impl Eval for ast::FuncCall<'_> {
     type Output = Value;
@@ -307,8 +309,32 @@ fn eval_field_call(
     span: Span,
     vm: &mut Vm,
 ) -> SourceResult<FieldCall> {
+    // Unlike the other mutating methods, `update` also exists on counters and
+    // states, which must stay usable when they are captured and thus
+    // read-only. We therefore only access its target mutably if it is a
+    // dictionary. If the target can't be looked up without evaluating it, we
+    // evaluate it first and access it again only if it is a dictionary, so
+    // that updating a temporary dictionary fails like the other mutating
+    // methods do.
+    let mut evaluated = None;
+    let mutating = if is_mutating_method(&field) {
+        true
+    } else if field.as_str() == "update" {
+        match peek(target_expr, vm) {
+            Some(value) => matches!(value, Value::Dict(_)),
+            None => {
+                let value = target_expr.eval(vm)?;
+                let is_dict = matches!(value, Value::Dict(_));
+                evaluated = Some(value);
+                is_dict
+            }
+        }
+    } else {
+        false
+    };
+
     // Evaluate the field-call's target and overall arguments.
-    let (target, mut args) = if is_mutating_method(&field) {
+    let (target, mut args) = if mutating {
         // If `field` looks like a mutating method, we evaluate the arguments first,
         // because `target_expr.access(vm)` mutably borrows the `vm`, so that we can't
         // evaluate the arguments after it.
@@ -318,17 +344,25 @@ fn eval_field_call(
         // a temporary which we disallow mutation on (returning an error).
         // Theoretically this could be observed if a method matching `is_mutating_method`
         // was added to some type in the future and we didn't update this function.
-        match target_expr.access(vm)? {
+        let world = vm.world();
+        match target_expr.place(vm)? {
             // Only arrays and dictionaries have mutable methods.
-            target @ (Value::Array(_) | Value::Dict(_)) => {
-                let value = call_method_mut(target, &field, args, span);
+            Place {
+                value: target @ (Value::Array(_) | Value::Dict(_)),
+                engine,
+                context,
+            } => {
+                let value = call_method_mut(engine, context, target, &field, args, span);
                 let point = || Tracepoint::Call(Some(field.get().clone()));
-                return Ok(FieldCall::Resolved(value.trace(vm.world(), point, span)?));
+                return Ok(FieldCall::Resolved(value.trace(world, point, span)?));
             }
-            target => (target.clone(), args),
+            Place { value: target, .. } => (target.clone(), args),
         }
     } else {
-        let target = target_expr.eval(vm)?;
+        let target = match evaluated {
+            Some(target) => target,
+            None => target_expr.eval(vm)?,
+        };
         let args = args.eval(vm)?.spanned(span);
         (target, args)
     };
@@ -1,13 +1,18 @@
 //! Handles special built-in methods on values.
 
+use comemo::Tracked;
 use ecow::{EcoString, eco_format};
 use typst_library::diag::{At, SourceResult};
-use typst_library::foundations::{Args, Str, Type, Value};
+use typst_library::engine::Engine;
+use typst_library::foundations::{Args, Context, Str, Type, Value};
 use typst_syntax::Span;
 
 /// Whether a specific method is mutating.
 pub(crate) fn is_mutating_method(method: &str) -> bool {
-    matches!(method, "push" | "pop" | "insert" | "remove")
+    matches!(
+        method,
+        "push" | "pop" | "insert" | "remove" | "move-item" | "swap" | "retain"
+    )
 }
 
 /// Whether a specific method is an accessor.
@@ -17,6 +22,8 @@ pub(crate) fn is_accessor_method(method: &str) -> bool {
 
 /// Call a mutating method on a value.
 pub(crate) fn call_method_mut(
+    engine: &mut Engine,
+    context: Tracked<Context>,
     value: &mut Value,
     method: &str,
     mut args: Args,
@@ -38,6 +45,10 @@ pub(crate) fn call_method_mut(
                     .remove(args.expect("index")?, args.named("default")?)
                     .at(span)?
             }
+            "move-item" => {
+                array.move_item(args.expect("from")?, args.expect("to")?).at(span)?
+            }
+            "swap" => array.swap(args.expect("i")?, args.expect("j")?).at(span)?,
             _ => return missing(),
         },
 
@@ -47,6 +58,15 @@ pub(crate) fn call_method_mut(
                 output =
                     dict.remove(args.expect("key")?, args.named("default")?).at(span)?
             }
+            "retain" => dict.retain(engine, context, args.expect("test")?)?,
+            "update" => dict.update(
+                engine,
+                context,
+                span,
+                args.expect("key")?,
+                args.expect("updater")?,
+                args.named("default")?,
+            )?,
             _ => return missing(),
         },
 
@@ -18,18 +18,17 @@ pub const fn is_valid_in_attribute_name(c: char) -> bool {
     }
 }
 
-/// Check whether a character can be an used in an attribute value without
-/// escaping.
+/// Check whether a character can be an used in an attribute value delimited by
+/// the given quotation mark without escaping.
 ///
 /// See <https://html.spec.whatwg.org/multipage/syntax.html#attributes-2>
-pub const fn is_valid_in_attribute_value(c: char) -> bool {
+pub const fn is_valid_in_attribute_value(c: char, quote: char) -> bool {
     match c {
         // Ampersands are sometimes legal (i.e. when they are not _ambiguous
         // ampersands_) but it is not worth the trouble to check for that.
         '&' => false,
-        // Quotation marks are not allowed in double-quote-delimited attribute
-        // values.
-        '"' => false,
+        // The delimiting quotation mark is not allowed in the attribute value.
+        c if c == quote => false,
         // All other text characters are allowed.
         c => is_w3c_text_char(c),
     }
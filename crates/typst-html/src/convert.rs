@@ -109,6 +109,15 @@ fn handle(
         // Nothing to do for zero-sized spacing. This is sometimes used to
         // destruct spaces, e.g. in footnotes. See [`HElem::hole`].
     } else if let Some(elem) = child.to_packed::<LinebreakElem>() {
+        // Browsers can't justify a single line, so justified line breaks
+        // degrade to regular ones.
+        if elem.justify.get(styles) {
+            converter.engine.sink.warn(warning!(
+                elem.span(),
+                "justified line breaks are not supported in HTML export";
+                hint: "the line break is exported as a regular `<br>` element"
+            ));
+        }
         converter.push(HtmlElement::new(tag::br).spanned(elem.span()));
     } else if let Some(elem) = child.to_packed::<SmartQuoteElem>() {
         let double = elem.double.get(styles);
@@ -135,7 +144,10 @@ fn handle(
             styles.chain(&style),
             Region::new(Size::splat(Abs::inf()), Axes::splat(false)),
         )?;
-        converter.push(HtmlFrame::new(frame, styles, elem.span()));
+        let mut frame = HtmlFrame::new(frame, styles, elem.span());
+        frame.title = elem.title.get_cloned(styles);
+        frame.description = elem.description.get_cloned(styles);
+        converter.push(frame);
     } else {
         converter.engine.sink.warn(warning!(
             child.span(),
@@ -153,8 +165,12 @@ fn handle_html_elem(
     styles: StyleChain,
 ) -> SourceResult<()> {
     // See the docs of `HtmlElem::role` for why we filter out roles for `<p>`
-    // elements.
-    let role = styles.get_cloned(HtmlElem::role).filter(|_| elem.tag != tag::p);
+    // elements. Roles are also not applied to `<template>` elements, which
+    // aren't rendered, nor passed on to their contents.
+    let is_template = elem.tag == tag::template;
+    let role = styles
+        .get_cloned(HtmlElem::role)
+        .filter(|_| elem.tag != tag::p && !is_template);
 
     let mut children = EcoVec::new();
     if let Some(body) = elem.body.get_ref(styles) {
@@ -178,7 +194,27 @@ fn handle_html_elem(
             styles
         };
 
-        if tag::is_block_by_default(elem.tag) {
+        let template;
+        let styles = if is_template {
+            template =
+                [HtmlElem::role.set(None).wrap(), HtmlElem::in_template.set(true).wrap()];
+            styles.chain(&template)
+        } else {
+            styles
+        };
+
+        // The children of a `<foreignObject>` are HTML again.
+        let foreign;
+        let styles = if tag::is_foreign_root(elem.tag) || elem.tag == tag::foreignObject {
+            foreign = HtmlElem::in_foreign.set(elem.tag != tag::foreignObject).wrap();
+            styles.chain(&foreign)
+        } else {
+            styles
+        };
+
+        // Template contents form a separate document fragment, so we treat
+        // them like the contents of a block-level element.
+        if tag::is_block_by_default(elem.tag) || is_template {
             children = html_block_fragment(
                 converter.engine,
                 body,
@@ -204,6 +240,23 @@ fn handle_html_elem(
         }
     }
 
+    // An `id` given to the element itself takes precedence over one from a set
+    // rule. As this is likely unintended, we warn about it.
+    if let Some(id) =
+        elem.attrs.as_option().as_ref().and_then(|attrs| attrs.get(attr::id))
+    {
+        let styled = styles.get_cloned(HtmlElem::attrs);
+        if let Some(other) = styled.get(attr::id)
+            && other != id
+        {
+            converter.engine.sink.warn(warning!(
+                elem.span(),
+                "element has conflicting `id` attributes";
+                hint: "the explicitly given id `{id}` takes precedence over `{other}`"
+            ));
+        }
+    }
+
     let mut attrs = elem.attrs.get_cloned(styles);
     if let Some(role) = role {
         attrs.push(attr::role, role);
@@ -216,6 +269,7 @@ fn handle_html_elem(
         parent: elem.parent,
         span: elem.span(),
         pre_span: false,
+        image: elem.image.clone(),
     });
 
     Ok(())
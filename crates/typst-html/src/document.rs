@@ -4,7 +4,7 @@ use comemo::{Tracked, TrackedMut};
 use ecow::{EcoVec, eco_vec};
 use rustc_hash::FxHashSet;
 use typst_library::World;
-use typst_library::diag::{SourceResult, bail};
+use typst_library::diag::{SourceResult, bail, warning};
 use typst_library::engine::{Engine, Route, Sink, Traced};
 use typst_library::foundations::{Content, StyleChain, Styles};
 use typst_library::introspection::{
@@ -112,7 +112,12 @@ fn html_document_impl(
 
     let mut link_targets = FxHashSet::default();
     let mut introspector = introspect_html(introspectibles, &mut link_targets);
+    let custom_html = matches!(output, OutputKind::Html(_));
     let mut root = root_element(output, &info);
+    if custom_html && let Some(head) = find_head(&mut root) {
+        dedup_head(&mut engine, head);
+        normalize_charset(&mut engine, head);
+    }
     crate::link::identify_link_targets(&mut root, &mut introspector, link_targets);
 
     Ok(HtmlDocument { info, root, introspector })
@@ -174,12 +179,59 @@ fn root_element(output: OutputKind, info: &DocumentInfo) -> HtmlElement {
     let head = head_element(info);
     let body = match output {
         OutputKind::Html(element) => return element,
-        OutputKind::Body(body) => body,
-        OutputKind::Leaves(leaves) => HtmlElement::new(tag::body).with_children(leaves),
+        OutputKind::Body(mut body) => {
+            body.children = split_into_sections(&body.children);
+            body
+        }
+        OutputKind::Leaves(leaves) => {
+            HtmlElement::new(tag::body).with_children(split_into_sections(&leaves))
+        }
     };
     HtmlElement::new(tag::html).with_children(eco_vec![head.into(), body.into()])
 }
 
+/// Groups the body's nodes into `<section>` elements, splitting at the
+/// markers of page breaks in section mode.
+///
+/// Leaves the nodes untouched if there are no such markers. Sections without
+/// any content are dropped.
+fn split_into_sections(children: &EcoVec<HtmlNode>) -> EcoVec<HtmlNode> {
+    if !children.iter().any(is_section_break) {
+        return children.clone();
+    }
+
+    let mut sections = EcoVec::new();
+    let mut current = EcoVec::new();
+    let mut finish = |current: &mut EcoVec<HtmlNode>| {
+        let current = std::mem::take(current);
+        if current.iter().any(|node| !matches!(node, HtmlNode::Tag(_))) {
+            sections.push(HtmlElement::new(tag::section).with_children(current).into());
+        }
+    };
+
+    for node in children {
+        if is_section_break(node) {
+            finish(&mut current);
+        } else {
+            current.push(node.clone());
+        }
+    }
+    finish(&mut current);
+
+    sections
+}
+
+/// Whether the node is the marker produced for a page break in section mode.
+fn is_section_break(node: &HtmlNode) -> bool {
+    matches!(
+        node,
+        HtmlNode::Element(element)
+            if element.tag == tag::section
+                && element.children.is_empty()
+                && element.attrs.get(attr::class).is_some_and(|class| class == "pagebreak")
+    )
+}
+
 /// Generate a `<head>` element.
 fn head_element(info: &DocumentInfo) -> HtmlElement {
     let mut children = EcoVec::new();
@@ -231,6 +283,134 @@ fn head_element(info: &DocumentInfo) -> HtmlElement {
     HtmlElement::new(tag::head).with_children(children)
 }
 
+/// Finds the `<head>` element among the children of the `<html>` element.
+fn find_head(root: &mut HtmlElement) -> Option<&mut HtmlElement> {
+    root.children.make_mut().iter_mut().find_map(|node| match node {
+        HtmlNode::Element(elem) if elem.tag == tag::head => Some(elem),
+        _ => None,
+    })
+}
+
+/// Removes all but the last occurrence of those elements in the `<head>` that
+/// may only appear once, emitting a warning for each removed one.
+fn dedup_head(engine: &mut Engine, head: &mut HtmlElement) {
+    let keys: Vec<_> = head.children.iter().map(unique_head_key).collect();
+    if !keys
+        .iter()
+        .enumerate()
+        .any(|(i, key)| key.is_some() && keys[i + 1..].contains(key))
+    {
+        return;
+    }
+
+    let mut children = EcoVec::with_capacity(head.children.len());
+    for (i, child) in head.children.iter().enumerate() {
+        if let Some(key) = keys[i]
+            && keys[i + 1..].contains(&Some(key))
+        {
+            let span = match child {
+                HtmlNode::Element(elem) => elem.span,
+                _ => Span::detached(),
+            };
+            engine.sink.warn(warning!(
+                span,
+                "duplicate `{key}` element in `<head>`";
+                hint: "only the last one is kept"
+            ));
+            continue;
+        }
+        children.push(child.clone());
+    }
+    head.children = children;
+}
+
+/// Returns a key identifying elements in the `<head>` that may only appear
+/// once, or `None` if the node may appear multiple times.
+fn unique_head_key(node: &HtmlNode) -> Option<&'static str> {
+    let HtmlNode::Element(elem) = node else { return None };
+    match elem.tag {
+        tag::title => Some("<title>"),
+        tag::meta if elem.attrs.get(attr::charset).is_some() => Some("<meta charset>"),
+        tag::meta
+            if elem
+                .attrs
+                .get(attr::name)
+                .is_some_and(|name| name.eq_ignore_ascii_case("viewport")) =>
+        {
+            Some("<meta name=\"viewport\">")
+        }
+        _ => None,
+    }
+}
+
+/// Ensures that the `<head>` starts with a `<meta charset="utf-8">` element,
+/// as the HTML output is always encoded as UTF-8.
+///
+/// Character encoding declarations for anything but UTF-8, be it through
+/// `<meta charset>` or `<meta http-equiv="content-type">`, are replaced with
+/// a warning. A missing declaration is inserted and a misplaced one is moved to
+/// the front, so that browsers find it before any other content.
+fn normalize_charset(engine: &mut Engine, head: &mut HtmlElement) {
+    let mut declaration = None;
+    let mut children = EcoVec::with_capacity(head.children.len() + 1);
+    for child in &head.children {
+        let HtmlNode::Element(elem) = child else {
+            children.push(child.clone());
+            continue;
+        };
+
+        let Some(charset) = declared_charset(elem) else {
+            children.push(child.clone());
+            continue;
+        };
+
+        if elem.attrs.get(attr::charset).is_some() && is_utf8(charset) {
+            declaration = Some(elem.clone());
+        } else if !is_utf8(charset) {
+            engine.sink.warn(warning!(
+                elem.span,
+                "document declares unsupported character encoding `{charset}`";
+                hint: "HTML export always produces UTF-8";
+                hint: "the declaration was replaced with `<meta charset=\"utf-8\">`"
+            ));
+        }
+    }
+
+    let declaration = declaration
+        .unwrap_or_else(|| HtmlElement::new(tag::meta).with_attr(attr::charset, "utf-8"));
+    children.insert(0, declaration.into());
+    head.children = children;
+}
+
+/// Returns the character encoding declared by a `<meta>` element, if any.
+fn declared_charset(elem: &HtmlElement) -> Option<&str> {
+    if elem.tag != tag::meta {
+        return None;
+    }
+
+    if let Some(charset) = elem.attrs.get(attr::charset) {
+        return Some(charset.trim());
+    }
+
+    let http_equiv = elem.attrs.get(attr::http_equiv)?;
+    if !http_equiv.eq_ignore_ascii_case("content-type") {
+        return None;
+    }
+
+    // The content looks like `text/html; charset=utf-8`.
+    elem.attrs
+        .get(attr::content)?
+        .split(';')
+        .filter_map(|param| param.split_once('='))
+        .find(|(key, _)| key.trim().eq_ignore_ascii_case("charset"))
+        .map(|(_, value)| value.trim().trim_matches(['"', '\'']))
+}
+
+/// Whether a declared character encoding refers to UTF-8.
+fn is_utf8(charset: &str) -> bool {
+    charset.eq_ignore_ascii_case("utf-8")
+}
+
 /// Determine which kind of output the user generated.
 fn classify_output(output: EcoVec<HtmlNode>) -> SourceResult<OutputKind> {
     let count = output.iter().filter(|node| !matches!(node, HtmlNode::Tag(_))).count();
@@ -262,3 +442,38 @@ enum OutputKind {
     /// The user generated leaves which we wrap in a `<body>` and `<html>`.
     Leaves(EcoVec<HtmlNode>),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_into_sections() {
+        let text = |s: &str| HtmlNode::text(s, Span::detached());
+        let marker = || {
+            HtmlNode::from(
+                HtmlElement::new(tag::section).with_attr(attr::class, "pagebreak"),
+            )
+        };
+        let sections = |children: &[HtmlNode]| {
+            split_into_sections(&children.iter().cloned().collect())
+                .iter()
+                .map(|node| match node {
+                    HtmlNode::Element(element) if element.tag == tag::section => {
+                        element.children.len()
+                    }
+                    _ => panic!("expected section"),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let plain: EcoVec<_> = [text("A"), text("B")].into_iter().collect();
+        assert_eq!(split_into_sections(&plain).len(), 2);
+        assert!(matches!(split_into_sections(&plain)[0], HtmlNode::Text(..)));
+        assert_eq!(sections(&[text("A"), marker(), text("B"), text("C")]), [1, 2]);
+        assert_eq!(
+            sections(&[marker(), text("A"), marker(), marker(), text("B"), marker()]),
+            [1, 1]
+        );
+    }
+}
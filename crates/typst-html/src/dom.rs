@@ -1,12 +1,13 @@
 use std::fmt::{self, Debug, Display, Formatter};
 
-use ecow::{EcoString, EcoVec};
-use typst_library::diag::{HintedStrResult, StrResult, bail};
-use typst_library::foundations::{Dict, Repr, Str, StyleChain, cast};
+use ecow::{EcoString, EcoVec, eco_format};
+use typst_library::diag::{StrResult, bail};
+use typst_library::foundations::{Dict, Fold, Repr, Str, StyleChain, Value, cast};
 use typst_library::introspection::{Introspector, Location, Tag};
 use typst_library::layout::{Abs, Frame, Point};
 use typst_library::model::DocumentInfo;
 use typst_library::text::TextElem;
+use typst_library::visualize::Image;
 use typst_syntax::Span;
 use typst_utils::{PicoStr, ResolvedPicoStr};
 
@@ -93,6 +94,11 @@ pub struct HtmlElement {
     /// the `white-space` CSS property is enough), it ensures that formatters
     /// won't mess up the output.
     pub pre_span: bool,
+    /// An image whose data is written as the element's `src` attribute.
+    ///
+    /// It is only encoded on export, where it is decided whether the image is
+    /// embedded as a data URL or written to a separate file.
+    pub image: Option<Image>,
 }
 
 impl HtmlElement {
@@ -105,6 +111,7 @@ impl HtmlElement {
             parent: None,
             span: Span::detached(),
             pre_span: false,
+            image: None,
         }
     }
 
@@ -262,13 +269,34 @@ impl HtmlAttrs {
     }
 
     /// Adds an attribute.
+    ///
+    /// If the attribute is already present, the new value is merged into the
+    /// existing one as described in [`merge_attr`].
     pub fn push(&mut self, attr: HtmlAttr, value: impl Into<EcoString>) {
-        self.0.push((attr, value.into()));
+        let value = value.into();
+        match self.0.iter().position(|&(k, _)| k == attr) {
+            Some(i) => {
+                let merged = merge_attr(attr, &self.0[i].1, &value);
+                self.0.make_mut()[i].1 = merged;
+            }
+            None => self.0.push((attr, value)),
+        }
     }
 
     /// Adds an attribute to the start of the list.
+    ///
+    /// If the attribute is already present, it stays in its position and the
+    /// new value is merged in front of the existing one as described in
+    /// [`merge_attr`].
     pub fn push_front(&mut self, attr: HtmlAttr, value: impl Into<EcoString>) {
-        self.0.insert(0, (attr, value.into()));
+        let value = value.into();
+        match self.0.iter().position(|&(k, _)| k == attr) {
+            Some(i) => {
+                let merged = merge_attr(attr, &value, &self.0[i].1);
+                self.0.make_mut()[i].1 = merged;
+            }
+            None => self.0.insert(0, (attr, value)),
+        }
     }
 
     /// Finds an attribute value.
@@ -277,6 +305,19 @@ impl HtmlAttrs {
     }
 }
 
+/// Attributes from multiple set rules and the element itself are combined in
+/// the order in which they were added, with the outer ones first. Values of
+/// the same attribute are merged as described in [`merge_attr`], so that the
+/// inner value takes precedence for attributes like `id`.
+impl Fold for HtmlAttrs {
+    fn fold(self, mut outer: Self) -> Self {
+        for (attr, value) in self.0 {
+            outer.push(attr, value);
+        }
+        outer
+    }
+}
+
 cast! {
     HtmlAttrs,
     self => self.0
@@ -284,14 +325,81 @@ cast! {
         .map(|(key, value)| (key.resolve().as_str().into(), value.into_value()))
         .collect::<Dict>()
         .into_value(),
-    values: Dict => Self(values
-        .into_iter()
-        .map(|(k, v)| {
+    values: Dict => {
+        let mut attrs = EcoVec::with_capacity(values.len());
+        for (k, v) in values {
             let attr = HtmlAttr::intern(&k)?;
-            let value = v.cast::<EcoString>()?;
-            Ok((attr, value))
-        })
-        .collect::<HintedStrResult<_>>()?),
+            // Boolean attributes are either present without a value or absent.
+            let value = match v {
+                Value::Bool(true) => EcoString::new(),
+                Value::Bool(false) => continue,
+                Value::Str(v) => v.into(),
+                v => Err(eco_format!("expected string or boolean, found {}", v.ty()))?,
+            };
+            attrs.push((attr, value));
+        }
+        Self(attrs)
+    },
+}
+
+/// Combines two values of the same attribute into one, as an element must not
+/// have duplicate attributes.
+///
+/// - Attributes holding a space-separated set of tokens (like `class` or
+///   `rel`) keep the tokens of both values, in order and without duplicates.
+/// - Inline styles are concatenated, so that the declarations of `second` take
+///   precedence.
+/// - For all other attributes, `second` replaces `first`.
+fn merge_attr(attr: HtmlAttr, first: &str, second: &str) -> EcoString {
+    if attr == attr::style {
+        let first = first.trim_end().trim_end_matches(';');
+        let second = second.trim();
+        return match (first.is_empty(), second.is_empty()) {
+            (true, _) => second.into(),
+            (_, true) => first.into(),
+            _ => eco_format!("{first}; {second}"),
+        };
+    }
+
+    if is_token_set_attr(attr) {
+        let mut merged = EcoString::new();
+        let mut seen = Vec::new();
+        for token in first.split_ascii_whitespace().chain(second.split_ascii_whitespace())
+        {
+            if seen.contains(&token) {
+                continue;
+            }
+            if !merged.is_empty() {
+                merged.push(' ');
+            }
+            merged.push_str(token);
+            seen.push(token);
+        }
+        return merged;
+    }
+
+    second.into()
+}
+
+/// Whether the attribute's value is a set of space-separated tokens.
+fn is_token_set_attr(attr: HtmlAttr) -> bool {
+    matches!(
+        attr,
+        attr::accesskey
+            | attr::aria_controls
+            | attr::aria_describedby
+            | attr::aria_flowto
+            | attr::aria_labelledby
+            | attr::aria_owns
+            | attr::blocking
+            | attr::class
+            | attr::headers
+            | attr::itemprop
+            | attr::itemref
+            | attr::ping
+            | attr::rel
+            | attr::sandbox
+    )
 }
 
 /// An attribute of an HTML element.
@@ -380,6 +488,10 @@ pub struct HtmlFrame {
     pub text_size: Abs,
     /// An ID to assign to the SVG itself.
     pub id: Option<EcoString>,
+    /// A short text alternative, written as the SVG's `<title>`.
+    pub title: Option<EcoString>,
+    /// A longer description, written as the SVG's `<desc>`.
+    pub description: Option<EcoString>,
     /// IDs to assign to destination jump points within the SVG.
     pub link_points: EcoVec<(Point, EcoString)>,
     /// The span from which the frame originated.
@@ -393,8 +505,47 @@ impl HtmlFrame {
             inner,
             text_size: styles.resolve(TextElem::size),
             id: None,
+            title: None,
+            description: None,
             link_points: EcoVec::new(),
             span,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attrs_merge_duplicates() {
+        let mut attrs = HtmlAttrs::new();
+        attrs.push(attr::id, "a");
+        attrs.push(attr::class, "x y");
+        attrs.push(attr::style, "color: red;");
+        attrs.push(attr::id, "b");
+        attrs.push(attr::class, "y z");
+        attrs.push(attr::style, "margin: 0");
+        attrs.push_front(attr::class, "w");
+        assert_eq!(attrs.0.len(), 3);
+        assert_eq!(attrs.get(attr::id).unwrap(), "b");
+        assert_eq!(attrs.get(attr::class).unwrap(), "w x y z");
+        assert_eq!(attrs.get(attr::style).unwrap(), "color: red; margin: 0");
+    }
+
+    #[test]
+    fn test_attrs_fold_in_order() {
+        let mut outer = HtmlAttrs::new();
+        outer.push(attr::class, "b a");
+        outer.push(attr::id, "outer");
+        let mut inner = HtmlAttrs::new();
+        inner.push(attr::title, "t");
+        inner.push(attr::class, "c a");
+        inner.push(attr::id, "inner");
+        let folded = inner.fold(outer);
+        let keys: Vec<_> = folded.0.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, [attr::class, attr::id, attr::title]);
+        assert_eq!(folded.get(attr::class).unwrap(), "b a c");
+        assert_eq!(folded.get(attr::id).unwrap(), "inner");
+    }
+}
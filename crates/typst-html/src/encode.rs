@@ -2,24 +2,65 @@ use std::fmt::Write;
 
 use ecow::{EcoString, eco_format};
 use typst_library::diag::{At, SourceResult, StrResult, bail};
-use typst_library::foundations::Repr;
+use typst_library::foundations::{Bytes, Repr};
 use typst_library::introspection::Introspector;
+use typst_library::visualize::Image;
 use typst_syntax::Span;
 
 use crate::{
-    HtmlDocument, HtmlElement, HtmlFrame, HtmlNode, HtmlTag, attr, charsets, tag,
+    HtmlAttr, HtmlDocument, HtmlElement, HtmlFrame, HtmlNode, HtmlTag, attr, charsets,
+    tag,
 };
 
 /// Encodes an HTML document into a string.
 pub fn html(document: &HtmlDocument) -> SourceResult<String> {
-    let mut w = Writer::new(&document.introspector, true);
+    html_with_options(document, &HtmlOptions::default()).map(|output| output.html)
+}
+
+/// Encodes an HTML document into a string, possibly together with files it
+/// references.
+pub fn html_with_options(
+    document: &HtmlDocument,
+    options: &HtmlOptions,
+) -> SourceResult<HtmlOutput> {
+    let mut w = Writer::new(&document.introspector, options, true);
     w.buf.push_str("<!DOCTYPE html>");
     write_indent(&mut w);
     write_element(&mut w, &document.root)?;
     if w.pretty {
         w.buf.push('\n');
     }
-    Ok(w.buf)
+    Ok(HtmlOutput { html: w.buf, assets: w.assets })
+}
+
+/// Settings for HTML export.
+#[derive(Debug, Default, Clone)]
+pub struct HtmlOptions {
+    /// If set, images are written to separate files in this directory
+    /// (relative to the HTML file) instead of being embedded as data URLs.
+    ///
+    /// The files are named after a hash of their contents, so identical
+    /// images are only written once.
+    pub image_dir: Option<EcoString>,
+}
+
+/// The result of encoding an HTML document.
+#[derive(Debug, Clone)]
+pub struct HtmlOutput {
+    /// The encoded HTML document.
+    pub html: String,
+    /// Files referenced by the document that need to be written alongside
+    /// it.
+    pub assets: Vec<HtmlAsset>,
+}
+
+/// A file referenced by an HTML document.
+#[derive(Debug, Clone)]
+pub struct HtmlAsset {
+    /// The file's path, relative to the HTML file.
+    pub path: EcoString,
+    /// The file's contents.
+    pub data: Bytes,
 }
 
 /// Encodes HTML.
@@ -30,14 +71,35 @@ struct Writer<'a> {
     level: usize,
     /// The document's introspector.
     introspector: &'a Introspector,
+    /// The export settings.
+    options: &'a HtmlOptions,
+    /// Files referenced by the document.
+    assets: Vec<HtmlAsset>,
     /// Whether pretty printing is enabled.
     pretty: bool,
+    /// How many IDs were generated for frames with a title or description.
+    frame_ids: usize,
+    /// Whether we are inside of foreign (SVG or MathML) content.
+    foreign: bool,
 }
 
 impl<'a> Writer<'a> {
     /// Creates a new writer.
-    fn new(introspector: &'a Introspector, pretty: bool) -> Self {
-        Self { buf: String::new(), level: 0, introspector, pretty }
+    fn new(
+        introspector: &'a Introspector,
+        options: &'a HtmlOptions,
+        pretty: bool,
+    ) -> Self {
+        Self {
+            buf: String::new(),
+            level: 0,
+            introspector,
+            options,
+            assets: vec![],
+            pretty,
+            frame_ids: 0,
+            foreign: false,
+        }
     }
 }
 
@@ -79,24 +141,18 @@ fn write_element(w: &mut Writer, element: &HtmlElement) -> SourceResult<()> {
     w.buf.push('<');
     w.buf.push_str(&element.tag.resolve());
 
+    if let Some(image) = &element.image {
+        let src = image_src(w, image);
+        write_attr(w, element, attr::src, &src)?;
+    }
+
     for (attr, value) in &element.attrs.0 {
-        w.buf.push(' ');
-        w.buf.push_str(&attr.resolve());
-
-        // If the string is empty, we can use shorthand syntax.
-        // `<elem attr="">..</div` is equivalent to `<elem attr>..</div>`
-        if !value.is_empty() {
-            w.buf.push('=');
-            w.buf.push('"');
-            for c in value.chars() {
-                if charsets::is_valid_in_attribute_value(c) {
-                    w.buf.push(c);
-                } else {
-                    write_escape(w, c).at(element.span)?;
-                }
-            }
-            w.buf.push('"');
-        }
+        write_attr(w, element, *attr, value)?;
+    }
+
+    // Foreign elements follow different syntax rules. See HTML spec § 13.1.2.
+    if w.foreign || tag::is_foreign_root(element.tag) {
+        return write_foreign_element(w, element);
     }
 
     w.buf.push('>');
@@ -128,6 +184,139 @@ fn write_element(w: &mut Writer, element: &HtmlElement) -> SourceResult<()> {
     Ok(())
 }
 
+/// Encodes the rest of an SVG or MathML element after its attributes.
+///
+/// Such elements may be self-closing and their contents are never raw text.
+/// The children of a `<foreignObject>` are HTML again.
+fn write_foreign_element(w: &mut Writer, element: &HtmlElement) -> SourceResult<()> {
+    if element.children.is_empty() {
+        w.buf.push_str("/>");
+        return Ok(());
+    }
+
+    w.buf.push('>');
+
+    let foreign = std::mem::replace(&mut w.foreign, element.tag != tag::foreignObject);
+    let result = write_children(w, element);
+    w.foreign = foreign;
+    result?;
+
+    w.buf.push_str("</");
+    w.buf.push_str(&element.tag.resolve());
+    w.buf.push('>');
+
+    Ok(())
+}
+
+/// Whether the attribute's value is a single URL.
+fn is_url_attr(attr: HtmlAttr) -> bool {
+    matches!(
+        attr,
+        attr::href
+            | attr::src
+            | attr::action
+            | attr::formaction
+            | attr::cite
+            | attr::poster
+            | attr::data
+    )
+}
+
+/// Percent-encodes the bytes of a URL that may not appear in it literally,
+/// that is non-ASCII characters, whitespace, and control characters.
+///
+/// A `%` is only encoded if it doesn't already start a percent-escape, so that
+/// URLs which are already encoded aren't encoded twice.
+fn encode_url(url: &EcoString) -> EcoString {
+    let bytes = url.as_bytes();
+    let needs_encoding = |i: usize| {
+        let b = bytes[i];
+        !b.is_ascii_graphic()
+            || (b == b'%'
+                && !(bytes.len() > i + 2
+                    && bytes[i + 1].is_ascii_hexdigit()
+                    && bytes[i + 2].is_ascii_hexdigit()))
+    };
+
+    if !(0..bytes.len()).any(needs_encoding) {
+        return url.clone();
+    }
+
+    let mut encoded = EcoString::with_capacity(url.len());
+    for (i, &b) in bytes.iter().enumerate() {
+        if needs_encoding(i) {
+            write!(encoded, "%{b:02X}").unwrap();
+        } else {
+            encoded.push(b as char);
+        }
+    }
+    encoded
+}
+
+/// Encodes one attribute of an element into the writer.
+fn write_attr(
+    w: &mut Writer,
+    element: &HtmlElement,
+    attr: HtmlAttr,
+    value: &str,
+) -> SourceResult<()> {
+    let encoded;
+    let value = if is_url_attr(attr) {
+        encoded = encode_url(value);
+        &encoded
+    } else {
+        value
+    };
+
+    w.buf.push(' ');
+    w.buf.push_str(&attr.resolve());
+
+    // If the string is empty, we can use shorthand syntax.
+    // `<elem attr="">..</div` is equivalent to `<elem attr>..</div>`
+    if !value.is_empty() {
+        let quote = attribute_quote(value);
+        w.buf.push('=');
+        w.buf.push(quote);
+        for c in value.chars() {
+            if charsets::is_valid_in_attribute_value(c, quote) {
+                w.buf.push(c);
+            } else {
+                write_escape(w, c).at(element.span)?;
+            }
+        }
+        w.buf.push(quote);
+    }
+
+    Ok(())
+}
+
+/// Determines the `src` of an image: A separate asset file if this is
+/// configured, and an embedded data URL otherwise.
+fn image_src(w: &mut Writer, image: &Image) -> EcoString {
+    let Some(dir) = &w.options.image_dir else {
+        return typst_svg::convert_image_to_base64_url(image);
+    };
+
+    let (format, data) = typst_svg::convert_image_to_bytes(image);
+    let extension = match format {
+        "jpeg" => "jpg",
+        "svg+xml" => "svg",
+        format => format,
+    };
+
+    let name = eco_format!("{:032x}.{extension}", typst_utils::hash128(&data));
+    let path = match dir.trim_end_matches('/') {
+        "" => name,
+        dir => eco_format!("{dir}/{name}"),
+    };
+
+    if !w.assets.iter().any(|asset| asset.path == path) {
+        w.assets.push(HtmlAsset { path: path.clone(), data });
+    }
+
+    path
+}
+
 /// Encodes the children of an element.
 fn write_children(w: &mut Writer, element: &HtmlElement) -> SourceResult<()> {
     let pretty = w.pretty;
@@ -177,14 +366,21 @@ fn starts_with_newline(element: &HtmlElement) -> bool {
 
 /// Encodes the contents of a raw text element.
 fn write_raw(w: &mut Writer, element: &HtmlElement) -> SourceResult<()> {
-    let text = collect_raw_text(element)?;
+    let mut text = collect_raw_text(element)?;
 
     if let Some(closing) = find_closing_tag(&text, element.tag) {
-        bail!(
-            element.span,
-            "HTML raw text element cannot contain its own closing tag";
-            hint: "the sequence `{closing}` appears in the raw text",
-        )
+        if is_json_script(element) {
+            // In JSON, `<` can only occur within strings, where `\/` is a
+            // valid escape for `/`. Thus, we can safely split up the closing
+            // tag without changing the meaning of the data.
+            text = text.replace("</", "<\\/");
+        } else {
+            bail!(
+                element.span,
+                "HTML raw text element cannot contain its own closing tag";
+                hint: "the sequence `{closing}` appears in the raw text",
+            )
+        }
     }
 
     let mode = if w.pretty { RawMode::of(element, &text) } else { RawMode::Keep };
@@ -262,6 +458,16 @@ fn find_closing_tag(text: &str, tag: HtmlTag) -> Option<&str> {
     })
 }
 
+/// Whether the element is a script that contains JSON data (e.g. JSON-LD
+/// structured data or an import map) rather than executable code.
+fn is_json_script(element: &HtmlElement) -> bool {
+    element.tag == tag::script
+        && element.attrs.get(attr::r#type).is_some_and(|ty| {
+            let ty = ty.trim().to_ascii_lowercase();
+            ty == "application/json" || ty == "importmap" || ty.ends_with("+json")
+        })
+}
+
 /// How to format the contents of a raw text element.
 enum RawMode {
     /// Just don't touch it.
@@ -313,6 +519,15 @@ fn wants_pretty_around(tag: HtmlTag) -> bool {
     allows_pretty_inside(tag) || tag::is_metadata(tag) || tag == tag::pre
 }
 
+/// Selects the quotation mark to delimit an attribute value with.
+///
+/// Double quotes are preferred, but if the value contains double quotes and no
+/// single quotes, we use single quotes to avoid escaping. If it contains both,
+/// the double quotes in it are escaped.
+fn attribute_quote(value: &str) -> char {
+    if value.contains('"') && !value.contains('\'') { '\'' } else { '"' }
+}
+
 /// Escape a character.
 fn write_escape(w: &mut Writer, c: char) -> StrResult<()> {
     // See <https://html.spec.whatwg.org/multipage/syntax.html#syntax-charref>
@@ -338,12 +553,68 @@ fn unencodable(c: char) -> EcoString {
 
 /// Encode a laid out frame into the writer.
 fn write_frame(w: &mut Writer, frame: &HtmlFrame) {
+    // The title and description are referenced by ID, which is derived from
+    // the SVG's own ID. Thus, we need one if the frame has either.
+    let mut id = frame.id.clone();
+    if id.is_none() && (frame.title.is_some() || frame.description.is_some()) {
+        w.frame_ids += 1;
+        id = Some(eco_format!("typst-frame-{}", w.frame_ids));
+    }
+
     let svg = typst_svg::svg_html_frame(
         &frame.inner,
         frame.text_size,
-        frame.id.as_deref(),
+        id.as_deref(),
+        frame.title.as_deref(),
+        frame.description.as_deref(),
         &frame.link_points,
         w.introspector,
     );
     w.buf.push_str(&svg);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_url() {
+        let encode = |url: &str| encode_url(&url.into());
+        assert_eq!(encode("https://typst.app/docs/"), "https://typst.app/docs/");
+        assert_eq!(encode("資料/a b.pdf"), "%E8%B3%87%E6%96%99/a%20b.pdf");
+        assert_eq!(encode("a%20b%E6"), "a%20b%E6");
+        assert_eq!(encode("100%"), "100%25");
+        assert_eq!(encode("%zz%4"), "%25zz%254");
+    }
+
+    #[test]
+    fn test_attribute_quote() {
+        assert_eq!(attribute_quote("plain"), '"');
+        assert_eq!(attribute_quote("it's"), '"');
+        assert_eq!(attribute_quote(r#"{"a": 1}"#), '\'');
+        assert_eq!(attribute_quote(r#"say "it's""#), '"');
+    }
+
+    #[test]
+    fn test_image_src() {
+        use typst_library::visualize::SvgImage;
+
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"/>"#;
+        let image = Image::plain(SvgImage::new(Bytes::from_string(svg)).unwrap());
+        let introspector = Introspector::default();
+
+        let options = HtmlOptions::default();
+        let mut w = Writer::new(&introspector, &options, false);
+        assert!(image_src(&mut w, &image).starts_with("data:image/svg+xml;base64,"));
+        assert!(w.assets.is_empty());
+
+        let options = HtmlOptions { image_dir: Some("images/".into()) };
+        let mut w = Writer::new(&introspector, &options, false);
+        let src = image_src(&mut w, &image);
+        assert!(src.starts_with("images/") && src.ends_with(".svg"));
+        assert_eq!(image_src(&mut w, &image), src);
+        assert_eq!(w.assets.len(), 1);
+        assert_eq!(w.assets[0].path, src);
+        assert_eq!(&*w.assets[0].data, svg.as_bytes());
+    }
+}
@@ -15,14 +15,15 @@ mod typed;
 
 pub use self::document::html_document;
 pub use self::dom::*;
-pub use self::encode::html;
+pub use self::encode::{HtmlAsset, HtmlOptions, HtmlOutput, html, html_with_options};
 pub use self::rules::{html_span_filled, register};
 
 use ecow::EcoString;
 use typst_library::Category;
-use typst_library::foundations::{Content, Module, Scope};
+use typst_library::foundations::{Content, Module, Scope, Smart};
 use typst_library::introspection::Location;
-use typst_macros::elem;
+use typst_library::visualize::Image;
+use typst_macros::{Cast, elem};
 
 /// Creates the module with all HTML definitions.
 pub fn module() -> Module {
@@ -31,6 +32,9 @@ pub fn module() -> Module {
     html.define_elem::<HtmlElem>();
     html.define_elem::<FrameElem>();
     crate::typed::define(&mut html);
+    html.define_func::<typed::srcset>();
+    html.define_func::<typed::preload>();
+    html.define_func::<typed::preconnect>();
     Module::new("html", html)
 }
 
@@ -51,6 +55,17 @@ pub fn module() -> Module {
 /// 通常、Typstは`html`、`head`、および`body`タグを生成します。
 /// 代わりにこの関数でそれらを作成した場合、Typstは自身の生成するタグを省略します。
 ///
+/// `svg`および`math`タグの内部は、HTMLの外部コンテンツ（foreign content）として出力されます。
+/// その配下で子を持たない要素は自己終了タグ（`<circle/>`など）になり、
+/// `viewBox`や`preserveAspectRatio`のように大文字を含む名前はそのまま保持されます。
+/// `foreignObject`の内部は再びHTMLとして扱われます。
+///
+/// ```typ
+/// #html.elem("svg", attrs: (viewBox: "0 0 10 10", width: "20"))[
+///   #html.elem("circle", attrs: (cx: "5", cy: "5", r: "4"))
+/// ]
+/// ```
+///
 /// ```typ
 /// #html.elem("div", attrs: (style: "background: aqua"))[
 ///   A div with _Typst content_ inside!
@@ -63,6 +78,21 @@ pub struct HtmlElem {
     pub tag: HtmlTag,
 
     /// 要素のHTML属性。
+    ///
+    /// setルールと要素自体で指定された属性は、追加された順にまとめられます。
+    /// `class`のように空白区切りのトークンを持つ属性は、重複を除いて連結されます。
+    /// `id`のようなその他の属性では、要素に直接指定した値が優先されます。
+    ///
+    /// 値には文字列のほか、ブール値も指定できます。
+    /// `hidden`や`inert`のようなブール属性は、`{true}`の場合に値なしで出力され、
+    /// `{false}`の場合は出力されません。
+    /// 例えば、装飾的な要素を支援技術から隠すには`{(hidden: true)}`を指定します。
+    ///
+    /// ```typ
+    /// #show html.elem.where(tag: "div"): set html.elem(attrs: (class: "card"))
+    /// #html.elem("div", attrs: (class: "note card"))[Hello]
+    /// ```
+    #[fold]
     pub attrs: HtmlAttrs,
 
     /// HTML要素の内容。
@@ -71,11 +101,31 @@ pub struct HtmlElem {
     #[positional]
     pub body: Option<Content>,
 
+    /// 要素をインライン要素とブロック要素のどちらとして扱うか。
+    ///
+    /// インライン要素は周囲のテキストとともに段落（`<p>`）にまとめられますが、
+    /// ブロック要素は段落を分割します。
+    /// `{auto}`の場合は、タグごとのHTMLの既定に従います。
+    /// 例えば`span`はインライン、`div`やカスタム要素はブロックとして扱われます。
+    ///
+    /// この指定は要素に直接与えた場合にのみ有効で、setルールでは変更できません。
+    ///
+    /// ```typ
+    /// New #html.elem("my-badge", display: "inline")[beta] feature.
+    /// ```
+    pub display: Smart<HtmlDisplay>,
+
     /// The element's logical parent, if any.
     #[internal]
     #[synthesized]
     pub parent: Location,
 
+    /// An image whose data is written as the element's `src` attribute on
+    /// export.
+    #[internal]
+    #[synthesized]
+    pub image: Image,
+
     /// 役割は、最上位のスタイル設定されたHTML要素に適用されるべきですが、
     /// その子孫には適用されません。スタイルセットルールが今後追加されて、
     /// サブツリーではなく特定の要素に適用される場合は、
@@ -91,6 +141,24 @@ pub struct HtmlElem {
     #[internal]
     #[ghost]
     pub role: Option<EcoString>,
+
+    /// Whether we are inside of the contents of a `<template>` element.
+    ///
+    /// Such contents are not rendered by the browser, but rather instantiated
+    /// by scripts. Thus, they are emitted as they are, without wrapping
+    /// inline-level content into paragraphs.
+    #[internal]
+    #[ghost]
+    pub in_template: bool,
+
+    /// Whether we are inside of foreign content, that is, of an `<svg>` or
+    /// `<math>` element, but not of a nested `<foreignObject>`.
+    ///
+    /// Paragraphs are not valid in such content. Thus, like template
+    /// contents, it is emitted as it is.
+    #[internal]
+    #[ghost]
+    pub in_foreign: bool,
 }
 
 impl HtmlElem {
@@ -124,10 +192,22 @@ impl HtmlElem {
     /// Checks whether the given element is an inline-level HTML element.
     fn is_inline(elem: &Content) -> bool {
         elem.to_packed::<HtmlElem>()
-            .is_some_and(|elem| tag::is_inline_by_default(elem.tag))
+            .is_some_and(|elem| match elem.display.as_option() {
+                Some(Smart::Custom(display)) => *display == HtmlDisplay::Inline,
+                _ => tag::is_inline_by_default(elem.tag),
+            })
     }
 }
 
+/// How an HTML element takes part in paragraph building.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum HtmlDisplay {
+    /// 周囲のテキストとともに段落にまとめられます。
+    Inline,
+    /// 段落を分割し、単独で配置されます。
+    Block,
+}
+
 /// コンテンツをインラインSVGとしてレイアウトする要素。
 ///
 /// TypstのコンテンツにはHTMLへの変換が不適切なものがあります。
@@ -143,4 +223,29 @@ pub struct FrameElem {
     #[positional]
     #[required]
     pub body: Content,
+
+    /// フレームの簡潔な代替テキスト。
+    ///
+    /// 指定すると、SVGの最初の子要素として`<title>`要素が出力され、
+    /// SVGには`role="img"`と、それを参照する`aria-labelledby`属性が付与されます。
+    /// これにより、スクリーンリーダーはプロットなどのフレームを1枚の画像として読み上げます。
+    ///
+    /// `title`も`description`も指定しない場合、SVGにはARIA属性が付与されず、
+    /// 支援技術には説明が伝わりません。
+    /// 意味を持つフレームには、必ずどちらかを指定してください。
+    ///
+    /// ```typ
+    /// #html.frame(
+    ///   title: "売上の推移",
+    ///   description: "2020年から2024年にかけて売上が倍増したことを示す折れ線グラフ。",
+    ///   plot,
+    /// )
+    /// ```
+    pub title: Option<EcoString>,
+
+    /// フレームの内容についての詳しい説明。
+    ///
+    /// 指定すると、SVGに`<desc>`要素が出力され、
+    /// `aria-describedby`属性によってSVGと関連付けられます。
+    pub description: Option<EcoString>,
 }
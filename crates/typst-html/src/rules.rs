@@ -5,23 +5,25 @@ use ecow::{EcoVec, eco_format};
 use typst_library::diag::{At, SourceResult, bail, error, warning};
 use typst_library::engine::Engine;
 use typst_library::foundations::{
-    Content, Context, NativeElement, NativeRuleMap, ShowFn, Smart, StyleChain, Target,
+    Content, Context, Depth, NativeElement, NativeRuleMap, Packed, ShowFn, Smart,
+    StyleChain, Target,
 };
 use typst_library::introspection::Counter;
 use typst_library::layout::resolve::{Cell, CellGrid, Entry};
 use typst_library::layout::{
-    BlockBody, BlockElem, BoxElem, HElem, OuterVAlignment, Sizing,
+    BlockBody, BlockElem, BoxElem, Dir, HElem, OuterVAlignment, PagebreakElem, Sizing,
 };
 use typst_library::model::{
     Attribution, BibliographyElem, CiteElem, CiteGroup, CslIndentElem, CslLightElem,
     Destination, DirectLinkElem, EmphElem, EnumElem, FigureCaption, FigureElem,
     FootnoteElem, FootnoteEntry, FootnoteMarker, HeadingElem, LinkElem, LinkTarget,
-    ListElem, OutlineElem, OutlineEntry, OutlineNode, ParElem, ParbreakElem, QuoteElem,
-    RefElem, StrongElem, TableCell, TableElem, TermsElem, TitleElem, Works,
+    ListElem, Numbering, NumberingKind, OutlineElem, OutlineEntry, OutlineNode, ParElem,
+    ParbreakElem, QuoteElem, RefElem, StrongElem, TableCell, TableElem, TermsElem,
+    TitleElem, Works,
 };
 use typst_library::text::{
-    HighlightElem, LinebreakElem, OverlineElem, RawElem, RawLine, SmallcapsElem,
-    SpaceElem, StrikeElem, SubElem, SuperElem, UnderlineElem,
+    HighlightElem, LinebreakElem, LocalName, OverlineElem, RawElem, RawLine,
+    SmallcapsElem, SpaceElem, StrikeElem, SubElem, SuperElem, TextElem, UnderlineElem,
 };
 use typst_library::visualize::{Color, ImageElem};
 use typst_macros::elem;
@@ -74,6 +76,7 @@ pub fn register(rules: &mut NativeRuleMap) {
     // Layout.
     rules.register(Html, BLOCK_RULE);
     rules.register(Html, BOX_RULE);
+    rules.register(Html, PAGEBREAK_RULE);
 
     // Visualize.
     rules.register(Html, IMAGE_RULE);
@@ -84,8 +87,18 @@ pub fn register(rules: &mut NativeRuleMap) {
     rules.register::<FrameElem>(Paged, |elem, _, _| Ok(elem.body.clone()));
 }
 
-const PAR_RULE: ShowFn<ParElem> =
-    |elem, _, _| Ok(HtmlElem::new(tag::p).with_body(Some(elem.body.clone())).pack());
+const PAR_RULE: ShowFn<ParElem> = |elem, _, styles| {
+    // Template and foreign contents are kept as written. See
+    // `HtmlElem::in_template` and `HtmlElem::in_foreign`.
+    if styles.get(HtmlElem::in_template) || styles.get(HtmlElem::in_foreign) {
+        return Ok(elem.body.clone());
+    }
+
+    Ok(HtmlElem::new(tag::p)
+        .with_body(Some(elem.body.clone()))
+        .with_optional_attr(attr::dir, text_dir(styles))
+        .pack())
+};
 
 const STRONG_RULE: ShowFn<StrongElem> =
     |elem, _, _| Ok(HtmlElem::new(tag::strong).with_body(Some(elem.body.clone())).pack());
@@ -112,7 +125,8 @@ const LIST_RULE: ShowFn<ListElem> = |elem, _, styles| {
 const ENUM_RULE: ShowFn<EnumElem> = |elem, _, styles| {
     let mut ol = HtmlElem::new(tag::ol);
 
-    if elem.reversed.get(styles) {
+    let reversed = elem.reversed.get(styles);
+    if reversed {
         ol = ol.with_attr(attr::reversed, "reversed");
     }
 
@@ -120,42 +134,89 @@ const ENUM_RULE: ShowFn<EnumElem> = |elem, _, styles| {
         ol = ol.with_attr(attr::start, eco_format!("{n}"));
     }
 
+    let depth = styles.get_cloned(EnumElem::parents).len();
+    if let Some(ty) = ol_type(elem.numbering.get_ref(styles), depth) {
+        ol = ol.with_attr(attr::r#type, ty);
+    }
+
+    let mut number = elem
+        .start
+        .get(styles)
+        .unwrap_or_else(|| if reversed { elem.children.len() as u64 } else { 1 });
+
     let body = Content::sequence(elem.children.iter().map(|item| {
         let mut li = HtmlElem::new(tag::li);
         if let Smart::Custom(nr) = item.number.get(styles) {
             li = li.with_attr(attr::value, eco_format!("{nr}"));
+            number = nr;
         }
         // Text in wide enums shall always turn into paragraphs.
         let mut body = item.body.clone();
         if !elem.tight.get(styles) {
             body += ParbreakElem::shared();
         }
+        // Nested enums need to know their parents' numbers, e.g. to pick the
+        // right counting symbol of the numbering pattern.
+        let body = body.set(EnumElem::parents, std::iter::once(number).collect());
+        number =
+            if reversed { number.saturating_sub(1) } else { number.saturating_add(1) };
         li.with_body(Some(body)).pack().spanned(item.span())
     }));
 
     Ok(ol.with_body(Some(body)).pack())
 };
 
-const TERMS_RULE: ShowFn<TermsElem> = |elem, _, styles| {
-    Ok(HtmlElem::new(tag::dl)
-        .with_body(Some(Content::sequence(elem.children.iter().flat_map(|item| {
-            // Text in wide term lists shall always turn into paragraphs.
-            let mut description = item.description.clone();
-            if !elem.tight.get(styles) {
-                description += ParbreakElem::shared();
-            }
+/// The value of the `type` attribute of an `<ol>` element for an enum with
+/// the given numbering at the given nesting depth. Returns `None` for decimal
+/// numbers, which are the default, and for numberings that HTML can't
+/// express.
+fn ol_type(numbering: &Numbering, depth: usize) -> Option<&'static str> {
+    let Numbering::Pattern(pattern) = numbering else { return None };
+    let (_, kind) = pattern.pieces.get(depth).or_else(|| pattern.pieces.last())?;
+    Some(match kind {
+        NumberingKind::LowerLatin => "a",
+        NumberingKind::UpperLatin => "A",
+        NumberingKind::LowerRoman | NumberingKind::LowerRomanAscii => "i",
+        NumberingKind::UpperRoman | NumberingKind::UpperRomanAscii => "I",
+        _ => return None,
+    })
+}
 
-            [
+const TERMS_RULE: ShowFn<TermsElem> = |elem, _, styles| {
+    let tight = elem.tight.get(styles);
+    let mut children = vec![];
+    let mut prev: Option<&Content> = None;
+
+    for item in &elem.children {
+        // Adjacent items with the same term are grouped under a single `<dt>`
+        // with multiple descriptions.
+        if prev != Some(&item.term) {
+            children.push(
                 HtmlElem::new(tag::dt)
                     .with_body(Some(item.term.clone()))
                     .pack()
                     .spanned(item.term.span()),
-                HtmlElem::new(tag::dd)
-                    .with_body(Some(description))
-                    .pack()
-                    .spanned(item.description.span()),
-            ]
-        }))))
+            );
+        }
+
+        // Text in wide term lists shall always turn into paragraphs.
+        let mut description = item.description.clone();
+        if !tight {
+            description += ParbreakElem::shared();
+        }
+
+        children.push(
+            HtmlElem::new(tag::dd)
+                .with_body(Some(description))
+                .pack()
+                .spanned(item.description.span()),
+        );
+
+        prev = Some(&item.term);
+    }
+
+    Ok(HtmlElem::new(tag::dl)
+        .with_body(Some(Content::sequence(children)))
         .pack())
 };
 
@@ -199,6 +260,7 @@ const DIRECT_LINK_RULE: ShowFn<DirectLinkElem> = |elem, _, _| {
 const TITLE_RULE: ShowFn<TitleElem> = |elem, _, styles| {
     Ok(HtmlElem::new(tag::h1)
         .with_body(Some(elem.resolve_body(styles).at(elem.span())?))
+        .with_optional_attr(attr::dir, text_dir(styles))
         .pack())
 };
 
@@ -234,13 +296,23 @@ const HEADING_RULE: ShowFn<HeadingElem> = |elem, engine, styles| {
             .with_body(Some(realized))
             .with_attr(attr::role, "heading")
             .with_attr(attr::aria_level, eco_format!("{}", level + 1))
+            .with_optional_attr(attr::dir, text_dir(styles))
             .pack()
     } else {
         let t = [tag::h2, tag::h3, tag::h4, tag::h5, tag::h6][level - 1];
-        HtmlElem::new(t).with_body(Some(realized)).pack()
+        HtmlElem::new(t)
+            .with_body(Some(realized))
+            .with_optional_attr(attr::dir, text_dir(styles))
+            .pack()
     })
 };
 
+/// The value of the `dir` attribute for a block-level element. Only
+/// right-to-left text is marked, since left-to-right is the browser's default.
+fn text_dir(styles: StyleChain) -> Option<&'static str> {
+    (styles.resolve(TextElem::dir) == Dir::RTL).then_some("rtl")
+}
+
 const FIGURE_RULE: ShowFn<FigureElem> = |elem, _, styles| {
     let span = elem.span();
     let mut realized = elem.body.clone();
@@ -256,7 +328,15 @@ const FIGURE_RULE: ShowFn<FigureElem> = |elem, _, styles| {
     // Ensure that the body is considered a paragraph.
     realized += ParbreakElem::shared().clone().spanned(span);
 
-    Ok(HtmlElem::new(tag::figure).with_body(Some(realized)).pack())
+    let mut attrs = HtmlAttrs::new();
+    if let Some(alt) = elem.alt.get_cloned(styles) {
+        attrs.push(attr::aria_label, alt);
+    }
+
+    Ok(HtmlElem::new(tag::figure)
+        .with_attrs(attrs)
+        .with_body(Some(realized))
+        .pack())
 };
 
 const FIGURE_CAPTION_RULE: ShowFn<FigureCaption> = |elem, engine, styles| {
@@ -268,32 +348,59 @@ const FIGURE_CAPTION_RULE: ShowFn<FigureCaption> = |elem, engine, styles| {
 const QUOTE_RULE: ShowFn<QuoteElem> = |elem, _, styles| {
     let span = elem.span();
     let block = elem.block.get(styles);
+    let quotes = elem.quotes.get(styles).unwrap_or(!block);
 
     let mut realized = elem.body.clone();
-
-    if elem.quotes.get(styles).unwrap_or(!block) {
-        realized = QuoteElem::quoted(realized, styles);
-    }
-
     let attribution = elem.attribution.get_ref(styles);
 
+    // The source URL of the quote, if the attribution is a plain link.
+    let cite = match attribution {
+        Some(Attribution::Content(attribution)) => {
+            attribution.to_packed::<LinkElem>().and_then(|link| match &link.dest {
+                LinkTarget::Dest(Destination::Url(url)) => Some(url.clone().into_inner()),
+                _ => None,
+            })
+        }
+        _ => None,
+    };
+
     if block {
+        if quotes {
+            realized = QuoteElem::quoted(realized, styles);
+        }
+
+        if let Some(attribution) = attribution.as_ref() {
+            // Ensure that the body is considered a paragraph.
+            realized += ParbreakElem::shared().clone().spanned(span);
+            realized += HtmlElem::new(tag::footer)
+                .with_body(Some(attribution.realize(span)))
+                .pack()
+                .spanned(span);
+        }
+
         let mut blockquote = HtmlElem::new(tag::blockquote).with_body(Some(realized));
-        if let Some(Attribution::Content(attribution)) = attribution
-            && let Some(link) = attribution.to_packed::<LinkElem>()
-            && let LinkTarget::Dest(Destination::Url(url)) = &link.dest
-        {
-            blockquote = blockquote.with_attr(attr::cite, url.clone().into_inner());
+        if let Some(cite) = cite {
+            blockquote = blockquote.with_attr(attr::cite, cite);
         }
 
         realized = blockquote.pack().spanned(span);
+    } else {
+        if quotes {
+            // Browsers render the quotation marks of `<q>` themselves, so we
+            // only need to keep track of the nesting depth for smart quotes
+            // within the body.
+            let mut q = HtmlElem::new(tag::q)
+                .with_body(Some(realized.set(QuoteElem::depth, Depth(1))));
+            if let Some(cite) = cite {
+                q = q.with_attr(attr::cite, cite);
+            }
+            realized = q.pack().spanned(span);
+        }
 
-        if let Some(attribution) = attribution.as_ref() {
-            realized += attribution.realize(span);
+        if let Some(Attribution::Label(label)) = attribution {
+            realized += SpaceElem::shared().clone();
+            realized += CiteElem::new(*label).pack().spanned(span);
         }
-    } else if let Some(Attribution::Label(label)) = attribution {
-        realized += SpaceElem::shared().clone();
-        realized += CiteElem::new(*label).pack().spanned(span);
     }
 
     Ok(realized)
@@ -302,18 +409,20 @@ const QUOTE_RULE: ShowFn<QuoteElem> = |elem, _, styles| {
 const FOOTNOTE_RULE: ShowFn<FootnoteElem> = |elem, engine, styles| {
     let span = elem.span();
     let (dest, num) = elem.realize(engine, styles)?;
-    let sup = SuperElem::new(num).pack().spanned(span);
 
-    // Link to the footnote entry.
-    let link = LinkElem::new(dest.into(), sup)
+    // Link to the footnote entry. The link is wrapped in the superscript
+    // rather than the other way around so that the whole superscript can be
+    // targeted by the backlinks.
+    let link = LinkElem::new(dest.into(), num)
         .pack()
         .styled(HtmlElem::role.set(Some("doc-noteref".into())));
+    let sup = SuperElem::new(link).pack().spanned(span);
 
     // Indicates the presence of a default footnote rule to emit an error when
     // no footnote container is available.
     let marker = FootnoteMarker::new().pack().spanned(span);
 
-    Ok(HElem::hole().clone() + link + marker)
+    Ok(HElem::hole().clone() + sup + marker)
 };
 
 /// This is inserted at the end of the body to display footnotes. In the future,
@@ -380,6 +489,7 @@ const FOOTNOTE_CONTAINER_RULE: ShowFn<FootnoteContainer> = |_, engine, _| {
     // represent an ordered list. However, the list is already numbered with the
     // footnote superscripts in the DOM, so we turn off CSS' list enumeration.
     let list = HtmlElem::new(tag::ol)
+        .with_attr(attr::class, "footnotes")
         .with_styles(css::Properties::new().with("list-style-type", "none"))
         .with_body(Some(Content::sequence(items)))
         .pack();
@@ -394,18 +504,30 @@ const FOOTNOTE_CONTAINER_RULE: ShowFn<FootnoteContainer> = |_, engine, _| {
 };
 
 const FOOTNOTE_ENTRY_RULE: ShowFn<FootnoteEntry> = |elem, engine, styles| {
+    let span = elem.span();
     let (prefix, body) = elem.realize(engine, styles)?;
 
     // The prefix is a link back to the first footnote reference, so
     // `doc-backlink` is the appropriate ARIA role.
-    let backlink = prefix.styled(HtmlElem::role.set(Some("doc-backlink".into())));
+    let role = HtmlElem::role.set(Some("doc-backlink".into()));
+    let backlink = prefix.styled(role.clone());
+
+    // Readers typically look for the way back at the end of the note, so we
+    // add a return arrow there, too. The variation selector prevents it from
+    // being rendered as an emoji.
+    let loc = elem.note.location().unwrap();
+    let arrow = TextElem::packed("\u{21A9}\u{FE0E}").spanned(span);
+    let ret = DirectLinkElem::new(loc, arrow, None)
+        .pack()
+        .spanned(span)
+        .styled(role);
 
     // We do not use the ARIA role `doc-footnote` because it "is only for
     // representing individual notes that occur within the body of a work" (see
     // <https://www.w3.org/TR/dpub-aria-1.1/#doc-footnote>). Our footnotes more
     // appropriately modelled as ARIA endnotes. This is also in line with how
     // Pandoc handles footnotes.
-    Ok(backlink + body)
+    Ok(backlink + body + SpaceElem::shared().clone() + ret)
 };
 
 const OUTLINE_RULE: ShowFn<OutlineElem> = |elem, engine, styles| {
@@ -438,8 +560,16 @@ const OUTLINE_RULE: ShowFn<OutlineElem> = |elem, engine, styles| {
     let tree = elem.realize_tree(engine, styles)?;
     let list = convert_list(tree);
 
+    // Name the navigation landmark so that assistive technology can tell it
+    // apart from other `<nav>` elements, even if the title is hidden.
+    let label = match elem.title.get_ref(styles) {
+        Smart::Custom(Some(title)) => title.plain_text(),
+        _ => Packed::<OutlineElem>::local_name_in(styles).into(),
+    };
+
     Ok(HtmlElem::new(tag::nav)
         .with_attr(attr::role, "doc-toc")
+        .with_attr(attr::aria_label, label)
         .with_body(Some(title.unwrap_or_default() + list))
         .pack())
 };
@@ -695,8 +825,14 @@ const RAW_RULE: ShowFn<RawElem> = |elem, _, styles| {
         seq.push(line.clone().pack());
     }
 
+    // The `language-*` class is understood by client-side highlighters like
+    // Prism.js or highlight.js.
     let lang = elem.lang.get_ref(styles);
     let code = HtmlElem::new(tag::code)
+        .with_optional_attr(
+            attr::class,
+            lang.as_ref().map(|lang| eco_format!("language-{lang}")),
+        )
         .with_optional_attr(const { HtmlAttr::constant("data-lang") }, lang.clone())
         .with_body(Some(Content::sequence(seq)))
         .pack()
@@ -751,12 +887,27 @@ const BOX_RULE: ShowFn<BoxElem> = |elem, _, styles| {
         .pack())
 };
 
+const PAGEBREAK_RULE: ShowFn<PagebreakElem> = |elem, _, styles| {
+    // Weak page breaks only take effect on non-empty pages. As there are no
+    // pages in HTML, they would at best produce an empty section, so we drop
+    // them.
+    if elem.weak.get(styles) {
+        return Ok(Content::empty());
+    }
+
+    // In section mode, the page break only marks where the document's body
+    // is split into sections once it is assembled.
+    let marker = if elem.sections.get(styles) { tag::section } else { tag::hr };
+    Ok(HtmlElem::new(marker).with_attr(attr::class, "pagebreak").pack())
+};
+
 const IMAGE_RULE: ShowFn<ImageElem> = |elem, engine, styles| {
-    let image = elem.decode(engine, styles)?;
+    let image = match elem.decode_or_fallback(engine, styles)? {
+        Ok(image) => image,
+        Err(fallback) => return Ok(fallback),
+    };
 
     let mut attrs = HtmlAttrs::new();
-    attrs.push(attr::src, typst_svg::convert_image_to_base64_url(&image));
-
     if let Some(alt) = elem.alt.get_cloned(styles) {
         attrs.push(attr::alt, alt);
     }
@@ -781,5 +932,9 @@ const IMAGE_RULE: ShowFn<ImageElem> = |elem, engine, styles| {
         Sizing::Fr(_) => {}
     }
 
-    Ok(HtmlElem::new(tag::img).with_attrs(attrs).with_styles(inline).pack())
+    Ok(HtmlElem::new(tag::img)
+        .with_attrs(attrs)
+        .with_styles(inline)
+        .with_image(image)
+        .pack())
 };
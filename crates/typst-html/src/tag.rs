@@ -41,6 +41,7 @@ pub const embed: HtmlTag = HtmlTag::constant("embed");
 pub const fieldset: HtmlTag = HtmlTag::constant("fieldset");
 pub const figcaption: HtmlTag = HtmlTag::constant("figcaption");
 pub const figure: HtmlTag = HtmlTag::constant("figure");
+pub const foreignObject: HtmlTag = HtmlTag::constant("foreignObject");
 pub const footer: HtmlTag = HtmlTag::constant("footer");
 pub const form: HtmlTag = HtmlTag::constant("form");
 pub const h1: HtmlTag = HtmlTag::constant("h1");
@@ -67,6 +68,7 @@ pub const link: HtmlTag = HtmlTag::constant("link");
 pub const main: HtmlTag = HtmlTag::constant("main");
 pub const map: HtmlTag = HtmlTag::constant("map");
 pub const mark: HtmlTag = HtmlTag::constant("mark");
+pub const math: HtmlTag = HtmlTag::constant("math");
 pub const menu: HtmlTag = HtmlTag::constant("menu");
 pub const meta: HtmlTag = HtmlTag::constant("meta");
 pub const meter: HtmlTag = HtmlTag::constant("meter");
@@ -100,6 +102,7 @@ pub const style: HtmlTag = HtmlTag::constant("style");
 pub const sub: HtmlTag = HtmlTag::constant("sub");
 pub const summary: HtmlTag = HtmlTag::constant("summary");
 pub const sup: HtmlTag = HtmlTag::constant("sup");
+pub const svg: HtmlTag = HtmlTag::constant("svg");
 pub const table: HtmlTag = HtmlTag::constant("table");
 pub const tbody: HtmlTag = HtmlTag::constant("tbody");
 pub const td: HtmlTag = HtmlTag::constant("td");
@@ -149,6 +152,12 @@ pub fn is_escapable_raw(tag: HtmlTag) -> bool {
     matches!(tag, self::textarea | self::title)
 }
 
+/// Whether this is the root tag of foreign content, that is, of an embedded
+/// SVG or MathML tree.
+pub fn is_foreign_root(tag: HtmlTag) -> bool {
+    matches!(tag, self::svg | self::math)
+}
+
 /// Whether an element is considered metadata.
 pub fn is_metadata(tag: HtmlTag) -> bool {
     matches!(
@@ -249,6 +258,8 @@ pub fn is_inline_by_default(tag: HtmlTag) -> bool {
             | self::sup
             | self::var
             | self::u
+            | self::svg
+            | self::math
     )
 }
 
@@ -15,14 +15,15 @@ use typst_library::diag::{At, Hint, HintedStrResult, SourceResult, bail};
 use typst_library::engine::Engine;
 use typst_library::foundations::{
     Args, Array, AutoValue, CastInfo, Content, Context, Datetime, Dict, Duration,
-    FromValue, IntoValue, NativeFuncData, NativeFuncPtr, NoneValue, ParamInfo,
-    PositiveF64, Reflect, Scope, Str, Type, Value,
+    FromValue, IntoValue, NativeElement, NativeFuncData, NativeFuncPtr, NoneValue,
+    ParamInfo, PositiveF64, Reflect, Scope, Str, Type, Value,
 };
 use typst_library::layout::{Axes, Axis, Dir, Length};
 use typst_library::visualize::Color;
-use typst_macros::cast;
+use typst_macros::{Cast, cast, func};
+use typst_syntax::{Span, Spanned};
 
-use crate::{HtmlAttr, HtmlAttrs, HtmlElem, HtmlTag, css, tag};
+use crate::{HtmlAttr, HtmlAttrs, HtmlElem, HtmlTag, attr, css, tag};
 
 /// Hook up all typed HTML definitions.
 pub(super) fn define(html: &mut Scope) {
@@ -646,7 +647,17 @@ impl IntoAttr for IconSize {
 }
 
 /// <https://html.spec.whatwg.org/#image-candidate-string>
-pub struct ImageCandidate(EcoString);
+pub struct ImageCandidate {
+    src: EcoString,
+    descriptor: Option<ImageDescriptor>,
+}
+
+/// The width or pixel density descriptor of an image candidate.
+#[derive(Copy, Clone, PartialEq)]
+enum ImageDescriptor {
+    Width(NonZeroU64),
+    Density(PositiveF64),
+}
 
 cast! {
     ImageCandidate,
@@ -662,23 +673,208 @@ cast! {
             bail!("`src` must not be empty");
         } else if src.starts_with(',') || src.ends_with(',') {
             bail!("`src` must not start or end with a comma");
+        } else if src.chars().any(|c| c.is_ascii_whitespace()) {
+            bail!(
+                "`src` must not contain whitespace";
+                hint: "percent-encode spaces in URLs as `%20`"
+            );
         }
 
-        let mut out = src;
-        match (width, density) {
-            (None, None) => {}
-            (Some(width), None) => write!(out, " {width}w").unwrap(),
-            (None, Some(density)) => write!(out, " {}d", density.get()).unwrap(),
+        let descriptor = match (width, density) {
+            (None, None) => None,
+            (Some(width), None) => Some(ImageDescriptor::Width(width)),
+            (None, Some(density)) => Some(ImageDescriptor::Density(density)),
             (Some(_), Some(_)) => bail!("cannot specify both `width` and `density`"),
-        }
+        };
 
-        Self(out)
+        Self { src, descriptor }
     },
 }
 
 impl IntoAttr for ImageCandidate {
     fn into_attr(self) -> EcoString {
-        self.0
+        let mut out = self.src;
+        match self.descriptor {
+            None => {}
+            Some(ImageDescriptor::Width(width)) => write!(out, " {width}w").unwrap(),
+            Some(ImageDescriptor::Density(density)) => {
+                write!(out, " {}x", density.get()).unwrap()
+            }
+        }
+        out
+    }
+}
+
+/// 画像候補の配列から`srcset`属性の値を組み立てます。
+///
+/// 各候補は`html.img`の`srcset`属性と同様に、`src`と、
+/// `width`（ピクセル単位の画像の幅）または`density`（ピクセル密度）のいずれか一方を持つ辞書で指定します。
+/// 候補の組み合わせは、HTMLの仕様に照らして検証されます。
+/// 幅を指定する場合はすべての候補に幅を指定する必要があり、
+/// 同じ幅や同じピクセル密度の候補を複数指定することはできません。
+///
+/// 組み立てた文字列は、[`html.elem`]($html.elem)の`attrs`に渡せます。
+///
+/// ```example
+/// #html.srcset(
+///   (src: "/tiger-480.jpg", width: 480),
+///   (src: "/tiger-960.jpg", width: 960),
+/// )
+/// ```
+#[func]
+pub fn srcset(
+    span: Span,
+    /// 画像候補。
+    #[variadic]
+    candidates: Vec<Spanned<ImageCandidate>>,
+) -> SourceResult<Str> {
+    if candidates.is_empty() {
+        bail!(span, "expected at least one image candidate");
+    }
+
+    let widths = candidates
+        .iter()
+        .filter(|c| matches!(c.v.descriptor, Some(ImageDescriptor::Width(_))))
+        .count();
+    let mut seen = vec![];
+    for Spanned { v: candidate, span } in &candidates {
+        if widths > 0 && !matches!(candidate.descriptor, Some(ImageDescriptor::Width(_)))
+        {
+            bail!(
+                *span,
+                "image candidate must specify a `width`";
+                hint: "if one candidate specifies a width, all of them must"
+            );
+        }
+
+        // A candidate without descriptor has a pixel density of one.
+        let descriptor = candidate
+            .descriptor
+            .unwrap_or(ImageDescriptor::Density(PositiveF64::new(1.0).unwrap()));
+        if seen.contains(&descriptor) {
+            bail!(*span, "duplicate image candidate descriptor");
+        }
+        seen.push(descriptor);
+    }
+
+    let mut out = EcoString::new();
+    for (i, candidate) in candidates.into_iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(&candidate.v.into_attr());
+    }
+
+    Ok(out.into())
+}
+
+/// リソースを先読みする`<link rel="preload">`要素を作成します。
+///
+/// ページの表示に必要なフォントやスタイルシートなどを、
+/// ブラウザが通常より早い段階で読み込み始めるように指示します。
+/// 読み込むリソースの種類は`as`属性として出力され、HTMLの仕様に照らして検証されます。
+///
+/// フォントは常にCORSモードで読み込まれるため、
+/// `{"font"}`を指定した場合は`crossorigin`属性が自動的に付与されます。
+///
+/// `<link rel="preload">`は`<body>`の内部にも配置できますが、
+/// 効果を最大限に得るには文書の先頭付近に配置してください。
+///
+/// ```typ
+/// #html.preload("/fonts/body.woff2", "font")
+/// #html.preload("/style.css", "style")
+/// ```
+#[func]
+pub fn preload(
+    /// 先読みするリソースのURL。
+    href: Str,
+    /// リソースの種類。
+    destination: PreloadDestination,
+    /// リソースをCORSモードで読み込むかどうか。
+    #[named]
+    #[default(false)]
+    crossorigin: bool,
+) -> Content {
+    let mut attrs = HtmlAttrs::new();
+    attrs.push(attr::rel, "preload");
+    attrs.push(attr::href, href);
+    attrs.push(attr::r#as, destination.into_attr());
+    if crossorigin || destination == PreloadDestination::Font {
+        attrs.push(attr::crossorigin, "");
+    }
+    HtmlElem::new(tag::link).with_attrs(attrs).pack()
+}
+
+/// オリジンへの接続を事前に確立する`<link rel="preconnect">`要素を作成します。
+///
+/// 外部のオリジンからフォントやスクリプトを読み込む場合に、
+/// DNSの解決やTLSのハンドシェイクを前もって済ませておくことで読み込みを高速化できます。
+///
+/// ```typ
+/// #html.preconnect("https://fonts.example.com", crossorigin: true)
+/// ```
+#[func]
+pub fn preconnect(
+    /// 接続するオリジンのURL。
+    href: Str,
+    /// CORSモードでの接続を確立するかどうか。
+    ///
+    /// フォントのようにCORSモードで読み込まれるリソースには、
+    /// この指定をした接続のみが再利用されます。
+    #[named]
+    #[default(false)]
+    crossorigin: bool,
+) -> Content {
+    let mut attrs = HtmlAttrs::new();
+    attrs.push(attr::rel, "preconnect");
+    attrs.push(attr::href, href);
+    if crossorigin {
+        attrs.push(attr::crossorigin, "");
+    }
+    HtmlElem::new(tag::link).with_attrs(attrs).pack()
+}
+
+/// The kind of resource fetched by `<link rel="preload">`.
+///
+/// <https://fetch.spec.whatwg.org/#concept-potential-destination>
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum PreloadDestination {
+    /// 音声ファイル。
+    Audio,
+    /// `<iframe>`で読み込まれる文書。
+    Document,
+    /// `<embed>`で読み込まれるリソース。
+    Embed,
+    /// `fetch`や`XMLHttpRequest`で読み込まれるリソース。
+    Fetch,
+    /// フォントファイル。
+    Font,
+    /// 画像ファイル。
+    Image,
+    /// JSONファイル。
+    Json,
+    /// `<object>`で読み込まれるリソース。
+    Object,
+    /// JavaScriptファイル。
+    Script,
+    /// CSSスタイルシート。
+    Style,
+    /// WebVTTなどのテキストトラック。
+    Track,
+    /// 動画ファイル。
+    Video,
+    /// Web Workerのスクリプト。
+    Worker,
+}
+
+impl IntoAttr for PreloadDestination {
+    fn into_attr(self) -> EcoString {
+        // The names of the variants are exactly the values of the `as`
+        // attribute.
+        match self.into_value() {
+            Value::Str(s) => s.into(),
+            _ => unreachable!(),
+        }
     }
 }
 
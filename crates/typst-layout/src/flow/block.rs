@@ -91,7 +91,7 @@ pub fn layout_single_block(
 
     // Add fill and/or stroke.
     if fill.is_some() || stroke.iter().any(Option::is_some) {
-        fill_and_stroke(&mut frame, fill, &stroke, &outset, &radius, elem.span());
+        fill_and_stroke(engine, &mut frame, fill, &stroke, &outset, &radius, elem.span());
     }
 
     // Assign label to each frame in the fragment.
@@ -236,7 +236,15 @@ pub fn layout_multi_block(
 
         // Add fill and/or stroke.
         if has_fill_or_stroke && (i > 0 || !skip_first) {
-            fill_and_stroke(frame, fill.clone(), &stroke, &outset, &radius, elem.span());
+            fill_and_stroke(
+                engine,
+                frame,
+                fill.clone(),
+                &stroke,
+                &outset,
+                &radius,
+                elem.span(),
+            );
         }
     }
 
@@ -419,9 +419,10 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
         flow_need: Abs,
         migratable: bool,
     ) -> FlowResult<()> {
-        // Ignore reference footnotes and already processed ones.
+        // Ignore reference footnotes, repetitions in table headers and footers,
+        // and already processed ones.
         let loc = elem.location().unwrap();
-        if elem.is_ref() || self.skipped(loc) {
+        if elem.is_ref() || elem.is_repeated() || self.skipped(loc) {
             return Ok(());
         }
 
@@ -1,10 +1,12 @@
+use std::cell::RefCell;
 use std::fmt::Debug;
 
+use ecow::EcoVec;
 use rustc_hash::FxHashMap;
 use typst_library::diag::{SourceResult, bail};
 use typst_library::engine::Engine;
 use typst_library::foundations::{Resolve, StyleChain};
-use typst_library::introspection::Locator;
+use typst_library::introspection::{Location, Locator};
 use typst_library::layout::grid::resolve::{
     Cell, CellGrid, Header, LinePosition, Repeatable,
 };
@@ -19,8 +21,8 @@ use typst_syntax::Span;
 use typst_utils::Numeric;
 
 use super::{
-    LineSegment, Rowspan, UnbreakableRowGroup, generate_line_segments,
-    hline_stroke_at_column, layout_cell, vline_stroke_at_row,
+    LineSegment, Rowspan, UnbreakableRowGroup, footnote_originals,
+    generate_line_segments, hline_stroke_at_column, layout_cell, vline_stroke_at_row,
 };
 
 /// Performs grid layout.
@@ -31,6 +33,9 @@ pub struct GridLayouter<'a> {
     pub(super) regions: Regions<'a>,
     /// The locators for the each cell in the cell grid.
     pub(super) cell_locators: FxHashMap<Axes<usize>, Locator<'a>>,
+    /// The footnotes of the first laid out occurrence of each cell in a
+    /// repeated header or footer, which its repetitions refer back to.
+    pub(super) footnotes: RefCell<FxHashMap<Axes<usize>, EcoVec<(u128, Location)>>>,
     /// The inherited styles.
     pub(super) styles: StyleChain<'a>,
     /// Resolved column sizes.
@@ -265,6 +270,7 @@ impl<'a> GridLayouter<'a> {
             grid,
             regions,
             cell_locators,
+            footnotes: RefCell::default(),
             styles,
             rcols: vec![Abs::zero(); grid.cols.len()],
             width: Abs::zero(),
@@ -310,6 +316,58 @@ impl<'a> GridLayouter<'a> {
         cell_locator
     }
 
+    /// Layouts a cell at the given position.
+    ///
+    /// Footnotes in repeated headers and footers refer back to the first laid
+    /// out occurrence of their cell. For a repeated footer, this is its first
+    /// repetition, as the final footer is only laid out at the very end. When
+    /// `record` is false, as during measurement, no new occurrence is
+    /// recorded.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn layout_cell(
+        &self,
+        cell: &Cell,
+        pos: Axes<usize>,
+        disambiguator: usize,
+        engine: &mut Engine,
+        regions: Regions,
+        is_repeated: bool,
+        record: bool,
+    ) -> SourceResult<Fragment> {
+        let in_footer = self
+            .grid
+            .footer
+            .as_ref()
+            .and_then(Repeatable::as_repeated)
+            .is_some_and(|footer| pos.y >= footer.start);
+        let in_header = self
+            .grid
+            .headers
+            .iter()
+            .any(|header| header.repeated && header.range.contains(&pos.y));
+
+        let originals = (is_repeated || in_footer)
+            .then(|| self.footnotes.borrow().get(&pos).cloned())
+            .flatten();
+        let recording = record && (in_header || in_footer) && originals.is_none();
+
+        let locator = self.cell_locator(pos, disambiguator);
+        let fragment = layout_cell(
+            cell,
+            engine,
+            locator,
+            self.styles,
+            regions,
+            is_repeated,
+            originals,
+        )?;
+        if recording {
+            self.footnotes.borrow_mut().insert(pos, footnote_originals(&fragment));
+        }
+
+        Ok(fragment)
+    }
+
     /// Determines the columns sizes and then layouts the grid row-by-row.
     pub fn layout(mut self, engine: &mut Engine) -> SourceResult<Fragment> {
         self.measure_columns(engine)?;
@@ -1077,16 +1135,17 @@ impl<'a> GridLayouter<'a> {
 
                 let size = Size::new(available, height);
                 let pod = Region::new(size, Axes::splat(false));
-                let locator = self.cell_locator(parent, 0);
-                let frame = layout_cell(
-                    cell,
-                    engine,
-                    locator,
-                    self.styles,
-                    pod.into(),
-                    self.row_state.is_being_repeated,
-                )?
-                .into_frame();
+                let frame = self
+                    .layout_cell(
+                        cell,
+                        parent,
+                        0,
+                        engine,
+                        pod.into(),
+                        self.row_state.is_being_repeated,
+                        false,
+                    )?
+                    .into_frame();
                 resolved.set_max(frame.width() - already_covered_width);
             }
 
@@ -1324,16 +1383,17 @@ impl<'a> GridLayouter<'a> {
                 pod
             };
 
-            let locator = self.cell_locator(parent, disambiguator);
-            let frames = layout_cell(
-                cell,
-                engine,
-                locator,
-                self.styles,
-                pod,
-                self.row_state.is_being_repeated,
-            )?
-            .into_frames();
+            let frames = self
+                .layout_cell(
+                    cell,
+                    parent,
+                    disambiguator,
+                    engine,
+                    pod,
+                    self.row_state.is_being_repeated,
+                    false,
+                )?
+                .into_frames();
 
             // HACK: Also consider frames empty if they only contain tags. Table
             // and grid cells need to be locatable for pdf accessibility, but
@@ -1497,16 +1557,17 @@ impl<'a> GridLayouter<'a> {
                         // rows.
                         pod.full = self.regions.full;
                     }
-                    let locator = self.cell_locator(Axes::new(x, y), disambiguator);
-                    let frame = layout_cell(
-                        cell,
-                        engine,
-                        locator,
-                        self.styles,
-                        pod,
-                        self.row_state.is_being_repeated,
-                    )?
-                    .into_frame();
+                    let frame = self
+                        .layout_cell(
+                            cell,
+                            Axes::new(x, y),
+                            disambiguator,
+                            engine,
+                            pod,
+                            self.row_state.is_being_repeated,
+                            true,
+                        )?
+                        .into_frame();
                     let mut pos = offset;
                     if self.is_rtl {
                         // In RTL cells expand to the left, thus the position
@@ -1553,14 +1614,14 @@ impl<'a> GridLayouter<'a> {
                     pod.size.x = width;
 
                     // Push the layouted frames into the individual output frames.
-                    let locator = self.cell_locator(Axes::new(x, y), disambiguator);
-                    let fragment = layout_cell(
+                    let fragment = self.layout_cell(
                         cell,
+                        Axes::new(x, y),
+                        disambiguator,
                         engine,
-                        locator,
-                        self.styles,
                         pod,
                         self.row_state.is_being_repeated,
+                        true,
                     )?;
                     for (output, frame) in outputs.iter_mut().zip(fragment) {
                         let mut pos = offset;
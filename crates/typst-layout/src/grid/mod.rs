@@ -5,15 +5,16 @@ mod rowspans;
 
 pub use self::layouter::GridLayouter;
 
+use ecow::EcoVec;
 use typst_library::diag::SourceResult;
 use typst_library::engine::Engine;
 use typst_library::foundations::{Content, NativeElement, Packed, StyleChain};
 use typst_library::introspection::{Location, Locator, SplitLocator, Tag, TagFlags};
 use typst_library::layout::grid::resolve::Cell;
 use typst_library::layout::{
-    Fragment, FrameItem, FrameParent, GridCell, GridElem, Inherit, Point, Regions,
+    Fragment, Frame, FrameItem, FrameParent, GridCell, GridElem, Inherit, Point, Regions,
 };
-use typst_library::model::{TableCell, TableElem};
+use typst_library::model::{FootnoteElem, TableCell, TableElem};
 
 use self::layouter::RowPiece;
 use self::lines::{
@@ -27,6 +28,10 @@ use self::rowspans::{Rowspan, UnbreakableRowGroup};
 /// layouted as. For normal cells, it is always `0`, but for headers and
 /// footers, it indicates the index of the header/footer among all. See the
 /// [`Locator`] docs for more details on the concepts behind this.
+///
+/// The `originals` are the footnotes of the first laid out occurrence of this
+/// cell if it belongs to a repeated header or footer. See
+/// [`footnote_originals`].
 pub fn layout_cell(
     cell: &Cell,
     engine: &mut Engine,
@@ -34,6 +39,7 @@ pub fn layout_cell(
     styles: StyleChain,
     regions: Regions,
     is_repeated: bool,
+    originals: Option<EcoVec<(u128, Location)>>,
 ) -> SourceResult<Fragment> {
     // HACK: manually generate tags for table and grid cells. Ideally table and
     // grid cells could just be marked as locatable, but the tags are somehow
@@ -51,6 +57,15 @@ pub fn layout_cell(
         tags = Some(generate_tags(grid_cell, &mut locator, engine));
     }
 
+    // Footnotes in repeated headers and footers refer back to their first
+    // occurrence instead of creating new entries.
+    let repeated =
+        originals.map(|originals| FootnoteElem::originals.set(Some(originals)).wrap());
+    let styles = match &repeated {
+        Some(repeated) => styles.chain(repeated),
+        None => styles,
+    };
+
     let locator = locator.next(&cell.body.span());
     let fragment = crate::layout_fragment(engine, &cell.body, locator, styles, regions)?;
 
@@ -84,6 +99,34 @@ pub fn layout_cell(
     Ok(Fragment::frames(frames))
 }
 
+/// Collects the footnotes in the frames of a laid out cell, keyed by
+/// [`FootnoteElem::key`], such that repetitions of the cell can refer back to
+/// them.
+pub(super) fn footnote_originals(fragment: &Fragment) -> EcoVec<(u128, Location)> {
+    fn visit(frame: &Frame, originals: &mut EcoVec<(u128, Location)>) {
+        for (_, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => visit(&group.frame, originals),
+                FrameItem::Tag(Tag::Start(elem, _)) => {
+                    if let Some(note) = elem.to_packed::<FootnoteElem>()
+                        && !note.is_ref()
+                        && !note.is_repeated()
+                    {
+                        originals.push((note.key(), note.location().unwrap()));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut originals = EcoVec::new();
+    for frame in fragment {
+        visit(frame, &mut originals);
+    }
+    originals
+}
+
 fn generate_tags<T: NativeElement>(
     mut cell: Packed<T>,
     locator: &mut SplitLocator,
@@ -5,7 +5,7 @@ use typst_library::layout::grid::resolve::Repeatable;
 use typst_library::layout::{Abs, Axes, Frame, Point, Region, Regions, Size, Sizing};
 
 use super::layouter::{Row, points};
-use super::{Cell, GridLayouter, layout_cell};
+use super::{Cell, GridLayouter};
 
 /// All information needed to layout a single rowspan.
 pub struct Rowspan {
@@ -148,9 +148,15 @@ impl GridLayouter<'_> {
         }
 
         // Push the layouted frames directly into the finished frames.
-        let locator = self.cell_locator(Axes::new(x, y), disambiguator);
-        let fragment =
-            layout_cell(cell, engine, locator, self.styles, pod, is_being_repeated)?;
+        let fragment = self.layout_cell(
+            cell,
+            Axes::new(x, y),
+            disambiguator,
+            engine,
+            pod,
+            is_being_repeated,
+            true,
+        )?;
         let (current_region, current_header_row_height) = current_region_data.unzip();
 
         // Clever trick to process finished header rows:
@@ -12,11 +12,16 @@ use typst_library::visualize::{Curve, Image, ImageElem, ImageFit};
 pub fn layout_image(
     elem: &Packed<ImageElem>,
     engine: &mut Engine,
-    _: Locator,
+    locator: Locator,
     styles: StyleChain,
     region: Region,
 ) -> SourceResult<Frame> {
-    let image = elem.decode(engine, styles)?;
+    let image = match elem.decode_or_fallback(engine, styles)? {
+        Ok(image) => image,
+        Err(fallback) => {
+            return crate::layout_frame(engine, &fallback, locator, styles, region);
+        }
+    };
 
     // Determine the image's pixel aspect ratio.
     let pxw = image.width();
@@ -68,7 +68,7 @@ pub fn layout_box(
 
     // Add fill and/or stroke.
     if fill.is_some() || stroke.iter().any(Option::is_some) {
-        fill_and_stroke(&mut frame, fill, &stroke, &outset, &radius, elem.span());
+        fill_and_stroke(engine, &mut frame, fill, &stroke, &outset, &radius, elem.span());
     }
 
     // Assign label to the frame.
@@ -75,7 +75,12 @@ pub fn layout_enum(
     styles: StyleChain,
     regions: Regions,
 ) -> SourceResult<Fragment> {
-    let numbering = elem.numbering.get_ref(styles);
+    // Ordinals depend on the text language, which patterns applied without a
+    // context don't know about.
+    let numbering = &elem
+        .numbering
+        .get_cloned(styles)
+        .with_lang(styles.get(TextElem::lang));
     let reversed = elem.reversed.get(styles);
     let indent = elem.indent.get(styles);
     let body_indent = elem.body_indent.get(styles);
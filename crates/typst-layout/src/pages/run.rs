@@ -130,7 +130,11 @@ fn layout_page_run_impl(
     let header_ascent = styles.resolve(PageElem::header_ascent).relative_to(margin.top);
     let footer_descent =
         styles.resolve(PageElem::footer_descent).relative_to(margin.bottom);
-    let numbering = styles.get_ref(PageElem::numbering);
+    // Ordinals depend on the text language, which exporters like PDF page
+    // labels don't know about.
+    let numbering = styles
+        .get_cloned(PageElem::numbering)
+        .map(|numbering| numbering.with_lang(styles.get(TextElem::lang)));
     let supplement = match styles.get_cloned(PageElem::supplement) {
         Smart::Auto => TextElem::packed(PageElem::local_name_in(styles)),
         Smart::Custom(content) => content.unwrap_or_default(),
@@ -147,6 +151,7 @@ fn layout_page_run_impl(
     let numbering_marginal = numbering.as_ref().map(|numbering| {
         let both = match numbering {
             Numbering::Pattern(pattern) => pattern.pieces() >= 2,
+            Numbering::Symbols(_) | Numbering::Alphabet(_) => false,
             Numbering::Func(_) => true,
         };
 
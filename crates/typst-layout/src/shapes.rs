@@ -1,7 +1,7 @@
 use std::f64::consts::SQRT_2;
 
 use kurbo::{CubicBez, ParamCurveExtrema};
-use typst_library::diag::{SourceResult, bail};
+use typst_library::diag::{SourceResult, bail, warning};
 use typst_library::engine::Engine;
 use typst_library::foundations::{Content, Packed, Resolve, Smart, StyleChain};
 use typst_library::introspection::Locator;
@@ -11,8 +11,8 @@ use typst_library::layout::{
 };
 use typst_library::visualize::{
     CircleElem, CloseMode, Curve, CurveComponent, CurveElem, EllipseElem, FillRule,
-    FixedStroke, Geometry, LineCap, LineElem, Paint, PathElem, PathVertex, PolygonElem,
-    RectElem, Shape, SquareElem, Stroke,
+    FixedStroke, Geometry, LineCap, LineElem, LineMarker, Paint, PathElem, PathVertex,
+    PolygonElem, RectElem, Shape, SquareElem, Stroke,
 };
 use typst_syntax::Span;
 use typst_utils::{Get, Numeric};
@@ -48,8 +48,36 @@ pub fn layout_line(
     }
 
     let mut frame = Frame::soft(size);
-    let shape = Geometry::Line(delta.to_point()).stroked(stroke);
-    frame.push(start.to_point(), FrameItem::Shape(shape, elem.span()));
+    let start = start.to_point();
+    let end = start + delta.to_point();
+    let start_marker = elem
+        .start_cap
+        .get(styles)
+        .and_then(|marker| layout_marker(marker, start, start - end, &stroke));
+    let end_marker = elem
+        .end_cap
+        .get(styles)
+        .and_then(|marker| layout_marker(marker, end, end - start, &stroke));
+
+    // Pull the ends of the line back into the markers, so that the stroke's
+    // caps don't stick out of them.
+    let inset = |marker: &Option<(Shape, Abs)>| {
+        marker.as_ref().map_or(Abs::zero(), |&(_, inset)| inset)
+    };
+    let (start_inset, end_inset) = (inset(&start_marker), inset(&end_marker));
+    let length = delta.to_point().hypot();
+    let (mut from, mut to) = (start, end);
+    if start_inset + end_inset < length {
+        let direction = delta.to_point() / length.to_raw();
+        from = from + direction * start_inset.to_raw();
+        to = to - direction * end_inset.to_raw();
+    }
+
+    let shape = Geometry::Line(to - from).stroked(stroke);
+    frame.push(from, FrameItem::Shape(shape, elem.span()));
+    for (marker, _) in start_marker.into_iter().chain(end_marker) {
+        frame.push(Point::zero(), FrameItem::Shape(marker, elem.span()));
+    }
     Ok(frame)
 }
 
@@ -57,7 +85,7 @@ pub fn layout_line(
 #[typst_macros::time(span = elem.span())]
 pub fn layout_path(
     elem: &Packed<PathElem>,
-    _: &mut Engine,
+    engine: &mut Engine,
     _: Locator,
     styles: StyleChain,
     region: Region,
@@ -133,6 +161,7 @@ pub fn layout_path(
     };
 
     let mut frame = Frame::soft(size);
+    warn_single_double(engine, elem.span(), [&stroke]);
     let shape = Shape {
         geometry: Geometry::Curve(curve),
         stroke,
@@ -147,7 +176,7 @@ pub fn layout_path(
 #[typst_macros::time(span = elem.span())]
 pub fn layout_curve(
     elem: &Packed<CurveElem>,
-    _: &mut Engine,
+    engine: &mut Engine,
     _: Locator,
     styles: StyleChain,
     region: Region,
@@ -202,7 +231,7 @@ pub fn layout_curve(
         }
     }
 
-    let (curve, size) = builder.finish();
+    let (mut curve, size) = builder.finish();
     if curve.is_empty() {
         return Ok(Frame::soft(size));
     }
@@ -220,7 +249,34 @@ pub fn layout_curve(
         Smart::Custom(stroke) => stroke.map(Stroke::unwrap_or_default),
     };
 
+    // Prepare the markers at the ends.
+    let mut markers = vec![];
+    if let Some(stroke) = &stroke {
+        let (mut start_inset, mut end_inset) = (Abs::zero(), Abs::zero());
+        if let Some(marker) = elem.start_cap.get(styles)
+            && let Some((start, direction)) = curve.start_direction()
+            && let Some((shape, inset)) = layout_marker(marker, start, -direction, stroke)
+        {
+            markers.push(shape);
+            start_inset = inset;
+        }
+        if let Some(marker) = elem.end_cap.get(styles)
+            && let Some((end, direction)) = curve.end_direction()
+            && let Some((shape, inset)) = layout_marker(marker, end, direction, stroke)
+        {
+            markers.push(shape);
+            end_inset = inset;
+        }
+
+        // Pull the ends back into the markers, as for lines. Filled curves
+        // are kept as-is, since shortening them would change the fill.
+        if fill.is_none() {
+            curve.shorten(start_inset, end_inset);
+        }
+    }
+
     let mut frame = Frame::soft(size);
+    warn_single_double(engine, elem.span(), [&stroke]);
     let shape = Shape {
         geometry: Geometry::Curve(curve),
         stroke,
@@ -228,9 +284,63 @@ pub fn layout_curve(
         fill_rule,
     };
     frame.push(Point::zero(), FrameItem::Shape(shape, elem.span()));
+    for marker in markers {
+        frame.push(Point::zero(), FrameItem::Shape(marker, elem.span()));
+    }
     Ok(frame)
 }
 
+/// Creates the shape of a marker at the end of a line or curve, along with
+/// the distance by which the line should be shortened to end inside of it.
+///
+/// The `tip` is the end of the line and the `direction` points away from the
+/// line. The marker's size is proportional to the stroke's thickness.
+fn layout_marker(
+    marker: LineMarker,
+    tip: Point,
+    direction: Point,
+    stroke: &FixedStroke,
+) -> Option<(Shape, Abs)> {
+    let length = direction.hypot();
+    if length.is_zero() {
+        return None;
+    }
+
+    // Hairlines have no thickness, so we size their markers as if they were
+    // half a point thick.
+    let t = stroke.thickness.max(Abs::pt(0.5));
+    let u = direction / length.to_raw();
+    let n = Point::new(-u.y, u.x);
+    let paint = stroke.paint.clone();
+
+    Some(match marker {
+        LineMarker::Arrow => {
+            let (long, wide) = (t * 5.0, t * 2.0);
+            let base = tip - u * long.to_raw();
+            let mut curve = Curve::new();
+            curve.move_(tip);
+            curve.line(base + n * wide.to_raw());
+            curve.line(base - n * wide.to_raw());
+            curve.close();
+            (Geometry::Curve(curve).filled(paint), long / 2.0)
+        }
+        LineMarker::Circle => {
+            let radius = t * 2.0;
+            let mut curve = Curve::ellipse(Size::splat(radius * 2.0));
+            curve.translate(tip - Point::splat(radius));
+            (Geometry::Curve(curve).filled(paint), Abs::zero())
+        }
+        LineMarker::Bar => {
+            let half = t * 3.0;
+            let mut curve = Curve::new();
+            curve.move_(tip + n * half.to_raw());
+            curve.line(tip - n * half.to_raw());
+            let stroke = FixedStroke { paint, thickness: t, ..FixedStroke::default() };
+            (Geometry::Curve(curve).stroked(stroke), Abs::zero())
+        }
+    })
+}
+
 /// Builds a `Curve` from a [`CurveElem`]'s parts.
 struct CurveBuilder<'a> {
     /// The output curve.
@@ -398,7 +508,7 @@ fn point_to_kurbo(point: Point) -> kurbo::Point {
 #[typst_macros::time(span = elem.span())]
 pub fn layout_polygon(
     elem: &Packed<PolygonElem>,
-    _: &mut Engine,
+    engine: &mut Engine,
     _: Locator,
     styles: StyleChain,
     region: Region,
@@ -438,6 +548,7 @@ pub fn layout_polygon(
     }
     curve.close();
 
+    warn_single_double(engine, elem.span(), [&stroke]);
     let shape = Shape {
         geometry: Geometry::Curve(curve),
         stroke,
@@ -664,6 +775,7 @@ fn layout_shape(
             let outset = outset.unwrap_or_default().relative_to(frame.size());
             let size = frame.size() + outset.sum_by_axis();
             let pos = Point::new(-outset.left, -outset.top);
+            warn_single_double(engine, span, [&stroke.left]);
             let shape = Shape {
                 geometry: Geometry::Curve(Curve::ellipse(size)),
                 fill,
@@ -673,6 +785,7 @@ fn layout_shape(
             frame.prepend(pos, FrameItem::Shape(shape, span));
         } else {
             fill_and_stroke(
+                engine,
                 &mut frame,
                 fill,
                 &stroke,
@@ -759,6 +872,7 @@ pub fn clip_rect(
 
 /// Add a fill and stroke with optional radius and outset to the frame.
 pub fn fill_and_stroke(
+    engine: &mut Engine,
     frame: &mut Frame,
     fill: Option<Paint>,
     stroke: &Sides<Option<FixedStroke>>,
@@ -766,6 +880,10 @@ pub fn fill_and_stroke(
     radius: &Corners<Rel<Abs>>,
     span: Span,
 ) {
+    if !is_simple_rect(stroke, radius) {
+        warn_single_double(engine, span, stroke.iter());
+    }
+
     let outset = outset.relative_to(frame.size());
     let size = frame.size() + outset.sum_by_axis();
     let pos = Point::new(-outset.left, -outset.top);
@@ -786,13 +904,38 @@ pub fn styled_rect(
     fill: Option<Paint>,
     stroke: &Sides<Option<FixedStroke>>,
 ) -> Vec<Shape> {
-    if stroke.is_uniform() && radius.iter().cloned().all(Rel::is_zero) {
+    if is_simple_rect(stroke, radius) {
         simple_rect(size, fill, stroke.top.clone())
     } else {
         segmented_rect(size, radius, fill, stroke)
     }
 }
 
+/// Whether a rectangle can be drawn with the rect primitive, as it has the
+/// same stroke on all sides and no rounded corners.
+fn is_simple_rect(
+    stroke: &Sides<Option<FixedStroke>>,
+    radius: &Corners<Rel<Abs>>,
+) -> bool {
+    stroke.is_uniform() && radius.iter().cloned().all(Rel::is_zero)
+}
+
+/// Warns that a double stroke is drawn as a single line because the stroked
+/// shape is neither a line nor a simple rectangle.
+fn warn_single_double<'a>(
+    engine: &mut Engine,
+    span: Span,
+    strokes: impl IntoIterator<Item = &'a Option<FixedStroke>>,
+) {
+    if strokes.into_iter().flatten().any(|stroke| stroke.double.is_some()) {
+        engine.sink.warn(warning!(
+            span,
+            "double strokes are only supported on lines and rectangles";
+            hint: "this shape is stroked with a single line instead"
+        ));
+    }
+}
+
 /// Use rect primitive for the rectangle
 fn simple_rect(
     size: Size,
@@ -5,6 +5,8 @@ use std::ops::{Add, AddAssign};
 
 use comemo::Tracked;
 use ecow::{EcoString, EcoVec, eco_format};
+use indexmap::IndexMap;
+use rustc_hash::{FxBuildHasher, FxHashSet};
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use typst_syntax::{Span, Spanned};
@@ -201,20 +203,47 @@ impl Array {
     /// left-hand side of an assignment. Returns the default value if the index
     /// is out of bounds or fails with an error if no default value was
     /// specified.
+    ///
+    /// Instead of a single index, you can also pass an array of a start and an
+    /// end index to extract a subslice, like with [`array.slice`]. The start
+    /// index is inclusive and the end index is exclusive. Both may be negative
+    /// to index from the back and the end may be `{none}` to extract
+    /// everything until the end of the array. Subslices cannot be assigned to.
+    ///
+    /// ```example
+    /// #(1, 2, 3, 4).at(1) \
+    /// #(1, 2, 3, 4).at((1, 3)) \
+    /// #(1, 2, 3, 4).at((-2, none))
+    /// ```
     #[func]
     pub fn at(
         &self,
-        /// The index at which to retrieve the item. If negative, indexes from
-        /// the back.
-        index: i64,
+        /// The index at which to retrieve the item or an array of a start and
+        /// an end index. If negative, indexes from the back.
+        index: ArrayIndex,
         /// A default value to return if the index is out of bounds.
         #[named]
         default: Option<Value>,
     ) -> StrResult<Value> {
-        self.locate_opt(index, false)
-            .and_then(|i| self.0.get(i).cloned())
-            .or(default)
-            .ok_or_else(|| out_of_bounds_no_default(index, self.len()))
+        match index {
+            ArrayIndex::Single(index) => self
+                .locate_opt(index, false)
+                .and_then(|i| self.0.get(i).cloned())
+                .or(default)
+                .ok_or_else(|| out_of_bounds_no_default(index, self.len())),
+            ArrayIndex::Range(start, end) => {
+                let end = end.unwrap_or(self.len() as i64);
+                match (self.locate_opt(start, true), self.locate_opt(end, true)) {
+                    (Some(s), Some(e)) => {
+                        Ok(Array::from(&self.0[s..e.max(s)]).into_value())
+                    }
+                    (s, _) => default.ok_or_else(|| {
+                        let index = if s.is_none() { start } else { end };
+                        out_of_bounds_no_default(index, self.len())
+                    }),
+                }
+            }
+        }
     }
 
     /// Adds a value to the end of the array.
@@ -270,6 +299,57 @@ impl Array {
             .ok_or_else(|| out_of_bounds_no_default(index, self.len()))
     }
 
+    /// Moves the item at the specified index to another index, shifting the
+    /// items in between. Fails with an error if either index is out of bounds.
+    ///
+    /// ```example
+    /// #let tasks = ("write", "review", "merge")
+    /// #tasks.move-item(2, 0)
+    /// #tasks
+    /// ```
+    #[func]
+    pub fn move_item(
+        &mut self,
+        /// The index of the item to move. If negative, indexes from the back.
+        from: i64,
+        /// The index the item should have after the move. If negative,
+        /// indexes from the back.
+        to: i64,
+    ) -> StrResult<()> {
+        let from = self.locate(from, false)?;
+        let to = self.locate(to, false)?;
+        if from < to {
+            self.0.make_mut()[from..=to].rotate_left(1);
+        } else if to < from {
+            self.0.make_mut()[to..=from].rotate_right(1);
+        }
+        Ok(())
+    }
+
+    /// Swaps the items at two indices. Fails with an error if either index is
+    /// out of bounds.
+    ///
+    /// ```example
+    /// #let values = (1, 2, 3)
+    /// #values.swap(0, 2)
+    /// #values
+    /// ```
+    #[func]
+    pub fn swap(
+        &mut self,
+        /// The index of the first item. If negative, indexes from the back.
+        i: i64,
+        /// The index of the second item. If negative, indexes from the back.
+        j: i64,
+    ) -> StrResult<()> {
+        let i = self.locate(i, false)?;
+        let j = self.locate(j, false)?;
+        if i != j {
+            self.0.make_mut().swap(i, j);
+        }
+        Ok(())
+    }
+
     /// Extracts a subslice of the array. Fails with an error if the start or end
     /// index is out of bounds.
     #[func]
@@ -327,6 +407,33 @@ impl Array {
         Ok(None)
     }
 
+    /// Searches for an item for which the given function returns `{true}`,
+    /// starting at the end, and returns the last match or `{none}` if there
+    /// is no match.
+    ///
+    /// ```example
+    /// #(1, 2, 3, 4).find-last(calc.even)
+    /// ```
+    #[func]
+    pub fn find_last(
+        &self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to each item. Must return a boolean.
+        searcher: Func,
+    ) -> SourceResult<Option<Value>> {
+        for item in self.iter().rev() {
+            if searcher
+                .call(engine, context, [item.clone()])?
+                .cast::<bool>()
+                .at(searcher.span())?
+            {
+                return Ok(Some(item.clone()));
+            }
+        }
+        Ok(None)
+    }
+
     /// Searches for an item for which the given function returns `{true}` and
     /// returns the index of the first match or `{none}` if there is no match.
     #[func]
@@ -350,6 +457,32 @@ impl Array {
         Ok(None)
     }
 
+    /// Searches for the specified value and returns the index of its first
+    /// occurrence or `{none}` if the array does not contain it.
+    ///
+    /// ```example
+    /// #(1, 2, 3, 2).index-of(2) \
+    /// #(1, 2, 3, 2).index-of(2, start: 2) \
+    /// #(1, 2, 3).index-of(4)
+    /// ```
+    #[func]
+    pub fn index_of(
+        &self,
+        /// The value to search for.
+        value: Value,
+        /// The index at which to start searching. If negative, it is counted
+        /// from the back of the array.
+        #[named]
+        #[default(0)]
+        start: i64,
+    ) -> StrResult<Option<i64>> {
+        let start = self.locate(start, true)?;
+        Ok(self.0[start..]
+            .iter()
+            .position(|item| ops::equal(item, &value))
+            .map(|i| (start + i) as i64))
+    }
+
     /// Create an array consisting of a sequence of numbers.
     ///
     /// If you pass just one positional parameter, it is interpreted as the
@@ -423,6 +556,68 @@ impl Array {
         Ok(kept.into())
     }
 
+    /// Produces a new array with the leading items of the original one for
+    /// which the given function returns true.
+    ///
+    /// Stops at the first item for which the function returns false. The
+    /// remaining items are not tested.
+    ///
+    /// ```example
+    /// #(1, 2, 3, 1).take-while(x => x < 3)
+    /// ```
+    #[func]
+    pub fn take_while(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to the leading items. Must return a boolean.
+        test: Func,
+    ) -> SourceResult<Array> {
+        let mut kept = EcoVec::new();
+        for item in self {
+            if !test
+                .call(engine, context, [item.clone()])?
+                .cast::<bool>()
+                .at(test.span())?
+            {
+                break;
+            }
+            kept.push(item);
+        }
+        Ok(kept.into())
+    }
+
+    /// Produces a new array without the leading items of the original one for
+    /// which the given function returns true.
+    ///
+    /// Starts at the first item for which the function returns false. The
+    /// remaining items are kept without being tested.
+    ///
+    /// ```example
+    /// #(1, 2, 3, 1).drop-while(x => x < 3)
+    /// ```
+    #[func]
+    pub fn drop_while(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to the leading items. Must return a boolean.
+        test: Func,
+    ) -> SourceResult<Array> {
+        let mut skip = 0;
+        for item in self.iter() {
+            if !test
+                .call(engine, context, [item.clone()])?
+                .cast::<bool>()
+                .at(test.span())?
+            {
+                break;
+            }
+            skip += 1;
+        }
+        Ok(self.into_iter().skip(skip).collect())
+    }
+
     /// Produces a new array in which all items from the original one were
     /// transformed with the given function.
     #[func]
@@ -438,6 +633,33 @@ impl Array {
             .collect()
     }
 
+    /// Transforms each item with the given function and flattens the results
+    /// by one level.
+    ///
+    /// If the function returns an array, its items are added to the result
+    /// individually. Any other value is added as a single item.
+    ///
+    /// ```example
+    /// #(1, 2).flat-map(n => (n, n * 10))
+    /// ```
+    #[func]
+    pub fn flat_map(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to each item.
+        mapper: Func,
+    ) -> SourceResult<Array> {
+        let mut flat = EcoVec::with_capacity(self.0.len());
+        for item in self {
+            match mapper.call(engine, context, [item])? {
+                Value::Array(mapped) => flat.extend(mapped),
+                value => flat.push(value),
+            }
+        }
+        Ok(flat.into())
+    }
+
     /// Returns a new array with the values alongside their indices.
     ///
     /// The returned array consists of `(index, value)` pairs in the form of
@@ -480,7 +702,8 @@ impl Array {
     ///
     /// If the arrays to be zipped have different lengths, they are zipped up to
     /// the last element of the shortest array and all remaining elements are
-    /// ignored.
+    /// ignored. If a `fill` value is given, they are instead zipped up to the
+    /// last element of the longest array and missing elements are filled in.
     ///
     /// This function is variadic, meaning that you can zip multiple arrays
     /// together at once: `{(1, 2).zip(("A", "B"), (10, 20))}` yields
@@ -495,11 +718,21 @@ impl Array {
         #[named]
         #[default(false)]
         exact: bool,
+        /// A value to use in place of missing elements when the arrays have
+        /// different lengths. If given, zips up to the longest array instead
+        /// of the shortest one. For example, `{(1, 2).zip((10,), fill: 0)}`
+        /// yields `{((1, 10), (2, 0))}`. Cannot be combined with `exact`.
+        #[named]
+        fill: Option<Value>,
         /// The arrays to zip with.
         #[external]
         #[variadic]
         others: Vec<Array>,
     ) -> SourceResult<Array> {
+        if exact && fill.is_some() {
+            bail!(args.span, "cannot use `exact` and `fill` at the same time");
+        }
+
         let remaining = args.remaining();
 
         // Fast path for one array.
@@ -507,6 +740,21 @@ impl Array {
             return Ok(self.into_iter().map(|item| array![item].into_value()).collect());
         }
 
+        // Zip up to the longest array, filling in missing elements.
+        if let Some(fill) = fill {
+            let arrays = args.all::<Array>()?;
+            let len = arrays.iter().map(Array::len).fold(self.len(), usize::max);
+            let mut out = Self::with_capacity(len);
+            for i in 0..len {
+                let row = std::iter::once(&self)
+                    .chain(&arrays)
+                    .map(|array| array.0.get(i).cloned().unwrap_or_else(|| fill.clone()))
+                    .collect::<Self>();
+                out.push(row.into_value());
+            }
+            return Ok(out);
+        }
+
         // Fast path for just two arrays.
         if remaining == 1 {
             let Spanned { v: other, span: other_span } =
@@ -614,6 +862,43 @@ impl Array {
         Ok(acc)
     }
 
+    /// Returns the running totals of the array, i.e. an array whose n-th item
+    /// combines the first n items.
+    ///
+    /// By default, the items are added (works for all types that can be
+    /// added). An empty array results in an empty array.
+    ///
+    /// ```example
+    /// #(1, 2, 3, 4).accumulate() \
+    /// #(1, 2, 3, 4).accumulate(op: calc.max) \
+    /// #("a", "b", "c").accumulate(op: (acc, x) => x + acc)
+    /// ```
+    #[func]
+    pub fn accumulate(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// The function to combine the accumulated value with the next item.
+        /// Must have two parameters: One for the accumulated value and one
+        /// for an item. Defaults to addition.
+        #[named]
+        op: Option<Func>,
+    ) -> SourceResult<Array> {
+        let mut result = Array::with_capacity(self.len());
+        let mut iter = self.into_iter();
+        let Some(mut acc) = iter.next() else { return Ok(result) };
+        result.push(acc.clone());
+        for item in iter {
+            acc = match &op {
+                Some(op) => op.call(engine, context, [acc, item])?,
+                None => ops::add(acc, item).at(span)?,
+            };
+            result.push(acc.clone());
+        }
+        Ok(result)
+    }
+
     /// Calculates the product of all items (works for all types that can be
     /// multiplied).
     #[func]
@@ -635,6 +920,48 @@ impl Array {
         Ok(acc)
     }
 
+    /// Calculates the arithmetic mean of all items (works for all types that
+    /// can be added and divided by a number).
+    ///
+    /// ```example
+    /// #(2, 4, 6).average() \
+    /// #(1cm, 2cm).average() \
+    /// #("a", "bcd").average(key: str.len)
+    /// ```
+    #[func]
+    pub fn average(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// If given, applied to each item before averaging.
+        #[named]
+        key: Option<Func>,
+        /// What to return if the array is empty. Must be set if the array can
+        /// be empty.
+        #[named]
+        default: Option<Value>,
+    ) -> SourceResult<Value> {
+        let len = self.len();
+        let mut iter = self.into_iter();
+        let Some(first) = iter.next() else {
+            return default
+                .ok_or("cannot calculate average of empty array with no default")
+                .at(span);
+        };
+
+        let mut map = |item: Value| match &key {
+            Some(f) => f.call(engine, context, [item]),
+            None => Ok(item),
+        };
+
+        let mut acc = map(first)?;
+        for item in iter {
+            acc = ops::add(acc, map(item)?).at(span)?;
+        }
+        ops::div(acc, Value::Int(len as i64)).at(span)
+    }
+
     /// Whether the given function returns `{true}` for any item in the array.
     #[func]
     pub fn any(
@@ -691,6 +1018,31 @@ impl Array {
         self.into_iter().rev().collect()
     }
 
+    /// Return a new array with the same items in a pseudo-random order.
+    ///
+    /// The order only depends on the array's length and the seed, so the same
+    /// array shuffled with the same seed always yields the same result.
+    ///
+    /// ```example
+    /// #range(10).shuffle(seed: 42)
+    /// ```
+    #[func]
+    pub fn shuffle(
+        self,
+        /// The seed for the pseudo-random order. If omitted, a fixed seed is
+        /// used.
+        #[named]
+        seed: Option<u64>,
+    ) -> Array {
+        let mut vec = self.0;
+        let items = vec.make_mut();
+        let mut rng = SplitMix64(seed.unwrap_or(0));
+        for i in (1..items.len()).rev() {
+            items.swap(i, rng.below(i as u64 + 1) as usize);
+        }
+        vec.into()
+    }
+
     /// Split the array at occurrences of the specified value.
     ///
     /// ```example
@@ -715,6 +1067,19 @@ impl Array {
         /// A value to insert between each item of the array.
         #[default]
         separator: Option<Value>,
+        /// Separators to insert between the items one after another, i.e. the
+        /// first one between the first two items, the second one between the
+        /// second and third item, and so on.
+        ///
+        /// Once the separators are exhausted, `separator` is used if it is
+        /// given. Otherwise, the last of the separators is repeated. The `last`
+        /// separator takes precedence over these between the last two items.
+        ///
+        /// ```example
+        /// #("Tokyo", "Chiyoda", "1-1").join(separators: (", ", " "))
+        /// ```
+        #[named]
+        separators: Option<Array>,
         /// An alternative separator between the last two items.
         #[named]
         last: Option<Value>,
@@ -730,7 +1095,10 @@ impl Array {
             return Ok(result);
         }
 
-        let separator = separator.unwrap_or(Value::None);
+        let separators = separators.unwrap_or_default();
+        let separator = separator
+            .or_else(|| separators.as_slice().last().cloned())
+            .unwrap_or(Value::None);
 
         let mut last = last;
         let mut result = Value::None;
@@ -739,6 +1107,8 @@ impl Array {
                 if i + 1 == len && last.is_some() {
                     result = ops::join(result, last.take().unwrap())?;
                 } else {
+                    let separator =
+                        separators.as_slice().get(i - 1).unwrap_or(&separator);
                     result = ops::join(result, separator.clone())?;
                 }
             }
@@ -782,6 +1152,148 @@ impl Array {
         Array(vec)
     }
 
+    /// Interleaves the array with other arrays, taking one element from each
+    /// array in turn.
+    ///
+    /// If the arrays have different lengths, only complete rounds are taken,
+    /// i.e. as many elements from each array as the shortest array has. If
+    /// `exhaustive` is set to `{true}`, exhausted arrays are skipped instead,
+    /// so that all elements end up in the result.
+    ///
+    /// ```example
+    /// #(1, 2).interleave((10, 20)) \
+    /// #(1, 2, 3).interleave(("a",), exhaustive: true)
+    /// ```
+    #[func]
+    pub fn interleave(
+        self,
+        args: &mut Args,
+        /// Whether to keep going after the shortest array ran out, skipping
+        /// arrays that have no elements left.
+        #[named]
+        #[default(false)]
+        exhaustive: bool,
+        /// The arrays to interleave with.
+        #[external]
+        #[variadic]
+        others: Vec<Array>,
+    ) -> SourceResult<Array> {
+        let others = args.all::<Array>()?;
+        let arrays: Vec<_> = std::iter::once(self).chain(others).collect();
+        let rounds = if exhaustive {
+            arrays.iter().map(|array| array.0.len()).max()
+        } else {
+            arrays.iter().map(|array| array.0.len()).min()
+        };
+
+        let mut iters: Vec<_> = arrays.into_iter().map(IntoIterator::into_iter).collect();
+        let mut out = Self::new();
+        for _ in 0..rounds.unwrap_or(0) {
+            for iter in &mut iters {
+                out.extend(iter.next());
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Computes the cartesian product of this array with other arrays.
+    ///
+    /// Returns an array of arrays, one for each combination of elements, where
+    /// the `i`th element of a combination is taken from the `i`th array. The
+    /// combinations are ordered such that the elements of the last array vary
+    /// fastest. To prevent accidentally huge results, the number of
+    /// combinations is limited to one million.
+    ///
+    /// ```example
+    /// #(1, 2).product-with(("a", "b")) \
+    /// #("x",).product-with((1, 2), (true, false)).len()
+    /// ```
+    #[func]
+    pub fn product_with(
+        self,
+        args: &mut Args,
+        /// The arrays to combine with.
+        #[external]
+        #[variadic]
+        others: Vec<Array>,
+    ) -> SourceResult<Array> {
+        const MAX_COMBINATIONS: usize = 1_000_000;
+
+        let arrays: Vec<_> = std::iter::once(self).chain(args.all::<Array>()?).collect();
+        let count = arrays
+            .iter()
+            .try_fold(1_usize, |acc, array| acc.checked_mul(array.len()))
+            .filter(|&count| count <= MAX_COMBINATIONS);
+        let Some(count) = count else {
+            bail!(
+                args.span,
+                "cartesian product has too many combinations";
+                hint: "at most {MAX_COMBINATIONS} combinations are supported"
+            );
+        };
+
+        let mut out = Self::with_capacity(count);
+        let mut indices = vec![0; arrays.len()];
+        for _ in 0..count {
+            let row = arrays.iter().zip(&indices).map(|(array, &i)| array.0[i].clone());
+            out.push(row.collect::<Self>().into_value());
+
+            // Advance to the next combination, starting with the last array.
+            for (index, array) in indices.iter_mut().zip(&arrays).rev() {
+                *index += 1;
+                if *index < array.len() {
+                    break;
+                }
+                *index = 0;
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Pads the array at the start with a value until it has the given length.
+    ///
+    /// If the array already has at least `len` elements, it is returned
+    /// unchanged.
+    ///
+    /// ```example
+    /// #(1, 2).pad-start(4, 0) \
+    /// #(1, 2, 3).pad-start(2, 0)
+    /// ```
+    #[func]
+    pub fn pad_start(
+        self,
+        /// The length the resulting array should at least have.
+        len: usize,
+        /// The value to pad the array with.
+        fill: Value,
+    ) -> Array {
+        let missing = len.saturating_sub(self.len());
+        std::iter::repeat_n(fill, missing).chain(self).collect()
+    }
+
+    /// Pads the array at the end with a value until it has the given length.
+    ///
+    /// If the array already has at least `len` elements, it is returned
+    /// unchanged.
+    ///
+    /// ```example
+    /// #(1, 2).pad-end(4, 0) \
+    /// #(1, 2, 3).pad-end(2, 0)
+    /// ```
+    #[func]
+    pub fn pad_end(
+        self,
+        /// The length the resulting array should at least have.
+        len: usize,
+        /// The value to pad the array with.
+        fill: Value,
+    ) -> Array {
+        let missing = len.saturating_sub(self.len());
+        self.into_iter().chain(std::iter::repeat_n(fill, missing)).collect()
+    }
+
     /// Splits an array into non-overlapping chunks, starting at the beginning,
     /// ending with a single remainder chunk.
     ///
@@ -812,6 +1324,72 @@ impl Array {
         }
     }
 
+    /// Splits an array into the given number of parts that are as evenly sized
+    /// as possible.
+    ///
+    /// If the array can't be split evenly, the earlier parts get one element
+    /// more than the later ones. The result always has exactly `parts`
+    /// elements, so some parts are empty if there are fewer elements than
+    /// parts. This is useful to distribute items across columns.
+    ///
+    /// ```example
+    /// #(1, 2, 3, 4, 5).split-into(2) \
+    /// #(1, 2, 3, 4, 5, 6, 7).split-into(3) \
+    /// #(1, 2).split-into(3)
+    /// ```
+    #[func]
+    pub fn split_into(
+        self,
+        /// How many parts to split the array into.
+        parts: NonZeroUsize,
+    ) -> Array {
+        let parts = parts.get();
+        let (size, remainder) = (self.len() / parts, self.len() % parts);
+        let mut rest = self.as_slice();
+        (0..parts)
+            .map(|i| {
+                let (part, tail) = rest.split_at(size + usize::from(i < remainder));
+                rest = tail;
+                Array::from(part).into_value()
+            })
+            .collect()
+    }
+
+    /// Splits an array into chunks of consecutive elements for which the
+    /// given function returns equal keys.
+    ///
+    /// ```example
+    /// #(1, 1, 2, 3, 3).chunks-by(x => x) \
+    /// #(1, 3, 2, 4, 5).chunks-by(calc.odd)
+    /// ```
+    #[func]
+    pub fn chunks_by(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to each element to determine its key.
+        key: Func,
+    ) -> SourceResult<Array> {
+        let mut chunks = EcoVec::new();
+        let mut current = EcoVec::new();
+        let mut last_key = None;
+
+        for item in self {
+            let k = key.call(engine, context, [item.clone()])?;
+            if last_key.as_ref().is_some_and(|last| !ops::equal(last, &k)) {
+                chunks.push(Array(std::mem::take(&mut current)).into_value());
+            }
+            current.push(item);
+            last_key = Some(k);
+        }
+
+        if !current.is_empty() {
+            chunks.push(Array(current).into_value());
+        }
+
+        Ok(Array(chunks))
+    }
+
     /// Returns sliding windows of `window-size` elements over an array.
     ///
     /// If the array length is less than `window-size`, this will return an empty array.
@@ -832,6 +1410,56 @@ impl Array {
             .collect()
     }
 
+    /// Applies a function to each sliding window of `window-size` elements and
+    /// returns an array with the results.
+    ///
+    /// This is a shorthand for calling [`windows`]($array.windows) followed by
+    /// [`map`]($array.map). If the array length is less than `window-size`,
+    /// this will return an empty array.
+    ///
+    /// ```example
+    /// #let values = (1, 2, 3, 4, 5)
+    /// #values.window-reduce(3, w => w.sum() / w.len())
+    /// ```
+    #[func]
+    pub fn window_reduce(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// How many elements each window will contain.
+        window_size: NonZeroUsize,
+        /// The function to apply to each window. Receives the window as an
+        /// array.
+        reducer: Func,
+    ) -> SourceResult<Array> {
+        self.0
+            .windows(window_size.get())
+            .map(|window| reducer.call(engine, context, [Array::from(window)]))
+            .collect()
+    }
+
+    /// Returns every `n`-th element of the array, starting at the given
+    /// index.
+    ///
+    /// ```example
+    /// #let array = (0, 1, 2, 3, 4, 5, 6)
+    /// #array.step-by(2) \
+    /// #array.step-by(3, start: 1)
+    /// ```
+    #[func]
+    pub fn step_by(
+        self,
+        /// The distance between two elements that are kept.
+        n: NonZeroUsize,
+        /// The index of the first element to keep. If it is past the end of
+        /// the array, the result is empty.
+        #[named]
+        #[default(0)]
+        start: usize,
+    ) -> Array {
+        self.into_iter().skip(start).step_by(n.get()).collect()
+    }
+
     /// Return a sorted version of this array, optionally by a given key
     /// function. The sorting algorithm used is stable.
     ///
@@ -992,6 +1620,53 @@ impl Array {
         }
     }
 
+    /// Checks whether the array is sorted in ascending order, optionally by a
+    /// given key function.
+    ///
+    /// Every two adjacent elements (or their keys) are compared in the same
+    /// way as by [`sorted`]($array.sorted). The array is considered sorted if
+    /// no element is greater than the one following it, so equal elements are
+    /// allowed. Empty arrays and arrays with a single element are always
+    /// sorted.
+    ///
+    /// Returns an error if two values could not be compared or if the key
+    /// function (if given) yields an error.
+    ///
+    /// ```example
+    /// #(1, 2, 3).is-sorted() \
+    /// #(1, 3, 2).is-sorted() \
+    /// #("a", "bb", "ccc").is-sorted(key: s => s.len())
+    /// ```
+    #[func]
+    pub fn is_sorted(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// If given, applies this function to each element in the array to
+        /// determine the keys to compare.
+        #[named]
+        key: Option<Func>,
+    ) -> SourceResult<bool> {
+        let mut key_of = |x: Value| match &key {
+            Some(f) => f.call(engine, context, [x]),
+            None => Ok(x),
+        };
+
+        let mut iter = self.into_iter();
+        let Some(first) = iter.next() else { return Ok(true) };
+        let mut prev = key_of(first)?;
+        for item in iter {
+            let next = key_of(item)?;
+            if ops::compare(&prev, &next).at(span)? == Ordering::Greater {
+                return Ok(false);
+            }
+            prev = next;
+        }
+
+        Ok(true)
+    }
+
     /// Deduplicates all items in the array.
     ///
     /// Returns a new array with all duplicate items removed. Only the first
@@ -1044,6 +1719,189 @@ impl Array {
         Ok(Self(out))
     }
 
+    /// Removes all duplicate items from the array, keeping the first
+    /// occurrence of each item.
+    ///
+    /// This yields the same result as [`array.dedup`], but is much faster for
+    /// large arrays as long as all keys are strings, integers, booleans,
+    /// labels, bytes, `{none}`, or `{auto}`. For other keys, it falls back to
+    /// comparing the keys pairwise.
+    ///
+    /// ```example
+    /// #("b", "a", "b", "c", "a").unique()
+    /// ```
+    #[func]
+    pub fn unique(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// If given, applies this function to each element in the array to
+        /// determine the keys to deduplicate by.
+        ///
+        /// ```example
+        /// #("apple", "Apple", "banana").unique(key: lower)
+        /// ```
+        #[named]
+        key: Option<Func>,
+    ) -> SourceResult<Array> {
+        let keys = match &key {
+            Some(f) => self
+                .iter()
+                .map(|value| f.call(engine, context, [value.clone()]))
+                .collect::<SourceResult<Vec<_>>>()?,
+            None => self.iter().cloned().collect(),
+        };
+
+        let mut out = EcoVec::with_capacity(self.0.len());
+        if keys.iter().all(is_hashable_key) {
+            let mut seen = FxHashSet::default();
+            for (value, key) in self.into_iter().zip(keys) {
+                if seen.insert(typst_utils::hash128(&key)) {
+                    out.push(value);
+                }
+            }
+        } else {
+            let mut seen: Vec<Value> = Vec::with_capacity(keys.len());
+            for (value, key) in self.into_iter().zip(keys) {
+                if !seen.iter().any(|other| ops::equal(&key, other)) {
+                    seen.push(key);
+                    out.push(value);
+                }
+            }
+        }
+
+        Ok(Self(out))
+    }
+
+    /// Counts how often each item occurs in the array.
+    ///
+    /// Returns a dictionary that maps each item to its number of occurrences.
+    /// The items must be strings, unless a `key` function is given. The pairs
+    /// are ordered by the first occurrence of each item.
+    ///
+    /// ```example
+    /// #("a", "b", "a").tally()
+    /// ```
+    #[func]
+    pub fn tally(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// If given, applies this function to each element in the array to
+        /// determine the keys to count by. Must return a string.
+        ///
+        /// ```example
+        /// #(1, 2, 3, 4, 5).tally(key: x => if calc.even(x) { "even" } else { "odd" })
+        /// ```
+        #[named]
+        key: Option<Func>,
+    ) -> SourceResult<Dict> {
+        let mut counts: IndexMap<Str, i64, FxBuildHasher> = IndexMap::default();
+        for value in self {
+            let key = match &key {
+                Some(f) => f.call(engine, context, [value])?.cast::<Str>().at(f.span())?,
+                None => value.cast::<Str>().at(span)?,
+            };
+            *counts.entry(key).or_default() += 1;
+        }
+        Ok(counts.into_iter().map(|(k, n)| (k, Value::Int(n))).collect())
+    }
+
+    /// Counts how often each element occurs in the array and returns
+    /// `(value, count)` pairs ordered from the most to the least frequent
+    /// element. Elements that occur equally often stay in the order of their
+    /// first occurrence.
+    ///
+    /// Unlike [`tally`]($array.tally), this works with elements of any type.
+    ///
+    /// ```example
+    /// #let words = "the cat and the hat and the bat".split()
+    /// #for (word, count) in words.ranked() [
+    ///   #word: #count \
+    /// ]
+    /// ```
+    #[func]
+    pub fn ranked(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// If given, applies this function to each element in the array to
+        /// determine the values to count. The pairs then contain these values
+        /// instead of the elements.
+        ///
+        /// ```example
+        /// #(1, 2, 3, 4, 5).ranked(key: calc.even)
+        /// ```
+        #[named]
+        key: Option<Func>,
+    ) -> SourceResult<Array> {
+        let keys = match &key {
+            Some(f) => self
+                .into_iter()
+                .map(|value| f.call(engine, context, [value]))
+                .collect::<SourceResult<Vec<_>>>()?,
+            None => self.into_iter().collect(),
+        };
+
+        let mut counts: Vec<(Value, i64)> = vec![];
+        if keys.iter().all(is_hashable_key) {
+            let mut map: IndexMap<u128, (Value, i64), FxBuildHasher> =
+                IndexMap::default();
+            for key in keys {
+                map.entry(typst_utils::hash128(&key)).or_insert((key, 0)).1 += 1;
+            }
+            counts.extend(map.into_values());
+        } else {
+            for key in keys {
+                match counts.iter_mut().find(|(other, _)| ops::equal(&key, other)) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((key, 1)),
+                }
+            }
+        }
+
+        // The sort is stable, so ties keep the order of first occurrence.
+        counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        Ok(counts
+            .into_iter()
+            .map(|(value, count)| array![value, Value::Int(count)].into_value())
+            .collect())
+    }
+
+    /// Creates a dictionary that maps each element of the array to its index.
+    /// This is the inverse of [`enumerate`]($array.enumerate). All elements
+    /// must be strings.
+    ///
+    /// ```example
+    /// #("a", "b", "c").index-map()
+    /// ```
+    #[func]
+    pub fn index_map(
+        self,
+        /// Whether to keep the index of the last occurrence of a duplicate
+        /// element instead of the first one.
+        ///
+        /// ```example
+        /// #("a", "b", "a").index-map(last: true)
+        /// ```
+        #[named]
+        #[default(false)]
+        last: bool,
+    ) -> StrResult<Dict> {
+        let mut indices: IndexMap<Str, i64, FxBuildHasher> = IndexMap::default();
+        for (i, value) in self.into_iter().enumerate() {
+            let key = value.cast::<Str>()?;
+            let i = i as i64;
+            if last {
+                indices.insert(key, i);
+            } else {
+                indices.entry(key).or_insert(i);
+            }
+        }
+        Ok(indices.into_iter().map(|(k, i)| (k, Value::Int(i))).collect())
+    }
+
     /// Converts an array of pairs into a dictionary.
     /// The first value of each pair is the key, the second the value.
     ///
@@ -1109,6 +1967,36 @@ impl Array {
         }
         Ok(acc)
     }
+
+    /// Reduces the elements to a single one, by repeatedly applying a reducing
+    /// operation from the end of the array.
+    ///
+    /// If the array is empty, returns `{none}`, otherwise, returns the result
+    /// of the reduction.
+    ///
+    /// This is the same as [`array.reduce`], but starts with the last element
+    /// as the initial accumulator value and folds every preceding element into
+    /// it, from right to left.
+    ///
+    /// ```example
+    /// #("a", "b", "c").reduce-right((acc, x) => acc + x)
+    /// ```
+    #[func]
+    pub fn reduce_right(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The reducing function. Must have two parameters: One for the
+        /// accumulated value and one for an item.
+        reducer: Func,
+    ) -> SourceResult<Value> {
+        let mut iter = self.into_iter().rev();
+        let mut acc = iter.next().unwrap_or_default();
+        for item in iter {
+            acc = reducer.call(engine, context, [acc, item])?;
+        }
+        Ok(acc)
+    }
 }
 
 /// A value that can be cast to bytes.
@@ -1121,6 +2009,27 @@ cast! {
     v: Version => Self(v.values().iter().map(|&v| Value::Int(v as i64)).collect())
 }
 
+/// An index or a range of indices into an array.
+pub enum ArrayIndex {
+    /// A single index.
+    Single(i64),
+    /// An inclusive start and an exclusive end index. If the end is `None`,
+    /// the range extends to the end of the array.
+    Range(i64, Option<i64>),
+}
+
+cast! {
+    ArrayIndex,
+    v: i64 => Self::Single(v),
+    array: Array => {
+        let mut iter = array.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(a), Some(b), None) => Self::Range(a.cast()?, b.cast()?),
+            _ => bail!("array must contain exactly two entries"),
+        }
+    },
+}
+
 impl Debug for Array {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         f.debug_list().entries(&self.0).finish()
@@ -1298,6 +2207,45 @@ impl<T> Default for OneOrMultiple<T> {
     }
 }
 
+/// A small, deterministic pseudo-random number generator.
+///
+/// We don't use a library for this because the sequence for a given seed must
+/// never change, as it determines the output of `array.shuffle`.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Returns the next number of the sequence.
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in the range `0..bound`.
+    fn below(&mut self, bound: u64) -> u64 {
+        ((u128::from(self.next_u64()) * u128::from(bound)) >> 64) as u64
+    }
+}
+
+/// Whether hashing the key is consistent with comparing it for equality.
+///
+/// This is only the case if the key can't be equal to a value of another type
+/// (like `{1}` and `{1.0}`).
+fn is_hashable_key(key: &Value) -> bool {
+    matches!(
+        key,
+        Value::None
+            | Value::Auto
+            | Value::Bool(_)
+            | Value::Int(_)
+            | Value::Str(_)
+            | Value::Label(_)
+            | Value::Bytes(_)
+    )
+}
+
 /// The error message when the array is empty.
 #[cold]
 fn array_is_empty() -> EcoString {
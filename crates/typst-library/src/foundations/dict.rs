@@ -1,18 +1,22 @@
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign};
 use std::sync::Arc;
 
+use comemo::Tracked;
 use ecow::{EcoString, eco_format};
 use indexmap::IndexMap;
 use rustc_hash::FxBuildHasher;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use typst_syntax::is_ident;
+use typst_syntax::{Span, is_ident};
 use typst_utils::ArcExt;
 
-use crate::diag::{Hint, HintedStrResult, StrResult};
+use crate::diag::{At, Hint, HintedStrResult, SourceResult, StrResult, bail};
+use crate::engine::Engine;
 use crate::foundations::{
-    Array, Module, Repr, Str, Value, array, cast, func, repr, scope, ty,
+    Array, Context, Func, Module, Repr, Str, Value, array, cast, func, ops, repr, scope,
+    ty,
 };
 
 /// Create a new [`Dict`] from key-value pairs.
@@ -165,6 +169,42 @@ impl Dict {
 
         msg.into()
     }
+
+    /// Returns the pairs of the dictionary, stably sorted by the value that
+    /// `by` extracts from each pair, optionally mapped through `key`.
+    fn sorted_pairs(
+        &self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        key: Option<Func>,
+        by: impl Fn(&Str, &Value) -> Value,
+    ) -> SourceResult<Array> {
+        let mut keyed = Vec::with_capacity(self.len());
+        for (k, v) in self.iter() {
+            let sort_key = match &key {
+                Some(f) => f.call(engine, context, [by(k, v)])?,
+                None => by(k, v),
+            };
+            keyed.push((sort_key, k, v));
+        }
+
+        let mut result = Ok(());
+        keyed.sort_by(|(a, ..), (b, ..)| {
+            ops::compare(a, b).unwrap_or_else(|err| {
+                if result.is_ok() {
+                    result = Err(err).at(span);
+                }
+                Ordering::Equal
+            })
+        });
+        result?;
+
+        Ok(keyed
+            .into_iter()
+            .map(|(_, k, v)| Value::Array(array![k.clone(), v.clone()]))
+            .collect())
+    }
 }
 
 #[scope]
@@ -192,6 +232,30 @@ impl Dict {
         self.0.len()
     }
 
+    /// The number of non-dictionary values in the dictionary, recursively
+    /// counting the values of nested dictionaries. In contrast to
+    /// [`len`]($dictionary.len), nested dictionaries themselves aren't
+    /// counted, so an empty nested dictionary contributes nothing.
+    ///
+    /// ```example
+    /// #let config = (
+    ///   page: (width: 10cm, margin: (x: 1cm, y: 2cm)),
+    ///   lang: "en",
+    /// )
+    /// #config.len() \
+    /// #config.deep-len()
+    /// ```
+    #[func(title = "Deep Length")]
+    pub fn deep_len(&self) -> usize {
+        self.0
+            .values()
+            .map(|value| match value {
+                Value::Dict(dict) => dict.deep_len(),
+                _ => 1,
+            })
+            .sum()
+    }
+
     /// Returns the value associated with the specified key in the dictionary.
     /// May be used on the left-hand side of an assignment if the key is already
     /// present in the dictionary. Returns the default value if the key is not
@@ -245,6 +309,75 @@ impl Dict {
             .ok_or_else(|| missing_key(&key))
     }
 
+    /// Updates the value associated with a key by applying a function to it.
+    ///
+    /// If the key is not part of the dictionary, the function is applied to
+    /// the default value instead. Fails with an error if the key is missing and
+    /// no default value was specified.
+    ///
+    /// ```example
+    /// #let counts = (apples: 1)
+    /// #counts.update("apples", n => n + 1)
+    /// #counts.update("pears", n => n + 1, default: 0)
+    /// #counts
+    /// ```
+    #[func]
+    pub fn update(
+        &mut self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// The key whose value should be updated.
+        key: Str,
+        /// The function to apply to the current value. Its return value
+        /// becomes the new value.
+        updater: Func,
+        /// A default value to pass to the function if the key does not exist.
+        #[named]
+        default: Option<Value>,
+    ) -> SourceResult<()> {
+        let value = self
+            .0
+            .get(&key)
+            .cloned()
+            .or(default)
+            .ok_or_else(|| missing_key_no_default(&key))
+            .at(span)?;
+        let value = updater.call(engine, context, [value])?;
+        Arc::make_mut(&mut self.0).insert(key, value);
+        Ok(())
+    }
+
+    /// Removes all pairs for which the given function returns `{false}`,
+    /// keeping the remaining pairs in insertion order.
+    ///
+    /// ```example
+    /// #let stock = (apple: 3, kiwi: 0, pear: 2, plum: 0)
+    /// #stock.retain((k, v) => v > 0)
+    /// #stock
+    /// ```
+    #[func]
+    pub fn retain(
+        &mut self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to each pair. Must have two parameters: One
+        /// for the key and one for the value. Must return a boolean.
+        test: Func,
+    ) -> SourceResult<()> {
+        let mut keep = Vec::with_capacity(self.len());
+        for (k, v) in self.iter() {
+            keep.push(
+                test.call(engine, context, [Value::Str(k.clone()), v.clone()])?
+                    .cast::<bool>()
+                    .at(test.span())?,
+            );
+        }
+        let mut keep = keep.into_iter();
+        Arc::make_mut(&mut self.0).retain(|_, _| keep.next().unwrap_or(true));
+        Ok(())
+    }
+
     /// Returns the keys of the dictionary as an array in insertion order.
     #[func]
     pub fn keys(&self) -> Array {
@@ -266,6 +399,248 @@ impl Dict {
             .map(|(k, v)| Value::Array(array![k.clone(), v.clone()]))
             .collect()
     }
+
+    /// Returns the keys and values of the dictionary as an array of pairs,
+    /// sorted by their keys. Each pair is represented as an array of length
+    /// two.
+    ///
+    /// ```example
+    /// #(b: 2, c: 3, a: 1).pairs-sorted()
+    /// ```
+    #[func]
+    pub fn pairs_sorted(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// If given, applies this function to each key to determine what to
+        /// sort by.
+        #[named]
+        key: Option<Func>,
+    ) -> SourceResult<Array> {
+        self.sorted_pairs(engine, context, span, key, |k, _| Value::Str(k.clone()))
+    }
+
+    /// Returns the keys and values of the dictionary as an array of pairs,
+    /// sorted by their values. Each pair is represented as an array of length
+    /// two. Pairs with equal values keep their insertion order.
+    ///
+    /// ```example
+    /// #let counts = (apple: 3, kiwi: 1, pear: 2)
+    /// #counts.sorted-by-value() \
+    /// #counts.sorted-by-value(key: v => -v)
+    /// ```
+    #[func]
+    pub fn sorted_by_value(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// If given, applies this function to each value to determine what to
+        /// sort by.
+        #[named]
+        key: Option<Func>,
+    ) -> SourceResult<Array> {
+        self.sorted_pairs(engine, context, span, key, |_, v| v.clone())
+    }
+
+    /// Matches the dictionary with another one by key.
+    ///
+    /// Returns a dictionary that maps each key to a pair of its value in this
+    /// dictionary and its value in the other dictionary. The keys are in the
+    /// insertion order of this dictionary.
+    ///
+    /// ```example
+    /// #let defaults = (size: 11pt, lang: "en", font: "Libertinus Serif")
+    /// #let config = (lang: "ja", size: 11pt)
+    /// #defaults.zip(config) \
+    /// #defaults.zip(config, fill: true)
+    /// ```
+    #[func]
+    pub fn zip(
+        &self,
+        /// The dictionary to match with.
+        other: Dict,
+        /// Whether to keep keys that only exist in one of the dictionaries.
+        /// If `{false}`, such keys are dropped. If `{true}`, the missing value
+        /// is filled with `{none}` and keys only present in the other
+        /// dictionary are appended in its insertion order.
+        #[named]
+        #[default(false)]
+        fill: bool,
+    ) -> Dict {
+        let mut zipped: Dict = self
+            .iter()
+            .filter_map(|(k, v)| {
+                let second = match other.0.get(k) {
+                    Some(w) => w.clone(),
+                    None if fill => Value::None,
+                    None => return None,
+                };
+                Some((k.clone(), Value::Array(array![v.clone(), second])))
+            })
+            .collect();
+
+        if fill {
+            for (k, w) in other.iter().filter(|(k, _)| !self.contains(k)) {
+                zipped.insert(k.clone(), Value::Array(array![Value::None, w.clone()]));
+            }
+        }
+
+        zipped
+    }
+
+    /// Renames keys of the dictionary according to a mapping from old to new
+    /// keys.
+    ///
+    /// Keys that are not part of the mapping are kept as-is. The pairs stay in
+    /// their original insertion order. Fails if two keys end up with the same
+    /// name after renaming.
+    ///
+    /// ```example
+    /// #let row = ("First Name": "Ada", "Last Name": "Lovelace", year: 1815)
+    /// #row.rename-keys((
+    ///   "First Name": "first",
+    ///   "Last Name": "last",
+    /// ))
+    /// ```
+    #[func]
+    pub fn rename_keys(
+        self,
+        /// A dictionary mapping old keys to new keys. Each value must be a
+        /// string.
+        mapping: Dict,
+    ) -> StrResult<Dict> {
+        let mut renamed = IndexMap::with_capacity_and_hasher(self.len(), FxBuildHasher);
+        for (key, value) in self {
+            let key = match mapping.0.get(&key) {
+                Some(Value::Str(new)) => new.clone(),
+                Some(other) => bail!(
+                    "expected string as new key for {}, found {}",
+                    key.repr(),
+                    other.ty()
+                ),
+                None => key,
+            };
+            if renamed.contains_key(&key) {
+                bail!("duplicate key {} after renaming", key.repr());
+            }
+            renamed.insert(key, value);
+        }
+        Ok(renamed.into())
+    }
+
+    /// Fills in missing keys from a dictionary of defaults.
+    ///
+    /// Keys that already exist in this dictionary keep their value. Missing
+    /// keys are appended in the order they appear in `defaults`. Nested
+    /// dictionaries are not merged.
+    ///
+    /// ```example
+    /// #let config = (size: 12pt)
+    /// #config.with-defaults((size: 10pt, font: "Libertinus Serif"))
+    /// ```
+    #[func]
+    pub fn with_defaults(
+        mut self,
+        /// The dictionary to take values for missing keys from.
+        defaults: Dict,
+    ) -> Dict {
+        for (key, value) in defaults {
+            if !self.contains(&key) {
+                Arc::make_mut(&mut self.0).insert(key, value);
+            }
+        }
+        self
+    }
+
+    /// Removes all pairs whose value is `{none}`.
+    ///
+    /// The remaining pairs keep their insertion order. Values are not
+    /// inspected recursively, so nested dictionaries are kept as-is.
+    ///
+    /// ```example
+    /// #let size = none
+    /// #(font: "Libertinus Serif", size: size).compact()
+    /// ```
+    #[func]
+    pub fn compact(mut self) -> Dict {
+        if self.0.values().any(|value| matches!(value, Value::None)) {
+            Arc::make_mut(&mut self.0).retain(|_, value| !matches!(value, Value::None));
+        }
+        self
+    }
+
+    /// Swaps the keys and values of the dictionary.
+    ///
+    /// All values must be strings. If multiple keys have the same value, the
+    /// last of them wins, unless `unique` is set.
+    ///
+    /// ```example
+    /// #(a: "x", b: "y").invert()
+    /// ```
+    #[func]
+    pub fn invert(
+        self,
+        /// Whether to fail if multiple keys have the same value instead of
+        /// keeping the last of them.
+        #[named]
+        #[default(false)]
+        unique: bool,
+    ) -> StrResult<Dict> {
+        let mut inverted: IndexMap<Str, Value, FxBuildHasher> =
+            IndexMap::with_capacity_and_hasher(self.len(), FxBuildHasher);
+        for (key, value) in self {
+            let ty = value.ty();
+            let Ok(value) = value.cast::<Str>() else {
+                bail!("expected string as value for {}, found {}", key.repr(), ty);
+            };
+            if let Some(Value::Str(other)) = inverted.get(&value)
+                && unique
+            {
+                bail!(
+                    "keys {} and {} have the same value {}",
+                    other.repr(),
+                    key.repr(),
+                    value.repr()
+                );
+            }
+            inverted.insert(value, Value::Str(key));
+        }
+        Ok(inverted.into())
+    }
+
+    /// Groups the pairs of the dictionary by a key function.
+    ///
+    /// The function is called with the key and the value of each pair and
+    /// must return a string. Returns a dictionary that maps each of these
+    /// strings to a sub-dictionary of the pairs that produced it. The groups
+    /// are ordered by their first occurrence and the pairs within a group keep
+    /// their insertion order.
+    ///
+    /// ```example
+    /// #let stock = (apple: 3, kiwi: 0, pear: 2, plum: 0)
+    /// #stock.group-by((k, v) => if v > 0 { "available" } else { "sold out" })
+    /// ```
+    #[func]
+    pub fn group_by(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to determine the group of a pair. Must have two
+        /// parameters: One for the key and one for the value.
+        key: Func,
+    ) -> SourceResult<Dict> {
+        let mut groups: IndexMap<Str, Dict, FxBuildHasher> = IndexMap::default();
+        for (k, v) in self {
+            let group = key
+                .call(engine, context, [Value::Str(k.clone()), v.clone()])?
+                .cast::<Str>()
+                .at(key.span())?;
+            groups.entry(group).or_default().insert(k, v);
+        }
+        Ok(groups.into_iter().map(|(g, d)| (g, Value::Dict(d))).collect())
+    }
 }
 
 /// A value that can be cast to dictionary.
@@ -3,9 +3,9 @@
 use ecow::{EcoString, eco_format};
 
 use crate::diag::StrResult;
-use crate::foundations::{IntoValue, Type, Value, Version};
+use crate::foundations::{IntoValue, Smart, Type, Value, Version};
 use crate::layout::{Alignment, Length, Rel};
-use crate::visualize::Stroke;
+use crate::visualize::{Stroke, Thickness};
 
 /// Try to access a field on a value.
 ///
@@ -36,13 +36,23 @@ pub(crate) fn field(value: &Value, field: &str) -> StrResult<Value> {
             if let Some(stroke) = dynamic.downcast::<Stroke>() {
                 match field {
                     "paint" => stroke.paint.clone().into_value(),
-                    "thickness" => stroke.thickness.into_value(),
+                    "paint-anchor" => stroke.paint_anchor.into_value(),
+                    "gradient-along-path" => stroke.gradient_along_path.into_value(),
+                    "thickness" => match stroke.hairline {
+                        Smart::Custom(true) => Thickness::Hairline.into_value(),
+                        _ => stroke.thickness.into_value(),
+                    },
                     "cap" => stroke.cap.into_value(),
                     "join" => stroke.join.into_value(),
+                    "join-radius" => stroke.join_radius.into_value(),
                     "dash" => stroke.dash.clone().into_value(),
+                    "gap-paint" => stroke.gap_paint.clone().into_value(),
+                    "double" => stroke.double.into_value(),
+                    "double-gap" => stroke.double_gap.into_value(),
                     "miter-limit" => {
                         stroke.miter_limit.map(|limit| limit.get()).into_value()
                     }
+                    "anti-alias" => stroke.anti_alias.into_value(),
                     _ => return missing(),
                 }
             } else if let Some(align) = dynamic.downcast::<Alignment>() {
@@ -82,7 +92,21 @@ pub fn fields_on(ty: Type) -> &'static [&'static str] {
     } else if ty == Type::of::<Rel>() {
         &["ratio", "length"]
     } else if ty == Type::of::<Stroke>() {
-        &["paint", "thickness", "cap", "join", "dash", "miter-limit"]
+        &[
+            "paint",
+            "paint-anchor",
+            "gradient-along-path",
+            "thickness",
+            "cap",
+            "join",
+            "join-radius",
+            "dash",
+            "gap-paint",
+            "double",
+            "double-gap",
+            "miter-limit",
+            "anti-alias",
+        ]
     } else if ty == Type::of::<Alignment>() {
         &["x", "y"]
     } else {
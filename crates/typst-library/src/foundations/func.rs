@@ -195,6 +195,21 @@ impl Func {
         }
     }
 
+    /// The number of positional parameters of a closure, excluding those that
+    /// were already applied with [`with`](Self::with).
+    ///
+    /// Returns `None` for non-closure functions.
+    pub fn num_pos_params(&self) -> Option<usize> {
+        match &self.repr {
+            Repr::Closure(closure) => Some(closure.num_pos_params),
+            Repr::With(with) => with
+                .0
+                .num_pos_params()
+                .map(|n| n.saturating_sub(with.1.to_pos().len())),
+            _ => None,
+        }
+    }
+
     /// Get the parameter info for a parameter with the given name if it exist.
     pub fn param(&self, name: &str) -> Option<&'static ParamInfo> {
         self.params()?.iter().find(|param| param.name == name)
@@ -18,7 +18,7 @@ use crate::foundations::{
     NoneValue, Reflect, Repr, Resolve, Scope, Str, Styles, Symbol, SymbolElem, Type,
     Version, fields, ops, repr,
 };
-use crate::layout::{Abs, Angle, Em, Fr, Length, Ratio, Rel};
+use crate::layout::{AbsUnit, Angle, Em, Fr, Length, Ratio, Rel};
 use crate::text::{RawContent, RawElem, TextElem};
 use crate::visualize::{Color, Gradient, Tiling};
 
@@ -101,10 +101,10 @@ impl Value {
     pub fn numeric(pair: (f64, ast::Unit)) -> Self {
         let (v, unit) = pair;
         match unit {
-            ast::Unit::Pt => Abs::pt(v).into_value(),
-            ast::Unit::Mm => Abs::mm(v).into_value(),
-            ast::Unit::Cm => Abs::cm(v).into_value(),
-            ast::Unit::In => Abs::inches(v).into_value(),
+            ast::Unit::Pt => Length::with_unit(v, AbsUnit::Pt).into_value(),
+            ast::Unit::Mm => Length::with_unit(v, AbsUnit::Mm).into_value(),
+            ast::Unit::Cm => Length::with_unit(v, AbsUnit::Cm).into_value(),
+            ast::Unit::In => Length::with_unit(v, AbsUnit::In).into_value(),
             ast::Unit::Rad => Angle::rad(v).into_value(),
             ast::Unit::Deg => Angle::deg(v).into_value(),
             ast::Unit::Em => Em::new(v).into_value(),
@@ -717,6 +717,7 @@ impl<T: Clone + Fold> Fold for Arc<T> {
 mod tests {
     use super::*;
     use crate::foundations::{array, dict};
+    use crate::layout::Abs;
 
     #[track_caller]
     fn test(value: impl IntoValue, exp: &str) {
@@ -127,6 +127,23 @@ impl Abs {
     pub fn signum(self) -> f64 {
         self.0.get().signum()
     }
+
+    /// Whether this length can be written in the given unit with at most two
+    /// decimal places.
+    fn is_exact_in(self, unit: AbsUnit) -> bool {
+        let scaled = self.to_unit(unit) * 100.0;
+        scaled.is_finite() && (scaled - scaled.round()).abs() < 1e-9
+    }
+
+    /// Like [`repr`](Repr::repr), but displays the length in the given unit
+    /// if that is exact up to two decimal places.
+    pub fn repr_in(self, unit: AbsUnit) -> EcoString {
+        if unit != AbsUnit::Pt && self.is_exact_in(unit) {
+            repr::format_float_with_unit(self.to_unit(unit), unit.name())
+        } else {
+            self.repr()
+        }
+    }
 }
 
 impl Numeric for Abs {
@@ -254,6 +271,16 @@ impl AbsUnit {
     /// The epsilon for approximate length comparisons.
     const EPS: f64 = 1e-4;
 
+    /// The suffix used for this unit in Typst source code.
+    const fn name(self) -> &'static str {
+        match self {
+            AbsUnit::Pt => "pt",
+            AbsUnit::Mm => "mm",
+            AbsUnit::Cm => "cm",
+            AbsUnit::In => "in",
+        }
+    }
+
     /// How many raw units correspond to a value of `1.0` in this unit.
     const fn raw_scale(self) -> f64 {
         // We choose a raw scale which has an integer conversion value to all
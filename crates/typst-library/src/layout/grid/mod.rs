@@ -225,6 +225,9 @@ pub struct GridElem {
     /// - 各列に対応する色の配列
     /// - セルの位置を色に変換する関数
     ///
+    /// 関数が3つの位置引数を受け取る場合、3番目の引数としてセルの本文も渡されます。
+    /// これにより、例えば空のセルだけを塗り潰すことができます。
+    ///
     /// 特に、配列や関数はストライプ状のグリッドを作成するのに便利です。
     /// 詳細は上記の[スタイル設定のセクション](#styling)を参照してください。
     ///
@@ -817,18 +820,18 @@ pub enum Celled<T> {
 }
 
 impl<T: Default + Clone + FromValue> Celled<T> {
-    /// Resolve the value based on the cell position.
+    /// Resolve the value based on the cell position and body.
     pub fn resolve(
         &self,
         engine: &mut Engine,
         styles: StyleChain,
         x: usize,
         y: usize,
+        body: &Content,
     ) -> SourceResult<T> {
         Ok(match self {
             Self::Value(value) => value.clone(),
-            Self::Func(func) => func
-                .call(engine, Context::new(None, Some(styles)).track(), [x, y])?
+            Self::Func(func) => call_celled(func, engine, styles, x, y, body)?
                 .cast()
                 .at(func.span())?,
             Self::Array(array) => x
@@ -918,18 +921,18 @@ where
     T: FromValue + Resolve,
     <T as Resolve>::Output: Default + Clone,
 {
-    /// Resolve the value based on the cell position.
+    /// Resolve the value based on the cell position and body.
     pub fn resolve(
         &self,
         engine: &mut Engine,
         styles: StyleChain,
         x: usize,
         y: usize,
+        body: &Content,
     ) -> SourceResult<T::Output> {
         Ok(match &self.0 {
             Celled::Value(value) => value.clone(),
-            Celled::Func(func) => func
-                .call(engine, Context::new(None, Some(styles)).track(), [x, y])?
+            Celled::Func(func) => call_celled(func, engine, styles, x, y, body)?
                 .cast::<T>()
                 .at(func.span())?
                 .resolve(styles),
@@ -941,3 +944,28 @@ where
         })
     }
 }
+
+/// Calls a per-cell function with the cell's position.
+///
+/// For backwards compatibility, the cell's body is only passed as a third
+/// argument if the function is a closure with exactly three positional
+/// parameters.
+fn call_celled(
+    func: &Func,
+    engine: &mut Engine,
+    styles: StyleChain,
+    x: usize,
+    y: usize,
+    body: &Content,
+) -> SourceResult<Value> {
+    let context = Context::new(None, Some(styles));
+    if func.num_pos_params() == Some(3) {
+        func.call(
+            engine,
+            context.track(),
+            [x.into_value(), y.into_value(), body.clone().into_value()],
+        )
+    } else {
+        func.call(engine, context.track(), [x, y])
+    }
+}
@@ -292,6 +292,10 @@ impl ResolvableCell for Packed<TableCell> {
         self.rowspan.get(styles)
     }
 
+    fn body(&self) -> &Content {
+        &self.body
+    }
+
     fn span(&self) -> Span {
         Packed::span(self)
     }
@@ -386,6 +390,10 @@ impl ResolvableCell for Packed<GridCell> {
         self.rowspan.get(styles)
     }
 
+    fn body(&self) -> &Content {
+        &self.body
+    }
+
     fn span(&self) -> Span {
         Packed::span(self)
     }
@@ -530,6 +538,9 @@ pub trait ResolvableCell {
     /// The amount of rows spanned by this cell.
     fn rowspan(&self, styles: StyleChain) -> NonZeroUsize;
 
+    /// The cell's body, which is passed to per-cell functions that accept it.
+    fn body(&self) -> &Content;
+
     /// The cell's span, for errors.
     fn span(&self) -> Span;
 }
@@ -1969,13 +1980,19 @@ impl CellGridResolver<'_, '_> {
                     .any(|row_gutter| row_gutter == &Sizing::Auto)
         };
 
+        let body = cell.body();
+        let fill = self.fill.resolve(self.engine, self.styles, x, y, body)?;
+        let align = self.align.resolve(self.engine, self.styles, x, y, body)?;
+        let inset = self.inset.resolve(self.engine, self.styles, x, y, body)?;
+        let stroke = self.stroke.resolve(self.engine, self.styles, x, y, body)?;
+
         Ok(cell.resolve_cell(
             x,
             y,
-            &self.fill.resolve(self.engine, self.styles, x, y)?,
-            self.align.resolve(self.engine, self.styles, x, y)?,
-            self.inset.resolve(self.engine, self.styles, x, y)?,
-            self.stroke.resolve(self.engine, self.styles, x, y)?,
+            &fill,
+            align,
+            inset,
+            stroke,
             breakable,
             self.styles,
             kind,
@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::ops::{Add, Div, Mul, Neg};
 
 use comemo::Tracked;
@@ -9,7 +10,7 @@ use typst_utils::Numeric;
 
 use crate::diag::{HintedStrResult, SourceResult, bail};
 use crate::foundations::{Context, Fold, Repr, Resolve, StyleChain, func, scope, ty};
-use crate::layout::{Abs, Em};
+use crate::layout::{Abs, AbsUnit, Em};
 
 /// 文脈に応じた単位で表現される場合もある、大きさまたは距離。
 ///
@@ -39,24 +40,41 @@ use crate::layout::{Abs, Em};
 /// - `abs`: 現在の長さの単なる数値部分（すなわち`em`部分を除いたもの）。
 /// - `em`: [float]としての、このlengthでの`em`単位の大きさ。
 #[ty(scope, cast)]
-#[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Default, Copy, Clone)]
 pub struct Length {
     /// The absolute part.
     pub abs: Abs,
     /// The font-relative part.
     pub em: Em,
+    /// The unit that the absolute part was written in, if it is known.
+    ///
+    /// This only affects how the length is displayed by
+    /// [`repr_exact`](Self::repr_exact) and is ignored when comparing lengths.
+    unit: Option<AbsUnit>,
 }
 
 impl Length {
     /// The zero length.
     pub const fn zero() -> Self {
-        Self { abs: Abs::zero(), em: Em::zero() }
+        Self { abs: Abs::zero(), em: Em::zero(), unit: None }
+    }
+
+    /// Create an absolute length from a value in a unit, remembering the
+    /// unit for display.
+    pub fn with_unit(val: f64, unit: AbsUnit) -> Self {
+        Self {
+            unit: Some(unit),
+            ..Abs::with_unit(val, unit).into()
+        }
     }
 
     /// Try to compute the absolute value of the length.
     pub fn try_abs(self) -> Option<Self> {
-        (self.abs.is_zero() || self.em.is_zero())
-            .then(|| Self { abs: self.abs.abs(), em: self.em.abs() })
+        (self.abs.is_zero() || self.em.is_zero()).then(|| Self {
+            abs: self.abs.abs(),
+            em: self.em.abs(),
+            ..self
+        })
     }
 
     /// Try to divide two lengths.
@@ -75,6 +93,24 @@ impl Length {
         self.abs + self.em.at(font_size)
     }
 
+    /// Like [`repr`](Repr::repr), but displays the absolute part in the unit
+    /// it was written in, so that e.g. `{1.5cm}` round-trips.
+    ///
+    /// Sums of lengths written in different units, as they can arise from
+    /// folding, and lengths that aren't exact in their unit up to two decimal
+    /// places are displayed in rounded points.
+    pub fn repr_exact(&self) -> EcoString {
+        let abs = match self.unit {
+            Some(unit) => self.abs.repr_in(unit),
+            None => self.abs.repr(),
+        };
+        match (self.abs.is_zero(), self.em.is_zero()) {
+            (false, false) => eco_format!("{abs} + {}", self.em.repr()),
+            (true, false) => self.em.repr(),
+            (_, true) => abs,
+        }
+    }
+
     /// Fails with an error if the length has a non-zero font-relative part.
     fn ensure_that_em_is_zero(&self, span: Span, unit: &str) -> SourceResult<()> {
         if self.em == Em::zero() {
@@ -177,6 +213,24 @@ impl Repr for Length {
     }
 }
 
+impl PartialEq for Length {
+    fn eq(&self, other: &Self) -> bool {
+        self.abs == other.abs && self.em == other.em
+    }
+}
+
+impl Eq for Length {}
+
+impl Hash for Length {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Unlike when comparing, the unit is taken into account because
+        // memoized functions may display the length.
+        self.abs.hash(state);
+        self.em.hash(state);
+        self.unit.hash(state);
+    }
+}
+
 impl Numeric for Length {
     fn zero() -> Self {
         Self::zero()
@@ -201,13 +255,13 @@ impl PartialOrd for Length {
 
 impl From<Abs> for Length {
     fn from(abs: Abs) -> Self {
-        Self { abs, em: Em::zero() }
+        Self { abs, em: Em::zero(), unit: None }
     }
 }
 
 impl From<Em> for Length {
     fn from(em: Em) -> Self {
-        Self { abs: Abs::zero(), em }
+        Self { abs: Abs::zero(), em, unit: None }
     }
 }
 
@@ -215,7 +269,7 @@ impl Neg for Length {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        Self { abs: -self.abs, em: -self.em }
+        Self { abs: -self.abs, em: -self.em, ..self }
     }
 }
 
@@ -223,7 +277,19 @@ impl Add for Length {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self { abs: self.abs + rhs.abs, em: self.em + rhs.em }
+        // The sum only keeps a unit if its absolute parts agree on it.
+        let unit = if rhs.abs.is_zero() {
+            self.unit
+        } else if self.abs.is_zero() || self.unit == rhs.unit {
+            rhs.unit
+        } else {
+            None
+        };
+        Self {
+            abs: self.abs + rhs.abs,
+            em: self.em + rhs.em,
+            unit,
+        }
     }
 }
 
@@ -233,7 +299,7 @@ impl Mul<f64> for Length {
     type Output = Self;
 
     fn mul(self, rhs: f64) -> Self::Output {
-        Self { abs: self.abs * rhs, em: self.em * rhs }
+        Self { abs: self.abs * rhs, em: self.em * rhs, ..self }
     }
 }
 
@@ -249,7 +315,7 @@ impl Div<f64> for Length {
     type Output = Self;
 
     fn div(self, rhs: f64) -> Self::Output {
-        Self { abs: self.abs / rhs, em: self.em / rhs }
+        Self { abs: self.abs / rhs, em: self.em / rhs, ..self }
     }
 }
 
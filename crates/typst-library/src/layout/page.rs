@@ -416,6 +416,17 @@ pub struct PagebreakElem {
     /// ```
     pub to: Option<Parity>,
 
+    /// HTMLエクスポートで、改ページで区切られた部分をそれぞれ`<section>`要素で囲むかどうか。
+    ///
+    /// `{false}`の場合、改ページは`<hr class="pagebreak">`要素として出力されます。
+    /// ページを持つ出力形式には影響しません。
+    ///
+    /// ```typ
+    /// #set pagebreak(sections: true)
+    /// ```
+    #[default(false)]
+    pub sections: bool,
+
     /// Whether this pagebreak designates an end boundary of a page run. This is
     /// an even weaker version of pagebreak `weak` because it not only doesn't
     /// force an empty page, but also doesn't force its initial styles onto a
@@ -109,6 +109,14 @@ use crate::visualize::ImageElem;
 /// ATは、図表の[`placement`]($figure.placement)による配置に関係なく、
 /// その図表が文書中に現れる位置で常に読み上げます。
 /// 読み上げ順序の中で最も自然になる位置にそのマークアップを配置してください。
+///
+/// # HTMLエクスポート
+/// HTMLエクスポートでは、図表は`<figure>`要素として出力され、
+/// キャプションはその中の`<figcaption>`要素になります。
+/// キャプションの[位置]($figure.caption.position)は要素の順序で表現され、
+/// `{top}`の場合は本体の前に、`{bottom}`の場合は本体の後に配置されます。
+/// 図表の[`alt`]($figure.alt)は`<figure>`要素の`aria-label`属性として出力されます。
+/// 本体に含まれる画像の`alt`は、これまでどおり`<img>`要素の`alt`属性になります。
 #[elem(scope, Locatable, Tagged, Synthesize, Count, ShowSet, Refable, Outlinable)]
 pub struct FigureElem {
     /// 図表のコンテンツ。多くの場合、 [image] が使われます。
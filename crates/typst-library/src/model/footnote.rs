@@ -1,7 +1,7 @@
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 
-use ecow::{EcoString, eco_format};
+use ecow::{EcoString, EcoVec, eco_format};
 use typst_utils::NonZeroExt;
 
 use crate::diag::{At, SourceResult, StrResult, bail};
@@ -79,6 +79,13 @@ pub struct FootnoteElem {
     /// この脚注が参照すべき他の脚注のラベルを指定することもできます。
     #[required]
     pub body: FootnoteBody,
+
+    /// The footnotes in the first occurrence of the repeated table header or
+    /// footer cell this footnote is part of, keyed by [`FootnoteElem::key`].
+    /// Such a footnote displays the number of its first occurrence and
+    /// neither steps the counter nor produces another entry.
+    #[internal]
+    pub originals: Option<EcoVec<(u128, Location)>>,
 }
 
 #[scope]
@@ -121,6 +128,18 @@ impl FootnoteElem {
         matches!(self.body, FootnoteBody::Reference(_))
     }
 
+    /// Tests if this footnote is a repetition of a footnote in a table header
+    /// or footer.
+    pub fn is_repeated(&self) -> bool {
+        self.originals.get_ref(StyleChain::default()).is_some()
+    }
+
+    /// A key that identifies this footnote among the footnotes of a table
+    /// cell and stays the same when the cell is repeated.
+    pub fn key(&self) -> u128 {
+        typst_utils::hash128(&(self.span(), &self.body))
+    }
+
     /// Returns the content of the body of this footnote if it is not a ref.
     pub fn body_content(&self) -> Option<&Content> {
         match &self.body {
@@ -157,14 +176,27 @@ impl Packed<FootnoteElem> {
                 }
                 footnote.declaration_location(engine)
             }
-            _ => Ok(self.location().unwrap()),
+            _ => Ok(self.original_location().unwrap_or_else(|| self.location().unwrap())),
         }
     }
+
+    /// Returns the location of the first occurrence of a footnote in a
+    /// repeated table header or footer.
+    fn original_location(&self) -> Option<Location> {
+        let key = self.key();
+        self.originals
+            .get_ref(StyleChain::default())
+            .as_ref()?
+            .iter()
+            .find(|&&(k, _)| k == key)
+            .map(|&(_, loc)| loc)
+    }
 }
 
 impl Count for Packed<FootnoteElem> {
     fn update(&self) -> Option<CounterUpdate> {
-        (!self.is_ref()).then(|| CounterUpdate::Step(NonZeroUsize::ONE))
+        (!self.is_ref() && !self.is_repeated())
+            .then(|| CounterUpdate::Step(NonZeroUsize::ONE))
     }
 }
 
@@ -5,10 +5,12 @@ use chinese_number::{
 };
 use comemo::Tracked;
 use ecow::{EcoString, EcoVec, eco_format};
+use typst_syntax::Span;
 
-use crate::diag::SourceResult;
+use crate::diag::{At, HintedStrResult, SourceResult, bail};
 use crate::engine::Engine;
-use crate::foundations::{Context, Func, Str, Value, cast, func};
+use crate::foundations::{Array, Context, Dict, Func, Str, Value, cast, dict, func, ops};
+use crate::text::{Lang, TextElem};
 
 /// 順序に応じた番号付け。
 ///
@@ -50,24 +52,183 @@ use crate::foundations::{Context, Func, Str, Value, cast, func};
 pub fn numbering(
     engine: &mut Engine,
     context: Tracked<Context>,
+    span: Span,
     /// 番号付けの表示形式を定義します。
     ///
-    /// **カウント記号**として使用できるパターン文字は `1`, `a`, `A`, `i`, `I`, `α`, `Α`, `一`, `壹`, `あ`, `い`, `ア`, `イ`, `א`, `가`, `ㄱ`, `*`, `١`, `۱`, `१`, `১`, `ক`, `①`, `⓵`があります。
+    /// **カウント記号**として使用できるパターン文字は `1`, `a`, `A`, `i`, `I`, `ⅰ`, `Ⅰ`, `α`, `Α`, `一`, `壹`, `〇`, `壱`, `あ`, `い`, `ア`, `イ`, `א`, `가`, `ㄱ`, `*`, `١`, `۱`, `१`, `১`, `ক`, `①`, `⓵`, `⑴`, `１`, `¹`があります。
     /// これらの文字は、大文字・小文字を維持したまま、対応する順序の番号文字に置き換えられます。
+    /// `a`と`A`、`i`と`I`はそれぞれ別のカウント記号として扱われるため、
+    /// `{"A.a"}`のように1つのパターン内で混在させても、各階層にそれぞれの大小が適用されます。
+    /// カウント記号よりも多くの数値が指定された場合は、最後のカウント記号の大小が使用されます。
+    ///
+    /// ```example
+    /// #numbering("A.a.I.i", 1, 2, 3, 4) \
+    /// #numbering("A.a", 1, 2, 3)
+    /// ```
+    ///
+    /// _注意:_ `ⅰ`, `Ⅰ`, `〇`, `壱`, `⑴`, `１`, `¹`は以前のバージョンではカウント記号ではなかったため、
+    /// これらの文字をプレフィックスやサフィックスとして含むパターンは、以前とは異なる表示になります。
+    /// これらの文字をそのまま表示するには、[`prefix`]($numbering.prefix)引数と[`suffix`]($numbering.suffix)引数、
+    /// または関数による番号付けを使用してください。
+    ///
+    /// ```example
+    /// #numbering("1", 3, suffix: "¹") \
+    /// #numbering(n => [(#n)〇], 3)
+    /// ```
     ///
     /// 記号`*`は `*`, `†`, `‡`, `§`, `¶`, `‖`の順序で番号付けすることを意味します。
     /// 項目が6つ以上ある場合は、記号を繰り返し使用して番号を表現します。
     ///
+    /// 記号`Ⅰ`と`ⅰ`は、`Ⅰ`, `Ⅱ`, `Ⅲ`のような合成済みのローマ数字の記号で番号付けします。
+    /// 縦書きなど、ローマ数字を1文字で表したい場合に便利です。
+    /// 記号が用意されているのは12までで、それより大きい数値は通常のローマ数字で表されます。
+    ///
+    /// ```example
+    /// #numbering("Ⅰ", 4) \
+    /// #numbering("(ⅰ)", 12) \
+    /// #numbering("Ⅰ", 13)
+    /// ```
+    ///
+    /// 記号`¹`は、アラビア数字に[テキストの言語]($text.lang)に応じた序数の接尾辞を付けて番号付けします。
+    /// 序数の接尾辞がしばしば上付きで書かれることから、上付きの1を記号としています。
+    /// 例えば英語では`1st`, `2nd`, `3rd`, `11th`のようになります。
+    /// 序数表記に対応していない言語では、通常のアラビア数字が使用されます。
+    /// 言語を参照するため、この記号は[コンテキスト]($context)内で使用してください。
+    ///
+    /// ```example
+    /// #context numbering("¹", 1) \
+    /// #context numbering("¹", 12) \
+    /// #set text(lang: "fr")
+    /// #context numbering("¹", 1)
+    /// ```
+    ///
+    /// 記号`一`は中国語の数詞の規則で、記号`〇`は`百一`のように日本語の数詞の規則で漢数字を用いて番号付けします。
+    /// 記号`壱`は、契約書などで用いられる大字（`壱`, `弐`, `参`, `拾`, `萬`など）で番号付けします。
+    /// 大字では改ざんを防ぐため、`壱拾`のように位の前の`壱`も省略しません。
+    ///
+    /// ```example
+    /// #numbering("一", 110) \
+    /// #numbering("〇", 110) \
+    /// #numbering("〇", 23456) \
+    /// #numbering("壱", 110)
+    /// ```
+    ///
     /// **サフィックス**とは、最後のカウント記号の後ろに置く文字列です。
     /// これらは、生成された番号文字の末尾に、そのままの形で繰り返し表示されます。
     ///
     /// **プレフィックス**は、カウント記号でもサフィックスでもない文字列です。
     /// それぞれのカウント記号の前に、そのままの形で繰り返し表示されます。
     ///
+    /// プレフィックスとサフィックスには、絵文字や結合文字を含む任意の文字列を使用できます。
+    /// カウント記号は1文字（コードポイント）単位で判定されるため、
+    /// カウント記号の直後に結合文字を置いた場合、その結合文字は生成された番号文字に付きます。
+    ///
+    /// ```example
+    /// #numbering("📖 1.", 3) \
+    /// #numbering("1\u{fe0f}\u{20e3}", 4)
+    /// ```
+    ///
+    /// 記号の配列を指定すると、記号`*`と同様に、それらの記号を循環させて番号付けします。
+    /// 配列の各要素は1つの記号としてそのまま使用されるため、
+    /// 複数のコードポイントからなる絵文字が途中で分割されることはありません。
+    ///
+    /// ```example
+    /// #numbering(("🍎", "🍌", "👨‍👩‍👧"), 3) \
+    /// #numbering(("🍎", "🍌", "👨‍👩‍👧"), 5)
+    /// ```
+    ///
+    /// キー`alphabet`に記号の配列を持つ辞書を指定すると、記号`a`と同様に、
+    /// それらの記号を独自のアルファベットとして番号付けします。
+    /// 記号が尽きると`z`の次が`aa`になるように桁が増えていきます（N個の記号による全単射N進法）。
+    /// 記号が1つだけの場合は、その記号を数値の回数だけ繰り返します。
+    ///
+    /// ```example
+    /// #let suits = (alphabet: ("♠", "♥", "♦", "♣"))
+    /// #numbering(suits, 3) \
+    /// #numbering(suits, 5) \
+    /// #numbering(suits, 20)
+    /// ```
+    ///
     /// このパラメーターには、数値を個別の引数として受け取る任意の関数も指定できます。
     /// 関数が与えられた場合、`numbering`関数はその引数をそのまま関数に渡します。
     /// これ自体は特に便利というわけではありませんが、番号付けがパターン指定であっても関数指定であっても、番号付けの定義を`numbering`関数に適用できるという意味を持ちます。
     numbering: Numbering,
+    /// ローマ数字で4000以上の数値を表す際に、オーバーライン付きの記号（`V̅`など）を使用するかどうか。
+    ///
+    /// `{false}`の場合、オーバーラインを一切使わず、`MMMM`のようにASCII文字のみで加算的に表現します。
+    /// `ⅰ`や`Ⅰ`のパターンも`ⅳ`のようなローマ数字記号の代わりにASCII文字で表現されるため、
+    /// コピー&ペーストした際にも元の文字列が保たれます。
+    ///
+    /// ```example
+    /// #numbering("I", 5000) \
+    /// #numbering("I", 5000, overline: false)
+    /// ```
+    #[named]
+    #[default(true)]
+    overline: bool,
+    /// カウント記号よりも多くの数値が指定され、最後のカウント記号にプレフィックスがない場合に、
+    /// サフィックスを各階層の区切りとして繰り返すかどうか。
+    ///
+    /// `{false}`の場合、サフィックスは末尾に一度だけ表示され、
+    /// 繰り返された階層はパターン中の最後の区切り文字（カウント記号の間のプレフィックス）で区切られます。
+    /// パターンに区切り文字がない場合は`.`で区切られます。
+    ///
+    /// ```example
+    /// #numbering("1)", 1, 2, 3) \
+    /// #numbering("1)", 1, 2, 3, repeat-suffix: false) \
+    /// #numbering("1-A1)", 1, 2, 3, 4) \
+    /// #numbering("1-A1)", 1, 2, 3, 4, repeat-suffix: false)
+    /// ```
+    #[named]
+    #[default(true)]
+    repeat_suffix: bool,
+    /// アラビア数字などの位取り記数法による番号で、大きな数値の桁を区切るかどうか。
+    ///
+    /// 区切り文字と区切る間隔は、カウント記号と[テキストの言語]($text.lang)から決まります。
+    /// 例えば`1`では英語の場合`1,234`、ドイツ語の場合`1.234`となり、
+    /// `١`と`۱`ではアラビア語の桁区切り記号を用いて`١٬٢٣٤`のようになります。
+    /// `१`と`১`では、インドの記数法に従って`१२,३४,५६७`のように区切られます。
+    /// 言語に応じた区切り文字を用いるには、[コンテキスト]($context)内で使用してください。
+    ///
+    /// ```example
+    /// #numbering("1", 1234567, group-digits: true) \
+    /// #numbering("۱", 1234, group-digits: true) \
+    /// #set text(lang: "de")
+    /// #context numbering("1", 1234567, group-digits: true)
+    /// ```
+    #[named]
+    #[default(false)]
+    group_digits: bool,
+    /// 番号全体の前に付ける文字列。
+    ///
+    /// パターン中のプレフィックスとは異なり、階層の数にかかわらず先頭に一度だけ表示されます。
+    /// 言語コードをキーとする辞書を指定すると、[テキストの言語]($text.lang)に応じて文字列を切り替えます。
+    /// 辞書に含まれない言語では何も付けません。
+    /// 辞書を指定する場合は、[コンテキスト]($context)内で使用してください。
+    ///
+    /// ```example
+    /// #let chapter(..nums) = numbering(
+    ///   "1", ..nums,
+    ///   prefix: (en: "Chapter ", ja: "第"),
+    ///   suffix: (ja: "章"),
+    /// )
+    ///
+    /// #context chapter(3) \
+    /// #set text(lang: "ja")
+    /// #context chapter(3)
+    /// ```
+    #[named]
+    prefix: Option<NumberingAffix>,
+    /// 番号全体の後に付ける文字列。
+    ///
+    /// [`prefix`]($numbering.prefix)と同様に、言語コードをキーとする辞書も指定できます。
+    ///
+    /// ```example
+    /// #set text(lang: "ja")
+    /// #context numbering("1", 12, suffix: (en: " pages", ja: " ページ"))
+    /// ```
+    #[named]
+    suffix: Option<NumberingAffix>,
     /// 番号付けを適用する対象の数値。負でない数で与えてください。
     ///
     /// 一般に番号は1から数えます。値が0の場合は、最初の要素がまだ出現していないことを示します。
@@ -77,7 +238,38 @@ pub fn numbering(
     #[variadic]
     numbers: Vec<u64>,
 ) -> SourceResult<Value> {
-    numbering.apply(engine, context, &numbers)
+    let numbering = match numbering {
+        Numbering::Pattern(mut pattern) => {
+            if !overline {
+                pattern = pattern.without_overline();
+            }
+            if !repeat_suffix {
+                pattern = pattern.without_repeated_suffix();
+            }
+            if group_digits {
+                pattern = pattern.with_digit_grouping();
+            }
+            Numbering::Pattern(pattern)
+        }
+        numbering => numbering,
+    };
+
+    let value = numbering.apply(engine, context, &numbers)?;
+    if prefix.is_none() && suffix.is_none() {
+        return Ok(value);
+    }
+
+    let resolve = |affix: Option<NumberingAffix>| -> SourceResult<Value> {
+        let text = match affix {
+            None => EcoString::new(),
+            Some(NumberingAffix::Plain(text)) => text,
+            Some(affix) => affix.resolve(context.styles().at(span)?.get(TextElem::lang)),
+        };
+        Ok(Value::Str(text.into()))
+    };
+
+    let value = ops::join(resolve(prefix)?, value).at(span)?;
+    ops::join(value, resolve(suffix)?).at(span)
 }
 
 /// How to number a sequence of things.
@@ -85,6 +277,12 @@ pub fn numbering(
 pub enum Numbering {
     /// A pattern with prefix, numbering, lower / upper case and suffix.
     Pattern(NumberingPattern),
+    /// A cyclic sequence of user-defined symbols, which are repeated once the
+    /// sequence is exhausted.
+    Symbols(EcoVec<EcoString>),
+    /// User-defined symbols that are used like the letters of an alphabet,
+    /// i.e. as digits of a bijective base-N system.
+    Alphabet(EcoVec<EcoString>),
     /// A closure mapping from an item's number to content.
     Func(Func),
 }
@@ -98,11 +296,44 @@ impl Numbering {
         numbers: &[u64],
     ) -> SourceResult<Value> {
         Ok(match self {
-            Self::Pattern(pattern) => Value::Str(pattern.apply(numbers).into()),
+            Self::Pattern(pattern) => Value::Str(
+                match context.styles() {
+                    // Ordinals depend on the text language, which is only
+                    // known if we have styles.
+                    Ok(styles) => {
+                        pattern.apply_with_lang(numbers, styles.get(TextElem::lang))
+                    }
+                    Err(_) => pattern.apply(numbers),
+                }
+                .into(),
+            ),
+            Self::Symbols(symbols) => Value::Str(
+                numbers
+                    .iter()
+                    .map(|&n| symbolic(symbols, n))
+                    .collect::<EcoString>()
+                    .into(),
+            ),
+            Self::Alphabet(symbols) => Value::Str(
+                numbers
+                    .iter()
+                    .map(|&n| alphabetic_symbols(symbols, n))
+                    .collect::<EcoString>()
+                    .into(),
+            ),
             Self::Func(func) => func.call(engine, context, numbers.iter().copied())?,
         })
     }
 
+    /// Format all ordinals and digit groups according to the given language
+    /// if this is a pattern.
+    pub fn with_lang(self, lang: Lang) -> Self {
+        match self {
+            Self::Pattern(pattern) => Self::Pattern(pattern.with_lang(lang)),
+            numbering => numbering,
+        }
+    }
+
     /// Trim the prefix suffix if this is a pattern.
     pub fn trimmed(mut self) -> Self {
         if let Self::Pattern(pattern) = &mut self {
@@ -122,10 +353,92 @@ cast! {
     Numbering,
     self => match self {
         Self::Pattern(pattern) => pattern.into_value(),
+        Self::Symbols(symbols) => symbols
+            .into_iter()
+            .map(|symbol| Value::Str(symbol.into()))
+            .collect::<Array>()
+            .into_value(),
+        Self::Alphabet(symbols) => dict! {
+            "alphabet" => symbols
+                .into_iter()
+                .map(|symbol| Value::Str(symbol.into()))
+                .collect::<Array>()
+        }
+        .into_value(),
         Self::Func(func) => func.into_value(),
     },
     v: NumberingPattern => Self::Pattern(v),
     v: Func => Self::Func(v),
+    v: Array => {
+        let symbols = v
+            .into_iter()
+            .map(Value::cast::<EcoString>)
+            .collect::<HintedStrResult<EcoVec<_>>>()?;
+        if symbols.is_empty() {
+            bail!("array of numbering symbols must not be empty");
+        }
+        if symbols.iter().any(EcoString::is_empty) {
+            bail!("numbering symbols must not be empty");
+        }
+        Self::Symbols(symbols)
+    },
+    mut v: Dict => {
+        let symbols = v
+            .take("alphabet")?
+            .cast::<Array>()?
+            .into_iter()
+            .map(Value::cast::<EcoString>)
+            .collect::<HintedStrResult<EcoVec<_>>>()?;
+        v.finish(&["alphabet"])?;
+        if symbols.is_empty() {
+            bail!("numbering alphabet must not be empty");
+        }
+        if symbols.iter().any(EcoString::is_empty) {
+            bail!("numbering symbols must not be empty");
+        }
+        Self::Alphabet(symbols)
+    },
+}
+
+/// Text placed before or after a whole formatted number.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum NumberingAffix {
+    /// The same text for all languages.
+    Plain(EcoString),
+    /// Texts for specific languages. Other languages get no text.
+    Localized(EcoVec<(Lang, EcoString)>),
+}
+
+impl NumberingAffix {
+    /// The text to use for the given language.
+    pub fn resolve(&self, lang: Lang) -> EcoString {
+        match self {
+            Self::Plain(text) => text.clone(),
+            Self::Localized(texts) => texts
+                .iter()
+                .find(|(l, _)| *l == lang)
+                .map(|(_, text)| text.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+cast! {
+    NumberingAffix,
+    self => match self {
+        Self::Plain(text) => text.into_value(),
+        Self::Localized(texts) => texts
+            .into_iter()
+            .map(|(lang, text)| (lang.as_str().into(), text.into_value()))
+            .collect::<Dict>()
+            .into_value(),
+    },
+    v: EcoString => Self::Plain(v),
+    v: Dict => Self::Localized(
+        v.into_iter()
+            .map(|(key, value)| Ok((key.parse::<Lang>()?, value.cast::<EcoString>()?)))
+            .collect::<HintedStrResult<_>>()?,
+    ),
 }
 
 /// How to turn a number into text.
@@ -142,11 +455,26 @@ pub struct NumberingPattern {
     pub pieces: EcoVec<(EcoString, NumberingKind)>,
     pub suffix: EcoString,
     trimmed: bool,
+    repeat_suffix: bool,
+    /// The language whose separators are used to group the digits of large
+    /// numbers, if digit grouping is enabled.
+    group_digits: Option<Lang>,
 }
 
 impl NumberingPattern {
     /// Apply the pattern to the given number.
     pub fn apply(&self, numbers: &[u64]) -> EcoString {
+        self.apply_in(numbers, None)
+    }
+
+    /// Apply the pattern to the given number, formatting all ordinals and
+    /// digit groups according to the given language.
+    pub fn apply_with_lang(&self, numbers: &[u64], lang: Lang) -> EcoString {
+        self.apply_in(numbers, Some(lang))
+    }
+
+    /// Apply the pattern to the given number, in the given language if any.
+    fn apply_in(&self, numbers: &[u64], lang: Option<Lang>) -> EcoString {
         let mut fmt = EcoString::new();
         let mut numbers = numbers.iter();
 
@@ -155,16 +483,18 @@ impl NumberingPattern {
             if i > 0 || !self.trimmed {
                 fmt.push_str(prefix);
             }
-            fmt.push_str(&kind.apply(n));
+            fmt.push_str(&self.apply_kind(*kind, n, lang));
         }
 
         for ((prefix, kind), &n) in self.pieces.last().into_iter().cycle().zip(numbers) {
-            if prefix.is_empty() {
+            if !prefix.is_empty() {
+                fmt.push_str(prefix);
+            } else if self.repeat_suffix {
                 fmt.push_str(&self.suffix);
             } else {
-                fmt.push_str(prefix);
+                fmt.push_str(self.separator());
             }
-            fmt.push_str(&kind.apply(n));
+            fmt.push_str(&self.apply_kind(*kind, n, lang));
         }
 
         if !self.trimmed {
@@ -186,16 +516,83 @@ impl NumberingPattern {
             .chain(self.pieces.last().into_iter().cycle())
             .nth(k)
         {
-            fmt.push_str(&kind.apply(number));
+            fmt.push_str(&self.apply_kind(*kind, number, None));
         }
         fmt.push_str(&self.suffix);
         fmt
     }
 
+    /// Apply a single counting symbol of the pattern to a number, grouping its
+    /// digits if enabled. If a language is given, it overrides the one that
+    /// the pattern was configured with.
+    fn apply_kind(&self, kind: NumberingKind, n: u64, lang: Option<Lang>) -> EcoString {
+        let kind = lang.map_or(kind, |lang| kind.with_lang(lang));
+        let formatted = kind.apply(n);
+        let grouping = self.group_digits.map(|default| lang.unwrap_or(default));
+        match grouping.and_then(|lang| kind.digit_grouping(lang)) {
+            Some((separator, first, rest)) => {
+                group_digits(&formatted, separator, first, rest)
+            }
+            None => formatted,
+        }
+    }
+
+    /// Separate levels beyond the last counting symbol with the pattern's
+    /// last separator instead of the suffix if that symbol has no prefix, so
+    /// that the suffix only appears once at the end.
+    pub fn without_repeated_suffix(mut self) -> Self {
+        self.repeat_suffix = false;
+        self
+    }
+
+    /// The last non-empty prefix between two counting symbols, or a dot if
+    /// there is none.
+    fn separator(&self) -> &str {
+        self.pieces
+            .iter()
+            .skip(1)
+            .rev()
+            .map(|(prefix, _)| prefix.as_str())
+            .find(|prefix| !prefix.is_empty())
+            .unwrap_or(".")
+    }
+
+    /// Group the digits of large numbers with separators, e.g. `1,234` instead
+    /// of `1234`. The separators depend on the language set with
+    /// [`with_lang`](Self::with_lang), defaulting to English.
+    pub fn with_digit_grouping(mut self) -> Self {
+        self.group_digits = Some(Lang::ENGLISH);
+        self
+    }
+
     /// How many counting symbols this pattern has.
     pub fn pieces(&self) -> usize {
         self.pieces.len()
     }
+
+    /// Switch all Roman numerals in this pattern to their ASCII-only
+    /// variants, which use neither overlines for large numbers nor Roman
+    /// numeral symbols like `ⅳ`.
+    pub fn without_overline(mut self) -> Self {
+        for (_, kind) in self.pieces.make_mut() {
+            *kind = kind.without_overline();
+        }
+        self
+    }
+
+    /// Format all ordinals and digit groups in this pattern according to the
+    /// given language.
+    pub fn with_lang(mut self, lang: Lang) -> Self {
+        if let Some(grouping) = &mut self.group_digits {
+            *grouping = lang;
+        }
+        if self.pieces.iter().any(|&(_, kind)| kind.with_lang(lang) != kind) {
+            for (_, kind) in self.pieces.make_mut() {
+                *kind = kind.with_lang(lang);
+            }
+        }
+        self
+    }
 }
 
 impl FromStr for NumberingPattern {
@@ -205,6 +602,9 @@ impl FromStr for NumberingPattern {
         let mut pieces = EcoVec::new();
         let mut handled = 0;
 
+        // Both `i` and `i + c.len_utf8()` are always char boundaries, so the
+        // slicing below can't panic, even if the prefixes contain multi-byte
+        // characters or combining sequences.
         for (i, c) in pattern.char_indices() {
             let Some(kind) = NumberingKind::from_char(c) else {
                 continue;
@@ -212,7 +612,7 @@ impl FromStr for NumberingPattern {
 
             let prefix = pattern[handled..i].into();
             pieces.push((prefix, kind));
-            handled = c.len_utf8() + i;
+            handled = i + c.len_utf8();
         }
 
         let suffix = pattern[handled..].into();
@@ -220,7 +620,13 @@ impl FromStr for NumberingPattern {
             return Err("invalid numbering pattern");
         }
 
-        Ok(Self { pieces, suffix, trimmed: false })
+        Ok(Self {
+            pieces,
+            suffix,
+            trimmed: false,
+            repeat_suffix: true,
+            group_digits: None,
+        })
     }
 }
 
@@ -251,6 +657,18 @@ pub enum NumberingKind {
     LowerRoman,
     /// Uppercase Roman numerals (I, II, III, etc.).
     UpperRoman,
+    /// Lowercase Roman numerals without overlines. Numbers from 4000 onwards
+    /// use repeated m's.
+    LowerRomanAscii,
+    /// Uppercase Roman numerals without overlines. Numbers from 4000 onwards
+    /// use repeated M's.
+    UpperRomanAscii,
+    /// Lowercase Roman numeral symbols (ⅰ, ⅱ, ⅲ, etc.), up to 12. Further
+    /// items use regular lowercase Roman numerals.
+    LowerRomanUnicode,
+    /// Uppercase Roman numeral symbols (Ⅰ, Ⅱ, Ⅲ, etc.), up to 12. Further
+    /// items use regular uppercase Roman numerals.
+    UpperRomanUnicode,
     /// Lowercase Greek letters (α, β, γ, etc.).
     LowerGreek,
     /// Uppercase Greek letters (Α, Β, Γ, etc.).
@@ -278,6 +696,11 @@ pub enum NumberingKind {
     /// Traditional Chinese "banknote" numerals. This corresponds to the
     /// `ChineseCase::Upper` variant.
     UpperTraditionalChinese,
+    /// Japanese kanji numerals in everyday use (一, 十, 百, 千, 一万, etc.).
+    JapaneseKanji,
+    /// Japanese formal kanji numerals (daiji), as used in contracts (壱, 弐,
+    /// 参, 壱拾, etc.).
+    JapaneseKanjiFormal,
     /// Hiragana in the gojūon order. Includes n but excludes wi and we.
     HiraganaAiueo,
     /// Hiragana in the iroha order. Includes wi and we but excludes n.
@@ -304,6 +727,14 @@ pub enum NumberingKind {
     CircledNumber,
     /// Double-circled numbers (⓵, ⓶, ⓷, etc.), up to 10.
     DoubleCircledNumber,
+    /// Parenthesized numbers (⑴, ⑵, ⑶, etc.), up to 20.
+    ParenthesizedNumber,
+    /// Fullwidth Arabic numerals (１, ２, ３, etc.).
+    FullwidthNumber,
+    /// Arabic numerals with an ordinal indicator for the given language (1st,
+    /// 2nd, 3rd, etc. in English). Languages without known ordinal indicators
+    /// fall back to plain Arabic numerals.
+    Ordinal(Lang),
 }
 
 impl NumberingKind {
@@ -315,12 +746,16 @@ impl NumberingKind {
             'A' => NumberingKind::UpperLatin,
             'i' => NumberingKind::LowerRoman,
             'I' => NumberingKind::UpperRoman,
+            'ⅰ' => NumberingKind::LowerRomanUnicode,
+            'Ⅰ' => NumberingKind::UpperRomanUnicode,
             'α' => NumberingKind::LowerGreek,
             'Α' => NumberingKind::UpperGreek,
             '*' => NumberingKind::Symbol,
             'א' => NumberingKind::Hebrew,
             '一' => NumberingKind::LowerSimplifiedChinese,
             '壹' => NumberingKind::UpperSimplifiedChinese,
+            '〇' => NumberingKind::JapaneseKanji,
+            '壱' => NumberingKind::JapaneseKanjiFormal,
             'あ' => NumberingKind::HiraganaAiueo,
             'い' => NumberingKind::HiraganaIroha,
             'ア' => NumberingKind::KatakanaAiueo,
@@ -334,6 +769,9 @@ impl NumberingKind {
             '\u{0995}' => NumberingKind::BengaliLetter,
             '①' => NumberingKind::CircledNumber,
             '⓵' => NumberingKind::DoubleCircledNumber,
+            '⑴' => NumberingKind::ParenthesizedNumber,
+            '１' => NumberingKind::FullwidthNumber,
+            '¹' => NumberingKind::Ordinal(Lang::ENGLISH),
             _ => return None,
         })
     }
@@ -344,14 +782,18 @@ impl NumberingKind {
             Self::Arabic => '1',
             Self::LowerLatin => 'a',
             Self::UpperLatin => 'A',
-            Self::LowerRoman => 'i',
-            Self::UpperRoman => 'I',
+            Self::LowerRoman | Self::LowerRomanAscii => 'i',
+            Self::UpperRoman | Self::UpperRomanAscii => 'I',
+            Self::LowerRomanUnicode => 'ⅰ',
+            Self::UpperRomanUnicode => 'Ⅰ',
             Self::LowerGreek => 'α',
             Self::UpperGreek => 'Α',
             Self::Symbol => '*',
             Self::Hebrew => 'א',
             Self::LowerSimplifiedChinese | Self::LowerTraditionalChinese => '一',
             Self::UpperSimplifiedChinese | Self::UpperTraditionalChinese => '壹',
+            Self::JapaneseKanji => '〇',
+            Self::JapaneseKanjiFormal => '壱',
             Self::HiraganaAiueo => 'あ',
             Self::HiraganaIroha => 'い',
             Self::KatakanaAiueo => 'ア',
@@ -365,6 +807,61 @@ impl NumberingKind {
             Self::BengaliLetter => '\u{0995}',
             Self::CircledNumber => '①',
             Self::DoubleCircledNumber => '⓵',
+            Self::ParenthesizedNumber => '⑴',
+            Self::FullwidthNumber => '１',
+            Self::Ordinal(_) => '¹',
+        }
+    }
+
+    /// The ASCII-only variant of this numbering kind, which uses neither
+    /// overlines nor Roman numeral symbols. Only affects Roman numerals.
+    pub fn without_overline(self) -> Self {
+        match self {
+            Self::LowerRoman | Self::LowerRomanUnicode => Self::LowerRomanAscii,
+            Self::UpperRoman | Self::UpperRomanUnicode => Self::UpperRomanAscii,
+            kind => kind,
+        }
+    }
+
+    /// The variant of this numbering kind for the given language. Only
+    /// affects ordinals.
+    pub fn with_lang(self, lang: Lang) -> Self {
+        match self {
+            Self::Ordinal(_) => Self::Ordinal(lang),
+            kind => kind,
+        }
+    }
+
+    /// The separator and the sizes of the first and all further groups with
+    /// which the digits of large numbers are grouped in the given language.
+    /// `None` if this is not a positional numeral system.
+    pub fn digit_grouping(self, lang: Lang) -> Option<(char, usize, usize)> {
+        match self {
+            Self::Arabic => {
+                let separator = match lang {
+                    Lang::GERMAN
+                    | Lang::DANISH
+                    | Lang::DUTCH
+                    | Lang::ITALIAN
+                    | Lang::SPANISH
+                    | Lang::PORTUGUESE
+                    | Lang::INDONESIAN
+                    | Lang::TURKISH => '.',
+                    Lang::FRENCH => '\u{202F}',
+                    Lang::CZECH
+                    | Lang::FINNISH
+                    | Lang::NORWEGIAN_BOKMAL
+                    | Lang::POLISH
+                    | Lang::RUSSIAN
+                    | Lang::SWEDISH
+                    | Lang::UKRAINIAN => '\u{A0}',
+                    _ => ',',
+                };
+                Some((separator, 3, 3))
+            }
+            Self::EasternArabic | Self::EasternArabicPersian => Some(('٬', 3, 3)),
+            Self::DevanagariNumber | Self::BengaliNumber => Some((',', 3, 2)),
+            _ => None,
         }
     }
 
@@ -426,6 +923,58 @@ impl NumberingKind {
                 ],
                 n,
             ),
+            Self::LowerRomanAscii => additive(
+                &[
+                    ("m", 1000),
+                    ("cm", 900),
+                    ("d", 500),
+                    ("cd", 400),
+                    ("c", 100),
+                    ("xc", 90),
+                    ("l", 50),
+                    ("xl", 40),
+                    ("x", 10),
+                    ("ix", 9),
+                    ("v", 5),
+                    ("iv", 4),
+                    ("i", 1),
+                    ("n", 0),
+                ],
+                n,
+            ),
+            Self::UpperRomanAscii => additive(
+                &[
+                    ("M", 1000),
+                    ("CM", 900),
+                    ("D", 500),
+                    ("CD", 400),
+                    ("C", 100),
+                    ("XC", 90),
+                    ("L", 50),
+                    ("XL", 40),
+                    ("X", 10),
+                    ("IX", 9),
+                    ("V", 5),
+                    ("IV", 4),
+                    ("I", 1),
+                    ("N", 0),
+                ],
+                n,
+            ),
+            Self::LowerRomanUnicode => match n {
+                1..=12 => fixed(
+                    &['0', 'ⅰ', 'ⅱ', 'ⅲ', 'ⅳ', 'ⅴ', 'ⅵ', 'ⅶ', 'ⅷ', 'ⅸ', 'ⅹ', 'ⅺ', 'ⅻ'],
+                    n,
+                ),
+                _ => Self::LowerRoman.apply(n),
+            },
+            Self::UpperRomanUnicode => match n {
+                1..=12 => fixed(
+                    &['0', 'Ⅰ', 'Ⅱ', 'Ⅲ', 'Ⅳ', 'Ⅴ', 'Ⅵ', 'Ⅶ', 'Ⅷ', 'Ⅸ', 'Ⅹ', 'Ⅺ', 'Ⅻ'],
+                    n,
+                ),
+                _ => Self::UpperRoman.apply(n),
+            },
             Self::LowerGreek => additive(
                 &[
                     ("͵θ", 9000),
@@ -632,6 +1181,16 @@ impl NumberingKind {
             Self::DoubleCircledNumber => {
                 fixed(&['0', '⓵', '⓶', '⓷', '⓸', '⓹', '⓺', '⓻', '⓼', '⓽', '⓾'], n)
             }
+            Self::ParenthesizedNumber => fixed(
+                &[
+                    '0', '⑴', '⑵', '⑶', '⑷', '⑸', '⑹', '⑺', '⑻', '⑼', '⑽', '⑾', '⑿', '⒀',
+                    '⒁', '⒂', '⒃', '⒄', '⒅', '⒆', '⒇',
+                ],
+                n,
+            ),
+            Self::FullwidthNumber => {
+                numeric(&['０', '１', '２', '３', '４', '５', '６', '７', '８', '９'], n)
+            }
 
             Self::LowerSimplifiedChinese => {
                 u64_to_chinese(ChineseVariant::Simple, ChineseCase::Lower, n).into()
@@ -645,6 +1204,20 @@ impl NumberingKind {
             Self::UpperTraditionalChinese => {
                 u64_to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, n).into()
             }
+            Self::JapaneseKanji => japanese(
+                &['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'],
+                &['十', '百', '千'],
+                &['万', '億', '兆', '京'],
+                false,
+                n,
+            ),
+            Self::JapaneseKanjiFormal => japanese(
+                &['零', '壱', '弐', '参', '四', '五', '六', '七', '八', '九'],
+                &['拾', '百', '千'],
+                &['萬', '億', '兆', '京'],
+                true,
+                n,
+            ),
 
             Self::EasternArabic => {
                 numeric(&['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'], n)
@@ -658,7 +1231,8 @@ impl NumberingKind {
             Self::BengaliNumber => {
                 numeric(&['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'], n)
             }
-            Self::Symbol => symbolic(&['*', '†', '‡', '§', '¶', '‖'], n),
+            Self::Symbol => symbolic(&["*", "†", "‡", "§", "¶", "‖"], n),
+            Self::Ordinal(lang) => ordinal(lang, n),
         }
     }
 }
@@ -707,6 +1281,65 @@ fn additive(symbols: &[(&str, u64)], mut n: u64) -> EcoString {
     s
 }
 
+/// Stringify a number using Japanese numerals, which name the powers of ten up
+/// to a thousand (`units`) and every fourth power of ten from ten thousand
+/// onwards (`myriads`).
+///
+/// Consider the situation of everyday Japanese numerals,
+///
+/// ```text
+/// 10 => '十'
+/// 11 => '十一'
+/// 110 => '百十'
+/// 10000 => '一万'
+/// 23456 => '二万三千四百五十六'
+/// ```
+///
+/// where the digit one is omitted before units, but not before myriads. If
+/// `explicit_one` is set, it is never omitted, as is customary for formal
+/// numerals.
+fn japanese(
+    digits: &[char; 10],
+    units: &[char; 3],
+    myriads: &[char; 4],
+    explicit_one: bool,
+    n: u64,
+) -> EcoString {
+    if n == 0 {
+        return digits[0].into();
+    }
+
+    let mut groups = vec![];
+    let mut rest = n;
+    while rest > 0 {
+        groups.push(rest % 10000);
+        rest /= 10000;
+    }
+
+    let mut s = EcoString::new();
+    for (k, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        for place in (0..4).rev() {
+            let digit = (group / 10_u64.pow(place)) % 10;
+            if digit == 0 {
+                continue;
+            }
+            if place == 0 || digit != 1 || explicit_one {
+                s.push(digits[digit as usize]);
+            }
+            if place > 0 {
+                s.push(units[place as usize - 1]);
+            }
+        }
+        if k > 0 {
+            s.push(myriads[k - 1]);
+        }
+    }
+    s
+}
+
 /// Stringify a number using a base-n (where n is the number of provided
 /// symbols) system without a zero symbol.
 ///
@@ -790,6 +1423,25 @@ fn numeric(symbols: &[char], mut n: u64) -> EcoString {
     s.chars().rev().collect()
 }
 
+/// Stringify a number like [`alphabetic`], but with symbols that may consist
+/// of multiple characters.
+///
+/// With a single symbol, this degrades to a unary system in which the symbol
+/// is repeated `n` times.
+fn alphabetic_symbols(symbols: &[EcoString], mut n: u64) -> EcoString {
+    let n_digits = symbols.len() as u64;
+    if n == 0 {
+        return '-'.into();
+    }
+    let mut digits = vec![];
+    while n != 0 {
+        n -= 1;
+        digits.push(&symbols[(n % n_digits) as usize]);
+        n /= n_digits;
+    }
+    digits.into_iter().rev().cloned().collect()
+}
+
 /// Stringify a number using repeating symbols.
 ///
 /// Consider the situation where ['A', 'B', 'C'] are the provided symbols,
@@ -805,11 +1457,67 @@ fn numeric(symbols: &[char], mut n: u64) -> EcoString {
 /// 7 => 'AAA'
 /// ...
 /// ```
-fn symbolic(symbols: &[char], n: u64) -> EcoString {
+fn symbolic(symbols: &[impl AsRef<str>], n: u64) -> EcoString {
     let n_digits = symbols.len() as u64;
     if n == 0 {
         return '-'.into();
     }
-    EcoString::from(symbols[((n - 1) % n_digits) as usize])
+    EcoString::from(symbols[((n - 1) % n_digits) as usize].as_ref())
         .repeat((n.div_ceil(n_digits)) as usize)
 }
+
+/// Insert a separator between groups of digits, counting from the right. The
+/// rightmost group has `first` digits and all other groups have `rest` digits.
+///
+/// ```text
+/// (',', 3, 3): 1234567 => '1,234,567'
+/// (',', 3, 2): 1234567 => '12,34,567'
+/// ```
+fn group_digits(digits: &str, separator: char, first: usize, rest: usize) -> EcoString {
+    let len = digits.chars().count();
+    let mut fmt = EcoString::new();
+    for (i, c) in digits.chars().enumerate() {
+        let remaining = len - i;
+        if i > 0 && remaining >= first && (remaining - first) % rest == 0 {
+            fmt.push(separator);
+        }
+        fmt.push(c);
+    }
+    fmt
+}
+
+/// Stringify a number as an ordinal in the given language.
+///
+/// ```text
+/// en: 1 => '1st', 2 => '2nd', 3 => '3rd', 4 => '4th', 11 => '11th', 21 => '21st'
+/// fr: 1 => '1er', 2 => '2e'
+/// de: 1 => '1.'
+/// es: 1 => '1º'
+/// ja: 1 => '第1'
+/// ```
+///
+/// Languages without known ordinal indicators use plain Arabic numerals.
+fn ordinal(lang: Lang, n: u64) -> EcoString {
+    match lang {
+        Lang::ENGLISH => {
+            let suffix = match (n % 10, n % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            eco_format!("{n}{suffix}")
+        }
+        Lang::FRENCH if n == 1 => "1er".into(),
+        Lang::FRENCH | Lang::DUTCH => eco_format!("{n}e"),
+        Lang::GERMAN
+        | Lang::DANISH
+        | Lang::NORWEGIAN_BOKMAL
+        | Lang::FINNISH
+        | Lang::CZECH => eco_format!("{n}."),
+        Lang::SPANISH | Lang::ITALIAN | Lang::PORTUGUESE => eco_format!("{n}º"),
+        Lang::JAPANESE | Lang::CHINESE => eco_format!("第{n}"),
+        _ => eco_format!("{n}"),
+    }
+}
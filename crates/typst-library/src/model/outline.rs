@@ -131,6 +131,14 @@ use crate::text::{LocalName, SpaceElem, TextElem};
 /// == History
 /// ```
 ///
+/// # HTMLエクスポート
+/// HTMLエクスポートでは、アウトラインは`role="doc-toc"`を持つ`<nav>`要素として出力され、
+/// 項目は見出しのレベルに応じて入れ子になった`<ol>`と`<li>`要素になります。
+/// 各項目は対象要素へのリンクとなり、リンク先の要素にはラベルに基づく`id`属性、
+/// またはラベルがない場合は自動生成された`id`属性が付与されます。
+/// `<nav>`要素の`aria-label`属性には[タイトル]($outline.title)のテキストが設定され、
+/// タイトルが`{auto}`または`{none}`の場合は[テキストの言語]($text.lang)に応じた名前が使われます。
+///
 /// [^1]: 数式のアウトラインはこのルールの例外で、本文を持たないためインデント付きのレイアウトは使用しません。
 #[elem(scope, keywords = ["Table of Contents", "toc"], ShowSet, LocalName, Locatable, Tagged)]
 pub struct OutlineElem {
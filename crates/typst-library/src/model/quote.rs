@@ -41,6 +41,12 @@ use crate::text::{SmartQuotes, SpaceElem, TextElem};
 ///   flame of Udûn. Go back to the Shadow! You cannot pass.
 /// ]
 /// ```
+///
+/// # HTMLエクスポート
+/// HTMLエクスポートでは、ブロック引用は`<blockquote>`要素に、インライン引用は`<q>`要素に変換されます。
+/// `<q>`要素の引用符はブラウザが表示するため、インライン引用に二重引用符は追加されません。
+/// 帰属情報は、ブロック引用では`<blockquote>`内の`<footer>`要素として出力されます。
+/// また、帰属情報がURLへの[リンク]($link)である場合、そのURLが`cite`属性として付与されます。
 #[elem(Locatable, Tagged, ShowSet)]
 pub struct QuoteElem {
     /// ブロック引用にするかどうか。
@@ -226,6 +226,10 @@ pub struct TableElem {
     /// - an array of fill corresponding to each column
     /// - a function that maps a cell's position to its fill
     ///
+    /// If the function takes three positional parameters, the cell's body is
+    /// passed as the third argument. This way, you can, for instance, only
+    /// fill empty cells.
+    ///
     /// Most notably, arrays and functions are useful for creating striped
     /// tables. See the [Table Guide]($guides/tables/#fills) for more
     /// details.
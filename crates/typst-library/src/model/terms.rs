@@ -22,6 +22,11 @@ use crate::model::{ListItemLike, ListLike};
 /// # Syntax
 /// This function also has dedicated syntax: Starting a line with a slash,
 /// followed by a term, a colon and a description creates a term list item.
+///
+/// # HTML export
+/// In HTML export, a term list becomes a `<dl>` element, with a `<dt>` for
+/// each term and a `<dd>` for each description. Adjacent items with the same
+/// term share a single `<dt>` followed by all of their descriptions.
 #[elem(scope, title = "Term List", Locatable, Tagged)]
 pub struct TermsElem {
     /// Defines the default [spacing]($terms.spacing) of the term list. If it is
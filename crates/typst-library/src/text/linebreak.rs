@@ -18,6 +18,15 @@ use crate::foundations::{Content, NativeElement, elem};
 /// この関数は専用の構文も持っています。
 /// 改行を挿入するには、単にバックスラッシュと空白を書いてください。
 /// これは常に両端揃えではない改行を作成します。
+///
+/// # HTMLエクスポート
+/// HTMLへのエクスポート時、改行は`<br>`要素として出力されます。
+/// 連続した改行はそれぞれ`<br>`となり、ブラウザーでは空行として表示されます。
+/// 段落の終わりにある単一の`<br>`はブラウザーで無視されるため、
+/// 段落末の改行の扱いはページ出力と一致します。
+///
+/// ブラウザーは1行だけを両端揃えすることができないため、
+/// `justify`が`{true}`の改行は警告とともに通常の`<br>`として出力されます。
 #[elem(title = "Line Break")]
 pub struct LinebreakElem {
     /// 改行の前の行を両端揃えするかどうか。
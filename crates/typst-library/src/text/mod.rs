@@ -286,9 +286,25 @@ pub struct TextElem {
 
     /// How to stroke the text.
     ///
+    /// Dash lengths relative to the stroked path are not supported, as glyph
+    /// outlines have no meaningful path length.
+    ///
     /// ```example
     /// #text(stroke: 0.5pt + red)[Stroked]
     /// ```
+    #[parse({
+        let stroke: Option<Spanned<Option<Stroke>>> = args.named("stroke")?;
+        if let Some(Spanned { v: Some(stroke), span }) = &stroke
+            && let Smart::Custom(Some(dash)) = &stroke.dash
+            && dash.is_relative() {
+                bail!(
+                    *span,
+                    "text strokes cannot have relative dash lengths";
+                    hint: "use absolute lengths like `2pt` instead"
+                );
+            }
+        stroke.map(|stroke| stroke.v)
+    })]
     #[ghost]
     pub stroke: Option<Stroke>,
 
@@ -492,6 +508,9 @@ pub struct TextElem {
     /// and `end`, which are equivalent to `left` and `right` in `ltr` text and
     /// the other way around in `rtl` text.
     ///
+    /// In HTML export, paragraphs and headings in right-to-left text receive a
+    /// `dir` attribute with the value `rtl`.
+    ///
     /// If you set this to `rtl` and experience bugs or in some way bad looking
     /// output, please get in touch with us through the
     /// [Forum](https://forum.typst.app/),
@@ -1003,10 +1022,10 @@ pub struct TextSize(pub Length);
 impl Fold for TextSize {
     fn fold(self, outer: Self) -> Self {
         // Multiply the two linear functions.
-        Self(Length {
-            em: Em::new(self.0.em.get() * outer.0.em.get()),
-            abs: self.0.em.get() * outer.0.abs + self.0.abs,
-        })
+        Self(
+            Length::from(self.0.em.get() * outer.0.abs + self.0.abs)
+                + Length::from(Em::new(self.0.em.get() * outer.0.em.get())),
+        )
     }
 }
 
@@ -253,6 +253,12 @@ pub struct RawElem {
     /// [`text`]関数を用いて前景色を、[ブロックの塗り潰し]($block.fill)を用いて背景色を、それぞれ手動で設定できます。
     /// [`xml`]関数でもこれらのプロパティをテーマから抽出できます。
     ///
+    /// HTML出力では、コードは`<code>`要素（ブロックの場合は`<pre>`で囲まれたもの）として出力され、
+    /// 言語が指定されていれば`class="language-..."`属性が付与されます。
+    /// ハイライトは色を指定した`<span>`要素として出力されます。
+    /// `{none}`を指定すると、言語のクラスのみを持つ生のテキストが出力されるため、
+    /// Prism.jsなどのクライアント側のハイライトと組み合わせられます。
+    ///
     /// ````example
     /// #set raw(theme: "halcyon.tmTheme")
     /// #show raw: it => block(
@@ -1,11 +1,11 @@
-use kurbo::ParamCurveExtrema;
+use kurbo::{ParamCurveArclen, ParamCurveExtrema};
 use typst_macros::{Cast, scope};
 use typst_utils::Numeric;
 
 use crate::diag::{HintedStrResult, HintedString, bail};
 use crate::foundations::{Content, Packed, Smart, cast, elem};
-use crate::layout::{Abs, Axes, Length, Point, Rect, Rel, Size};
-use crate::visualize::{FillRule, Paint, Stroke};
+use crate::layout::{Abs, Axes, Length, Point, Rect, Rel, Size, Transform};
+use crate::visualize::{FillRule, LineMarker, Paint, Stroke};
 
 use super::FixedStroke;
 
@@ -86,6 +86,30 @@ pub struct CurveElem {
     #[fold]
     pub stroke: Smart<Option<Stroke>>,
 
+    /// The marker to draw at the start of the curve.
+    ///
+    /// Markers are only drawn for curves with a stroke. The marker sits at the
+    /// start of the first segment and points away from it. See the
+    /// [`start-cap`]($line.start-cap) of lines for the available markers.
+    ///
+    /// ```example
+    /// #curve(
+    ///   stroke: 1.5pt,
+    ///   start-cap: "circle",
+    ///   end-cap: "arrow",
+    ///   curve.move((0pt, 30pt)),
+    ///   curve.cubic((30pt, -10pt), (60pt, 50pt), (90pt, 10pt)),
+    /// )
+    /// ```
+    pub start_cap: Option<LineMarker>,
+
+    /// The marker to draw at the end of the curve.
+    ///
+    /// The marker sits at the end of the last segment and points in its
+    /// direction. Curves whose last subpath is closed have no marker at the
+    /// end.
+    pub end_cap: Option<LineMarker>,
+
     /// The components of the curve, in the form of moves, line and Bézier
     /// segment, and closes.
     #[variadic]
@@ -474,6 +498,105 @@ impl Curve {
         }
     }
 
+    /// Transform all points in this curve with the given transform.
+    pub fn transform(&mut self, ts: Transform) {
+        if ts.is_identity() {
+            return;
+        }
+        for item in self.0.iter_mut() {
+            match item {
+                CurveItem::Move(p) => *p = p.transform(ts),
+                CurveItem::Line(p) => *p = p.transform(ts),
+                CurveItem::Cubic(p1, p2, p3) => {
+                    *p1 = p1.transform(ts);
+                    *p2 = p2.transform(ts);
+                    *p3 = p3.transform(ts);
+                }
+                CurveItem::Close => (),
+            }
+        }
+    }
+
+    /// The start point of this curve and the direction in which it leaves
+    /// the start point, if it has any non-degenerate segment.
+    pub fn start_direction(&self) -> Option<(Point, Point)> {
+        let mut items = self.0.iter();
+        let CurveItem::Move(start) = items.next()? else { return None };
+        let targets = match items.next()? {
+            CurveItem::Line(p) => [*p, *p, *p],
+            CurveItem::Cubic(p1, p2, p3) => [*p1, *p2, *p3],
+            CurveItem::Move(_) | CurveItem::Close => return None,
+        };
+        let target = targets.into_iter().find(|p| p != start)?;
+        Some((*start, target - *start))
+    }
+
+    /// Returns the end point of the curve and the direction in which the last
+    /// segment arrives there, if the curve ends with an open segment.
+    pub fn end_direction(&self) -> Option<(Point, Point)> {
+        let mut start = Point::zero();
+        let mut cursor = Point::zero();
+        let mut last = None;
+        for item in &self.0 {
+            match *item {
+                CurveItem::Move(p) => {
+                    start = p;
+                    cursor = p;
+                    last = None;
+                }
+                CurveItem::Line(p) => {
+                    last = Some((p, [cursor, cursor, cursor]));
+                    cursor = p;
+                }
+                CurveItem::Cubic(p1, p2, p3) => {
+                    last = Some((p3, [p2, p1, cursor]));
+                    cursor = p3;
+                }
+                CurveItem::Close => {
+                    last = None;
+                    cursor = start;
+                }
+            }
+        }
+        let (end, sources) = last?;
+        let source = sources.into_iter().find(|p| *p != end)?;
+        Some((end, end - source))
+    }
+
+    /// Moves the start of the first segment forward and the end of the last
+    /// segment backward by the given distances.
+    ///
+    /// Control points next to the moved points move along with them, so that
+    /// the directions at the ends are kept. The distances should be shorter
+    /// than the segments they apply to.
+    pub fn shorten(&mut self, start: Abs, end: Abs) {
+        if !start.is_zero()
+            && let Some((_, direction)) = self.start_direction()
+        {
+            let offset = direction * (start / direction.hypot());
+            if let Some(CurveItem::Move(p)) = self.0.first_mut() {
+                *p = *p + offset;
+            }
+            if let Some(CurveItem::Cubic(p1, _, _)) = self.0.get_mut(1) {
+                *p1 = *p1 + offset;
+            }
+        }
+
+        if !end.is_zero()
+            && let Some((_, direction)) = self.end_direction()
+        {
+            let offset = direction * (end / direction.hypot());
+            match self.0.last_mut() {
+                Some(CurveItem::Line(p)) => *p = *p - offset,
+                Some(CurveItem::Cubic(_, p2, p3)) => {
+                    *p2 = *p2 - offset;
+                    *p3 = *p3 - offset;
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Computes the bounding box of this curve.
     pub fn bbox(&self) -> Rect {
         let mut min = Point::splat(Abs::inf());
@@ -516,6 +639,201 @@ impl Curve {
     pub fn bbox_size(&self) -> Size {
         self.bbox().size()
     }
+
+    /// Computes the total length of all segments of this curve, including the
+    /// lines added by closing subpaths.
+    pub fn length(&self) -> Abs {
+        let mut length = 0.0;
+        let mut start = Point::zero();
+        let mut cursor = Point::zero();
+        for item in self.0.iter() {
+            match item {
+                CurveItem::Move(to) => {
+                    start = *to;
+                    cursor = *to;
+                }
+                CurveItem::Line(to) => {
+                    length += (*to - cursor).hypot().to_pt();
+                    cursor = *to;
+                }
+                CurveItem::Cubic(c0, c1, end) => {
+                    let cubic = kurbo::CubicBez::new(
+                        kurbo::Point::new(cursor.x.to_pt(), cursor.y.to_pt()),
+                        kurbo::Point::new(c0.x.to_pt(), c0.y.to_pt()),
+                        kurbo::Point::new(c1.x.to_pt(), c1.y.to_pt()),
+                        kurbo::Point::new(end.x.to_pt(), end.y.to_pt()),
+                    );
+                    length += cubic.arclen(1e-3);
+                    cursor = *end;
+                }
+                CurveItem::Close => {
+                    length += (start - cursor).hypot().to_pt();
+                    cursor = start;
+                }
+            }
+        }
+        Abs::pt(length)
+    }
+
+    /// Approximates each subpath of this curve with a polyline that deviates
+    /// from it by at most `tolerance`.
+    ///
+    /// Returns the vertices of each subpath along with whether it is closed.
+    /// The vertices of a closed subpath end with its starting point.
+    pub fn flatten(&self, tolerance: Abs) -> Vec<(Vec<Point>, bool)> {
+        let mut polylines: Vec<(Vec<Point>, bool)> = vec![];
+        kurbo::flatten(self.to_kurbo(), tolerance.to_raw(), |el| match el {
+            kurbo::PathEl::MoveTo(p) => {
+                polylines.push((vec![point_from_kurbo(p)], false))
+            }
+            kurbo::PathEl::LineTo(p) => {
+                if let Some((points, _)) = polylines.last_mut() {
+                    points.push(point_from_kurbo(p));
+                }
+            }
+            kurbo::PathEl::ClosePath => {
+                if let Some((points, closed)) = polylines.last_mut() {
+                    if points.last() != points.first() {
+                        points.push(points[0]);
+                    }
+                    *closed = true;
+                }
+            }
+            _ => {}
+        });
+        polylines
+    }
+
+    /// Rounds off the corners between consecutive line segments with circular
+    /// arcs of the given radius.
+    ///
+    /// Where the adjacent segments are too short to fit an arc of the full
+    /// radius, the radius is reduced. Subpaths that contain Bézier segments
+    /// are kept as-is.
+    pub fn round_corners(&self, radius: Abs) -> Curve {
+        let mut rounded = Curve::new();
+        let mut start = 0;
+        while start < self.0.len() {
+            let end = self.0[start + 1..]
+                .iter()
+                .position(|item| matches!(item, CurveItem::Move(_)))
+                .map_or(self.0.len(), |i| start + 1 + i);
+            let subpath = &self.0[start..end];
+            match polyline(subpath) {
+                Some((points, closed)) => {
+                    round_polyline(&mut rounded, &points, closed, radius)
+                }
+                None => rounded.0.extend_from_slice(subpath),
+            }
+            start = end;
+        }
+        rounded
+    }
+}
+
+/// Extracts the points of a subpath that consists only of lines, along with
+/// whether it is closed.
+fn polyline(items: &[CurveItem]) -> Option<(Vec<Point>, bool)> {
+    let (CurveItem::Move(first), rest) = items.split_first()? else {
+        return None;
+    };
+
+    let mut points = vec![*first];
+    let mut closed = false;
+    for (i, item) in rest.iter().enumerate() {
+        match item {
+            CurveItem::Line(to) => points.push(*to),
+            CurveItem::Close if i + 1 == rest.len() => closed = true,
+            _ => return None,
+        }
+    }
+
+    // An explicit line back to the start is subsumed by the closing segment.
+    if closed && points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    Some((points, closed))
+}
+
+/// Pushes a polyline with rounded corners to the curve.
+fn round_polyline(curve: &mut Curve, points: &[Point], closed: bool, radius: Abs) {
+    let n = points.len();
+    let corners: Vec<_> = (0..n)
+        .map(|i| {
+            if !closed && (i == 0 || i == n - 1) {
+                return None;
+            }
+            round_corner(points[(i + n - 1) % n], points[i], points[(i + 1) % n], radius)
+        })
+        .collect();
+
+    // A closed polyline starts where the arc of its first corner ends.
+    let start = match corners[0] {
+        Some([.., end]) if closed => end,
+        _ => points[0],
+    };
+
+    curve.move_(start);
+    for (&point, corner) in points.iter().zip(&corners).skip(1) {
+        match *corner {
+            Some([from, c1, c2, to]) => {
+                curve.line(from);
+                curve.cubic(c1, c2, to);
+            }
+            None => curve.line(point),
+        }
+    }
+
+    if closed {
+        if let Some([from, c1, c2, to]) = corners[0] {
+            curve.line(from);
+            curve.cubic(c1, c2, to);
+        }
+        curve.close();
+    }
+}
+
+/// Computes the arc rounding off the corner at `at` between the lines from
+/// `prev` and to `next`.
+///
+/// Returns the start point, the two control points, and the end point of a
+/// cubic Bézier segment approximating the arc, or `None` if there is no
+/// corner to round.
+fn round_corner(prev: Point, at: Point, next: Point, radius: Abs) -> Option<[Point; 4]> {
+    use std::f64::consts::PI;
+
+    let to_vec = |p: Point| kurbo::Vec2::new(p.x.to_raw(), p.y.to_raw());
+    let (a, b) = (to_vec(prev - at), to_vec(next - at));
+    let (la, lb) = (a.hypot(), b.hypot());
+    if la == 0.0 || lb == 0.0 {
+        return None;
+    }
+
+    // The angle enclosed by the two lines. Straight continuations and full
+    // reversals have no corner to round.
+    let (ua, ub) = (a / la, b / lb);
+    let angle = ua.dot(ub).clamp(-1.0, 1.0).acos();
+    if !(1e-6..=PI - 1e-6).contains(&angle) {
+        return None;
+    }
+
+    // The distance from the corner to where the arc touches the lines. It is
+    // limited to half of each line so that arcs of adjacent corners can't
+    // overlap.
+    let tan = (angle / 2.0).tan();
+    let dist = (radius.to_raw() / tan).min(la / 2.0).min(lb / 2.0);
+    let radius = dist * tan;
+
+    // See <https://spencermortensen.com/articles/bezier-circle/>.
+    let handle = 4.0 / 3.0 * ((PI - angle) / 4.0).tan() * radius;
+    let point = |v: kurbo::Vec2| at + Point::new(Abs::raw(v.x), Abs::raw(v.y));
+    Some([
+        point(ua * dist),
+        point(ua * (dist - handle)),
+        point(ub * (dist - handle)),
+        point(ub * dist),
+    ])
 }
 
 impl Curve {
@@ -564,6 +882,7 @@ impl Curve {
             .with_join(join)
             .with_miter_limit(miter_limit);
         if let Some(dash) = &stroke.dash {
+            let dash = dash.along(self.length());
             style = style.with_dashes(
                 dash.phase.to_raw(),
                 dash.array.iter().copied().map(Abs::to_raw),
@@ -579,3 +898,144 @@ impl Curve {
 fn point_to_kurbo(point: Point) -> kurbo::Point {
     kurbo::Point::new(point.x.to_raw(), point.y.to_raw())
 }
+
+fn point_from_kurbo(point: kurbo::Point) -> Point {
+    Point::new(Abs::raw(point.x), Abs::raw(point.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Angle;
+
+    fn pt(x: f64, y: f64) -> Point {
+        Point::new(Abs::pt(x), Abs::pt(y))
+    }
+
+    fn assert_approx_eq(a: Point, b: Point) {
+        assert!(a.x.approx_eq(b.x) && a.y.approx_eq(b.y), "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_round_corners_open() {
+        let mut curve = Curve::new();
+        curve.move_(pt(0.0, 0.0));
+        curve.line(pt(10.0, 0.0));
+        curve.line(pt(10.0, 10.0));
+
+        let rounded = curve.round_corners(Abs::pt(2.0));
+        let [
+            CurveItem::Move(start),
+            CurveItem::Line(from),
+            CurveItem::Cubic(_, _, to),
+            CurveItem::Line(end),
+        ] = rounded.0.as_slice()
+        else {
+            panic!("unexpected curve {rounded:?}");
+        };
+
+        assert_approx_eq(*start, pt(0.0, 0.0));
+        assert_approx_eq(*from, pt(8.0, 0.0));
+        assert_approx_eq(*to, pt(10.0, 2.0));
+        assert_approx_eq(*end, pt(10.0, 10.0));
+    }
+
+    #[test]
+    fn test_round_corners_limited_by_segments() {
+        let mut curve = Curve::new();
+        curve.move_(pt(0.0, 0.0));
+        curve.line(pt(10.0, 0.0));
+        curve.line(pt(10.0, 4.0));
+
+        let rounded = curve.round_corners(Abs::pt(100.0));
+        let CurveItem::Cubic(_, _, to) = rounded.0[2] else {
+            panic!("unexpected curve {rounded:?}");
+        };
+        assert_approx_eq(to, pt(10.0, 2.0));
+    }
+
+    #[test]
+    fn test_round_corners_closed() {
+        let rounded = Curve::rect(Size::new(Abs::pt(10.0), Abs::pt(10.0)))
+            .round_corners(Abs::pt(1.0));
+        let items = &rounded.0;
+        assert_eq!(items.len(), 10);
+        assert_eq!(
+            items
+                .iter()
+                .filter(|item| matches!(item, CurveItem::Cubic(..)))
+                .count(),
+            4
+        );
+        assert_eq!(items.last(), Some(&CurveItem::Close));
+
+        // The curve starts and ends where the arc of the first corner ends.
+        let (CurveItem::Move(start), CurveItem::Cubic(_, _, end)) =
+            (&items[0], &items[8])
+        else {
+            panic!("unexpected curve {rounded:?}");
+        };
+        assert_approx_eq(*start, pt(1.0, 0.0));
+        assert_approx_eq(*end, pt(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_round_corners_keeps_beziers() {
+        let curve = Curve::ellipse(Size::new(Abs::pt(10.0), Abs::pt(5.0)));
+        assert_eq!(curve.round_corners(Abs::pt(1.0)), curve);
+    }
+
+    #[test]
+    fn test_end_direction() {
+        let mut curve = Curve::new();
+        curve.move_(pt(0.0, 0.0));
+        curve.line(pt(10.0, 0.0));
+        curve.cubic(pt(20.0, 0.0), pt(20.0, 10.0), pt(20.0, 10.0));
+        assert_eq!(curve.end_direction(), Some((pt(20.0, 10.0), pt(0.0, 10.0))));
+        curve.close();
+        assert_eq!(curve.end_direction(), None);
+    }
+
+    #[test]
+    fn test_shorten() {
+        let mut curve = Curve::new();
+        curve.move_(pt(0.0, 0.0));
+        curve.line(pt(10.0, 0.0));
+        curve.line(pt(10.0, 10.0));
+        curve.shorten(Abs::pt(2.0), Abs::pt(3.0));
+        assert_eq!(
+            curve.0,
+            [
+                CurveItem::Move(pt(2.0, 0.0)),
+                CurveItem::Line(pt(10.0, 0.0)),
+                CurveItem::Line(pt(10.0, 7.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_start_direction_skips_degenerate_controls() {
+        let mut curve = Curve::new();
+        curve.move_(pt(5.0, 5.0));
+        curve.cubic(pt(5.0, 5.0), pt(5.0, 10.0), pt(10.0, 10.0));
+        assert_eq!(curve.start_direction(), Some((pt(5.0, 5.0), pt(0.0, 5.0))));
+    }
+
+    #[test]
+    fn test_transform_into_start_direction() {
+        let mut curve = Curve::new();
+        curve.move_(pt(10.0, 10.0));
+        curve.line(pt(10.0, 20.0));
+
+        let (start, _) = curve.start_direction().unwrap();
+        let ts = Transform::translate(start.x, start.y)
+            .pre_concat(Transform::rotate(Angle::deg(90.0)));
+        curve.transform(ts.invert().unwrap());
+
+        let [CurveItem::Move(start), CurveItem::Line(end)] = curve.0.as_slice() else {
+            panic!("unexpected curve {curve:?}");
+        };
+        assert_approx_eq(*start, pt(0.0, 0.0));
+        assert_approx_eq(*end, pt(10.0, 0.0));
+    }
+}
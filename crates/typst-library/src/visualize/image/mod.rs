@@ -23,14 +23,15 @@ use typst_utils::{LazyHash, NonZeroExt};
 use crate::diag::{At, LoadedWithin, SourceResult, StrResult, bail, warning};
 use crate::engine::Engine;
 use crate::foundations::{
-    Bytes, Cast, Content, Derived, NativeElement, Packed, Smart, StyleChain, Synthesize,
-    cast, elem, func, scope,
+    Bytes, Cast, Content, Derived, Dict, NativeElement, Packed, Smart, StyleChain,
+    Synthesize, cast, dict, elem, func, scope,
 };
 use crate::introspection::{Locatable, Tagged};
 use crate::layout::{Length, Rel, Sizing};
 use crate::loading::{DataSource, Load, LoadSource, Loaded, Readable};
 use crate::model::Figurable;
 use crate::text::{LocalName, Locale, families};
+use crate::visualize::Color;
 use crate::visualize::image::pdf::PdfDocument;
 
 /// ラスターまたはベクター画像。
@@ -71,10 +72,12 @@ pub struct ImageElem {
     #[required]
     #[parse(
         let source = args.expect::<Spanned<DataSource>>("source")?;
-        let loaded = source.load(engine.world)?;
+        // A failed load is only reported when decoding, such that a fallback
+        // can take the image's place.
+        let loaded = source.load(engine.world);
         Derived::new(source.v, loaded)
     )]
-    pub source: Derived<DataSource, Loaded>,
+    pub source: Derived<DataSource, SourceResult<Loaded>>,
 
     /// 画像のフォーマット。
     ///
@@ -155,6 +158,12 @@ pub struct ImageElem {
     #[default(NonZeroUsize::ONE)]
     pub page: NonZeroUsize,
 
+    /// 画像として埋め込むフレーム番号。アニメーションGIFおよびWebPファイルでのみ有効です。
+    ///
+    /// `{auto}`に設定した場合、最初のフレームが埋め込まれます。
+    /// 指定されたフレームは静止画として埋め込まれます。
+    pub frame: Smart<NonZeroUsize>,
+
     /// 与えられた領域に対して、画像をどのように調整するか。
     /// 領域は `width` や `height` フィールドで定義します。
     /// 領域の縦横比が画像の縦横比と同じであれば、`fit` で見た目が変わらないことに注意してください。
@@ -191,6 +200,59 @@ pub struct ImageElem {
     })]
     pub icc: Smart<Derived<DataSource, Bytes>>,
 
+    /// 画像の色をICCプロファイルから出力先の色空間へ変換する際に用いるレンダリングインテント。
+    ///
+    /// `{auto}`に設定した場合、色変換の方法は出力先に委ねられます。
+    /// PNGエクスポートでは、インテントが指定されていて画像がICCプロファイルを持つ場合に限り、
+    /// そのインテントで画像の色をsRGBへ変換します。
+    ///
+    /// _注意:_ PDFおよびSVGエクスポートでは画像がICCプロファイルとともにそのまま埋め込まれ、
+    /// 現在インテントは出力に反映されません。
+    /// 色変換はビューアーのデフォルトの動作に委ねられます。
+    pub intent: Smart<RenderingIntent>,
+
+    /// 画像の透明部分を合成する背景色。
+    ///
+    /// 指定した場合、ラスター画像のアルファチャンネルをこの色と合成し、
+    /// 透明度を持たない画像として埋め込みます。
+    /// PDFで透明度を避けたい場合や、サムネイルを生成する場合に便利です。
+    /// 背景色自体の透明度は無視されます。
+    /// SVGおよびPDFの画像には影響しません。
+    pub background: Option<Color>,
+
+    /// SVG画像内の`<image>`要素が参照する外部の画像ファイルを読み込むかどうか。
+    ///
+    /// `{true}`（デフォルト）の場合、相対パスによる参照はSVGファイルのあるディレクトリを基準に解決され、
+    /// Typstの他のファイル読み込みと同様に、プロジェクトのルートディレクトリ内のファイルのみ読み込めます。
+    /// URLや絶対パスによる参照は常に許可されず、エラーになります。
+    ///
+    /// `{false}`の場合、外部のファイルは一切読み込まれず、それらを参照する画像は表示されません。
+    /// 信頼できないSVGファイルを埋め込む場合に便利です。
+    /// データURLとしてSVG内に埋め込まれた画像は、この設定に関わらず表示されます。
+    ///
+    /// なお、`<use>`要素による参照は同じSVG文書内の要素に対してのみ解決され、
+    /// 外部ファイルへの参照は常に無視されます。
+    ///
+    /// ```typ
+    /// #image("diagram.svg", resolve-hrefs: false)
+    /// ```
+    #[default(true)]
+    pub resolve_hrefs: bool,
+
+    /// 画像を読み込めない、またはデコードできない場合に代わりに表示するコンテンツ。
+    ///
+    /// `{none}`（デフォルト）の場合、従来通りエラーになります。
+    /// コンテンツを指定すると、エラーの代わりにそのコンテンツ（別の画像やテキストなど）が表示されます。
+    /// 多数の画像を含む文書の下書きを作成する場合に便利です。
+    ///
+    /// ファイルが見つからないなど画像ファイルの読み込み自体に失敗した場合にも、
+    /// setルールで指定したフォールバックを含めて同様に使われます。
+    ///
+    /// ```typ
+    /// #image("figures/draft.png", fallback: [_画像なし_])
+    /// ```
+    pub fallback: Option<Content>,
+
     /// この要素のロケール（代替説明に使用）。
     #[internal]
     #[synthesized]
@@ -239,7 +301,7 @@ impl ImageElem {
         let bytes = data.v.into_bytes();
         let loaded =
             Loaded::new(Spanned::new(LoadSource::Bytes, data.span), bytes.clone());
-        let source = Derived::new(DataSource::Bytes(bytes), loaded);
+        let source = Derived::new(DataSource::Bytes(bytes), Ok(loaded));
         let mut elem = ImageElem::new(source);
         if let Some(format) = format {
             elem.format.set(format);
@@ -261,25 +323,115 @@ impl ImageElem {
         }
         Ok(elem.pack().spanned(span))
     }
+
+    /// 画像をデコードし、その情報を辞書として返します。
+    ///
+    /// 画像を配置する前に、自然なサイズやアスペクト比を知りたい場合に便利です。
+    /// 返される辞書は次のキーを持ちます。
+    /// - `width` ([float]): 画像の幅のピクセル数。
+    /// - `height` ([float]): 画像の高さのピクセル数。
+    /// - `dpi` ([float]または[`{none}`]($none)): 1インチあたりのピクセル数で表した画像の解像度。
+    ///   生のピクセルデータなど、解像度が不明な場合は`{none}`になります。
+    /// - `format` ([str]または[dictionary]): 画像の[フォーマット]($image.format)。
+    /// - `pages` ([int]または[`{none}`]($none)): PDF画像のページ数。
+    ///   PDF以外の画像では`{none}`になります。
+    ///
+    /// PDF画像の場合、`width`と`height`は最初のページの大きさを表します。
+    /// 画像をデコードできない場合はエラーになります。
+    ///
+    /// ```example
+    /// #let info = image.info("molecular.jpg")
+    /// #info \
+    /// Aspect ratio: #calc.round(info.width / info.height, digits: 2)
+    /// ```
+    #[func]
+    pub fn info(
+        engine: &mut Engine,
+        span: Span,
+        /// 画像ファイルへの[path]($syntax/#paths)、または画像データの生バイト。
+        source: Spanned<DataSource>,
+        /// 画像のフォーマット。デフォルトでは自動的に検出されます。
+        #[named]
+        #[default]
+        format: Smart<ImageFormat>,
+    ) -> SourceResult<Dict> {
+        let loaded = source.load(engine.world)?;
+        let mut elem = ImageElem::new(Derived::new(source.v, Ok(loaded)));
+        elem.format.set(format);
+        let image = Packed::new(elem)
+            .spanned(span)
+            .decode(engine, StyleChain::default())?;
+        let pages = match image.kind() {
+            ImageKind::Pdf(pdf) => Some(pdf.document().num_pages() as i64),
+            _ => None,
+        };
+        Ok(dict! {
+            "width" => image.width(),
+            "height" => image.height(),
+            "dpi" => image.dpi(),
+            "format" => image.format(),
+            "pages" => pages,
+        })
+    }
 }
 
 impl Packed<ImageElem> {
+    /// Decodes the image or, if it fails to load or decode, returns its
+    /// fallback content instead, if any.
+    pub fn decode_or_fallback(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+    ) -> SourceResult<Result<Image, Content>> {
+        match self.decode(engine, styles) {
+            Ok(image) => Ok(Ok(image)),
+            Err(err) => self.fallback.get_cloned(styles).map(Err).ok_or(err),
+        }
+    }
+
     /// Decodes the image.
     pub fn decode(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Image> {
         let span = self.span();
-        let loaded = &self.source.derived;
-        let format = self.determine_format(styles).at(span)?;
+        let loaded = self.source.derived.as_ref().map_err(Clone::clone)?;
+        let format = self.determine_format(loaded, styles).at(span)?;
 
         // Construct the image itself.
         let kind = match format {
-            ImageFormat::Raster(format) => ImageKind::Raster(
-                RasterImage::new(
+            ImageFormat::Raster(format) => {
+                let raster = RasterImage::new(
                     loaded.data.clone(),
                     format,
                     self.icc.get_ref(styles).as_ref().map(|icc| icc.derived.clone()),
                 )
-                .at(span)?,
-            ),
+                .at(span)?;
+
+                // The user provides the frame number start from 1, but the
+                // raster image expects a 0-based index.
+                let raster = match self.frame.get(styles) {
+                    Smart::Auto => raster,
+                    Smart::Custom(frame_num) => {
+                        let frame_num = frame_num.get();
+                        let Some(raster) = raster.with_frame(frame_num - 1).at(span)?
+                        else {
+                            let num_frames = raster.frame_count();
+                            let s = if num_frames == 1 { "" } else { "s" };
+                            bail!(
+                                span,
+                                "frame {frame_num} does not exist";
+                                hint: "the image only has {num_frames} frame{s}"
+                            );
+                        };
+                        raster
+                    }
+                };
+
+                let raster = match self.background.get(styles) {
+                    Some(color) => raster.with_background(color),
+                    None => raster,
+                };
+
+                ImageKind::Raster(raster)
+            }
             ImageFormat::Vector(VectorFormat::Svg) => {
                 // Warn the user if the image contains a foreign object. Not
                 // perfect because the svg could also be encoded, but that's an
@@ -304,6 +456,7 @@ impl Packed<ImageElem> {
                         engine.world,
                         &families(styles).map(|f| f.as_str()).collect::<Vec<_>>(),
                         svg_file,
+                        self.resolve_hrefs.get(styles),
                     )
                     .within(loaded)?,
                 )
@@ -360,18 +513,26 @@ impl Packed<ImageElem> {
             }
         };
 
-        Ok(Image::new(kind, self.alt.get_cloned(styles), self.scaling.get(styles)))
+        Ok(Image::new(
+            kind,
+            self.alt.get_cloned(styles),
+            self.scaling.get(styles),
+            self.intent.get(styles),
+        ))
     }
 
     /// Tries to determine the image format based on the format that was
     /// explicitly defined, or else the extension, or else the data.
-    fn determine_format(&self, styles: StyleChain) -> StrResult<ImageFormat> {
+    fn determine_format(
+        &self,
+        loaded: &Loaded,
+        styles: StyleChain,
+    ) -> StrResult<ImageFormat> {
         if let Smart::Custom(v) = self.format.get(styles) {
             return Ok(v);
         };
 
-        let Derived { source, derived: loaded } = &self.source;
-        if let DataSource::Path(path) = source
+        if let DataSource::Path(path) = &self.source.source
             && let Some(format) = determine_format_from_path(path.as_str())
         {
             return Ok(format);
@@ -438,6 +599,8 @@ struct Repr {
     alt: Option<EcoString>,
     /// The scaling algorithm to use.
     scaling: Smart<ImageScaling>,
+    /// The rendering intent to use for color management.
+    intent: Smart<RenderingIntent>,
 }
 
 impl Image {
@@ -453,13 +616,14 @@ impl Image {
         kind: impl Into<ImageKind>,
         alt: Option<EcoString>,
         scaling: Smart<ImageScaling>,
+        intent: Smart<RenderingIntent>,
     ) -> Self {
-        Self::new_impl(kind.into(), alt, scaling)
+        Self::new_impl(kind.into(), alt, scaling, intent)
     }
 
     /// Create an image with optional properties set to the default.
     pub fn plain(kind: impl Into<ImageKind>) -> Self {
-        Self::new(kind, None, Smart::Auto)
+        Self::new(kind, None, Smart::Auto, Smart::Auto)
     }
 
     /// The internal, non-generic implementation. This is memoized to reuse
@@ -469,8 +633,9 @@ impl Image {
         kind: ImageKind,
         alt: Option<EcoString>,
         scaling: Smart<ImageScaling>,
+        intent: Smart<RenderingIntent>,
     ) -> Image {
-        Self(Arc::new(LazyHash::new(Repr { kind, alt, scaling })))
+        Self(Arc::new(LazyHash::new(Repr { kind, alt, scaling, intent })))
     }
 
     /// The format of the image.
@@ -519,10 +684,33 @@ impl Image {
         self.0.scaling
     }
 
+    /// The rendering intent to use for color management of this image.
+    pub fn intent(&self) -> Smart<RenderingIntent> {
+        self.0.intent
+    }
+
     /// The decoded image.
     pub fn kind(&self) -> &ImageKind {
         &self.0.kind
     }
+
+    /// A key that identifies the image's contents and properties.
+    ///
+    /// Images with the same key are interchangeable, so exporters can use it
+    /// to embed an image that is used many times only once. The key is cached,
+    /// so this is cheap to call repeatedly.
+    pub fn key(&self) -> u128 {
+        typst_utils::hash128(&self.0)
+    }
+
+    /// Whether both images share the same representation.
+    ///
+    /// Since [`Image::new`] is memoized, this holds for images that were
+    /// created from equal data and properties. Their decoded data is then
+    /// shared instead of being decoded once per use.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 impl Debug for Image {
@@ -533,6 +721,7 @@ impl Debug for Image {
             .field("height", &self.height())
             .field("alt", &self.alt())
             .field("scaling", &self.scaling())
+            .field("intent", &self.intent())
             .finish()
     }
 }
@@ -653,3 +842,21 @@ pub enum ImageScaling {
     /// ピクセルで構成された画像の見た目を保ちます。
     Pixelated,
 }
+
+/// How colors should be mapped when converting an image from its ICC profile
+/// to the output color space.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum RenderingIntent {
+    /// 色域全体を滑らかに圧縮し、色同士の見た目の関係を保ちます。
+    /// 写真に適しています。
+    Perceptual,
+    /// 出力先の色域内の色を正確に再現し、白色点を出力先に合わせます。
+    /// 色域外の色は最も近い色に置き換えられます。
+    RelativeColorimetric,
+    /// 相対的な測色的インテントと同様ですが、白色点を合わせません。
+    /// 紙色のシミュレーションなどの校正刷りに適しています。
+    AbsoluteColorimetric,
+    /// 色の正確さよりも鮮やかさを優先します。
+    /// グラフや図表に適しています。
+    Saturation,
+}
@@ -5,13 +5,15 @@ use std::sync::Arc;
 
 use crate::diag::{StrResult, bail};
 use crate::foundations::{Bytes, Cast, Dict, Smart, Value, cast, dict};
+use crate::visualize::Color;
 use ecow::{EcoString, eco_format};
 use image::codecs::gif::GifDecoder;
 use image::codecs::jpeg::JpegDecoder;
 use image::codecs::png::PngDecoder;
 use image::codecs::webp::WebPDecoder;
 use image::{
-    DynamicImage, ImageBuffer, ImageDecoder, ImageResult, Limits, Pixel, guess_format,
+    AnimationDecoder, DynamicImage, ImageBuffer, ImageDecoder, ImageResult, Limits,
+    Pixel, guess_format,
 };
 
 /// A decoded raster image.
@@ -26,6 +28,7 @@ struct Repr {
     exif_rotation: Option<u32>,
     icc: Option<Bytes>,
     dpi: Option<f64>,
+    frame: usize,
 }
 
 impl RasterImage {
@@ -148,9 +151,55 @@ impl RasterImage {
             dynamic: Arc::new(dynamic),
             icc,
             dpi,
+            frame: 0,
         })))
     }
 
+    /// Create a version of this image that shows the frame with the given
+    /// zero-based index.
+    ///
+    /// Only animated GIF and WebP images have frames beyond the first one.
+    /// Returns `None` if the image has no frame with this index.
+    pub fn with_frame(&self, index: usize) -> StrResult<Option<Self>> {
+        if index == self.0.frame {
+            return Ok(Some(self.clone()));
+        }
+
+        let RasterFormat::Exchange(format) = self.0.format else {
+            return Ok(None);
+        };
+
+        let Some(dynamic) =
+            decode_frame(&self.0.data, format, index, self.0.exif_rotation)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self(Arc::new(Repr {
+            data: self.0.data.clone(),
+            format: self.0.format,
+            dynamic,
+            exif_rotation: self.0.exif_rotation,
+            icc: self.0.icc.clone(),
+            dpi: self.0.dpi,
+            frame: index,
+        }))))
+    }
+
+    /// Create a version of this image whose transparent parts are composited
+    /// onto the given background color.
+    ///
+    /// The result is an opaque image in raw RGB pixel format. The
+    /// background's own transparency is ignored. Images without an alpha
+    /// channel are returned as they are.
+    pub fn with_background(&self, background: Color) -> Self {
+        if !self.dynamic().color().has_alpha() {
+            return self.clone();
+        }
+
+        composite_background(self, background)
+    }
+
     /// The raw image data.
     pub fn data(&self) -> &Bytes {
         &self.0.data
@@ -195,14 +244,38 @@ impl RasterImage {
     pub fn icc(&self) -> Option<&Bytes> {
         self.0.icc.as_ref()
     }
+
+    /// The zero-based index of the frame this image shows.
+    ///
+    /// If this is not zero, the [`dynamic`](Self::dynamic) image differs from
+    /// the first frame contained in the raw [`data`](Self::data).
+    pub fn frame(&self) -> usize {
+        self.0.frame
+    }
+
+    /// The number of frames in the image. Only animated GIF and WebP images
+    /// can have more than one frame.
+    pub fn frame_count(&self) -> usize {
+        count_frames(&self.0.data, self.0.format)
+    }
+
+    /// Whether both images share the same decoded data.
+    ///
+    /// Decoding is memoized, so this holds for images that were created from
+    /// the same data and options, even if the data was loaded separately.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 impl Hash for Repr {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // The image is fully defined by data, format, and ICC profile.
+        // The image is fully defined by data, format, ICC profile, and
+        // selected frame.
         self.data.hash(state);
         self.format.hash(state);
         self.icc.hash(state);
+        self.frame.hash(state);
     }
 }
 
@@ -331,6 +404,103 @@ impl From<PixelFormat> for Dict {
     }
 }
 
+/// Decode the frame with the given index from an animated image.
+///
+/// Returns `None` if the image is not animated or has no such frame.
+#[comemo::memoize]
+fn decode_frame(
+    data: &Bytes,
+    format: ExchangeFormat,
+    index: usize,
+    rotation: Option<u32>,
+) -> StrResult<Option<Arc<DynamicImage>>> {
+    fn nth<'a>(
+        decoder: impl AnimationDecoder<'a>,
+        index: usize,
+    ) -> ImageResult<Option<DynamicImage>> {
+        let frame = decoder.into_frames().nth(index).transpose()?;
+        Ok(frame.map(|frame| DynamicImage::ImageRgba8(frame.into_buffer())))
+    }
+
+    let cursor = io::Cursor::new(data);
+    let dynamic = match format {
+        ExchangeFormat::Gif => GifDecoder::new(cursor).and_then(|d| nth(d, index)),
+        ExchangeFormat::Webp => WebPDecoder::new(cursor)
+            .and_then(|d| if d.has_animation() { nth(d, index) } else { Ok(None) }),
+        ExchangeFormat::Png | ExchangeFormat::Jpg => Ok(None),
+    }
+    .map_err(format_image_error)?;
+
+    Ok(dynamic.map(|mut dynamic| {
+        if let Some(rotation) = rotation {
+            apply_rotation(&mut dynamic, rotation);
+        }
+        Arc::new(dynamic)
+    }))
+}
+
+/// Composite the transparent parts of an image onto a background color.
+#[comemo::memoize]
+fn composite_background(image: &RasterImage, background: Color) -> RasterImage {
+    let [br, bg, bb, _] = Color::Rgb(background.to_rgb()).to_vec4_u8();
+    let blend = |c: u8, b: u8, a: u8| {
+        let (c, b, a) = (c as u32, b as u32, a as u32);
+        ((c * a + b * (255 - a) + 127) / 255) as u8
+    };
+
+    let rgba = image.dynamic().to_rgba8();
+    let mut data = Vec::with_capacity(rgba.len() / 4 * 3);
+    for &image::Rgba([r, g, b, a]) in rgba.pixels() {
+        data.push(blend(r, br, a));
+        data.push(blend(g, bg, a));
+        data.push(blend(b, bb, a));
+    }
+
+    let format = PixelFormat {
+        encoding: PixelEncoding::Rgb8,
+        width: rgba.width(),
+        height: rgba.height(),
+    };
+    let buffer = ImageBuffer::from_raw(format.width, format.height, data)
+        .expect("buffer has the right size");
+    let dynamic = Arc::new(DynamicImage::ImageRgb8(buffer));
+
+    RasterImage(Arc::new(Repr {
+        data: Bytes::new(Pixels(dynamic.clone())),
+        format: RasterFormat::Pixel(format),
+        dynamic,
+        exif_rotation: None,
+        icc: image.0.icc.clone(),
+        dpi: image.0.dpi,
+        frame: 0,
+    }))
+}
+
+/// The pixels of a decoded image as raw bytes, such that they can back
+/// [`Bytes`] without a copy.
+struct Pixels(Arc<DynamicImage>);
+
+impl AsRef<[u8]> for Pixels {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+/// Count the frames of an image.
+#[comemo::memoize]
+fn count_frames(data: &Bytes, format: RasterFormat) -> usize {
+    let cursor = io::Cursor::new(data);
+    let count = match format {
+        RasterFormat::Exchange(ExchangeFormat::Gif) => {
+            GifDecoder::new(cursor).map(|d| d.into_frames().count())
+        }
+        RasterFormat::Exchange(ExchangeFormat::Webp) => WebPDecoder::new(cursor)
+            .map(|d| if d.has_animation() { d.into_frames().count() } else { 1 }),
+        _ => Ok(1),
+    };
+    count.unwrap_or(1).max(1)
+}
+
 /// Try to get the rotation from the EXIF metadata.
 fn exif_rotation(exif: &exif::Exif) -> Option<u32> {
     exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)?
@@ -455,6 +625,7 @@ fn format_image_error(error: image::ImageError) -> EcoString {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::visualize::{Image, ImageKind};
 
     #[test]
     fn test_image_dpi() {
@@ -470,4 +641,112 @@ mod tests {
         test("images/tiger.jpg", ExchangeFormat::Jpg, 72.0);
         test("images/graph.png", ExchangeFormat::Png, 144.0);
     }
+
+    #[test]
+    fn test_image_frames() {
+        // A 1x1 GIF with a red and a blue frame.
+        let data = Bytes::new([
+            0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00, 0x00,
+            0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x21, 0xF9, 0x04, 0x00, 0x0A, 0x00, 0x00,
+            0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02,
+            0x44, 0x01, 0x00, 0x21, 0xF9, 0x04, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x2C, 0x00,
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02, 0x4C, 0x01, 0x00,
+            0x3B,
+        ]);
+
+        let image = RasterImage::plain(data, ExchangeFormat::Gif).unwrap();
+        assert_eq!(image.frame_count(), 2);
+        assert_eq!(image.frame(), 0);
+
+        let second = image.with_frame(1).unwrap().unwrap();
+        assert_eq!(second.frame(), 1);
+        assert_eq!(second.dynamic().to_rgba8().get_pixel(0, 0).0, [0, 0, 255, 255]);
+        assert!(image.with_frame(2).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_image_decoded_once() {
+        let data = typst_dev_assets::get("images/graph.png").unwrap();
+        let first = RasterImage::plain(Bytes::new(data), ExchangeFormat::Png).unwrap();
+        let second = RasterImage::plain(Bytes::new(data), ExchangeFormat::Png).unwrap();
+        assert!(first.ptr_eq(&second));
+
+        let image = Image::plain(first.clone());
+        let again = Image::plain(second);
+        assert!(image.ptr_eq(&again));
+        assert_eq!(image.key(), again.key());
+
+        // Different properties yield a different image, but the decoded data
+        // is still shared.
+        let described =
+            Image::new(first.clone(), Some("Graph".into()), Smart::Auto, Smart::Auto);
+        assert!(!image.ptr_eq(&described));
+        assert_ne!(image.key(), described.key());
+        let ImageKind::Raster(raster) = described.kind() else { unreachable!() };
+        assert!(raster.ptr_eq(&first));
+    }
+
+    #[test]
+    fn test_image_webp_alpha() {
+        use image::codecs::png::PngEncoder;
+        use image::codecs::webp::WebPEncoder;
+        use image::{ExtendedColorType, ImageEncoder};
+
+        // A 2x2 image with an opaque, a half-transparent, and two fully
+        // transparent pixels, one of which still has a color.
+        #[rustfmt::skip]
+        let pixels = [
+            255, 0, 0, 255,   0, 0, 255, 128,
+            0, 0, 0, 0,       0, 255, 0, 0,
+        ];
+
+        let mut webp = vec![];
+        WebPEncoder::new_lossless(&mut webp)
+            .write_image(&pixels, 2, 2, ExtendedColorType::Rgba8)
+            .unwrap();
+
+        let mut png = vec![];
+        PngEncoder::new(&mut png)
+            .write_image(&pixels, 2, 2, ExtendedColorType::Rgba8)
+            .unwrap();
+
+        let webp = RasterImage::plain(Bytes::new(webp), ExchangeFormat::Webp).unwrap();
+        let png = RasterImage::plain(Bytes::new(png), ExchangeFormat::Png).unwrap();
+        assert!(webp.dynamic().color().has_alpha());
+        assert_eq!(webp.dynamic().to_rgba8().into_raw(), pixels);
+        assert_eq!(webp.dynamic().to_rgba8(), png.dynamic().to_rgba8());
+    }
+
+    #[test]
+    fn test_image_with_background() {
+        #[rustfmt::skip]
+        let pixels = [
+            255, 0, 0, 255,   0, 0, 0, 128,
+            0, 0, 0, 0,       0, 255, 0, 0,
+        ];
+
+        let format = PixelFormat {
+            encoding: PixelEncoding::Rgba8,
+            width: 2,
+            height: 2,
+        };
+        let image = RasterImage::plain(Bytes::new(pixels), format).unwrap();
+        let flat = image.with_background(Color::WHITE);
+        assert!(!flat.dynamic().color().has_alpha());
+        assert_eq!(flat.width(), 2);
+        assert_eq!(flat.height(), 2);
+        assert_eq!(
+            flat.dynamic().to_rgb8().into_raw(),
+            [255, 0, 0, 127, 127, 127, 255, 255, 255, 255, 255, 255],
+        );
+        assert_eq!(flat.data().as_slice(), flat.dynamic().as_bytes());
+        assert!(Arc::ptr_eq(&image.with_background(Color::WHITE).0, &flat.0));
+
+        let opaque = RasterImage::plain(
+            Bytes::new([1, 2, 3]),
+            PixelFormat { encoding: PixelEncoding::Rgb8, width: 1, height: 1 },
+        )
+        .unwrap();
+        assert!(Arc::ptr_eq(&opaque.with_background(Color::BLACK).0, &opaque.0));
+    }
 }
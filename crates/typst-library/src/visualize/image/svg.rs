@@ -42,6 +42,9 @@ impl SvgImage {
     }
 
     /// Decode an SVG image with access to fonts and linked images.
+    ///
+    /// If `resolve_hrefs` is false, linked images are not loaded and left out
+    /// of the image. Images embedded as data URLs are always loaded.
     #[comemo::memoize]
     #[typst_macros::time(name = "load svg")]
     pub fn with_fonts_images(
@@ -49,6 +52,7 @@ impl SvgImage {
         world: Tracked<dyn World + '_>,
         families: &[&str],
         svg_file: Option<FileId>,
+        resolve_hrefs: bool,
     ) -> LoadResult<SvgImage> {
         let book = world.book();
         let font_resolver = Mutex::new(FontResolver::new(world, book, families));
@@ -71,6 +75,9 @@ impl SvgImage {
                 image_href_resolver: usvg::ImageHrefResolver {
                     resolve_data: usvg::ImageHrefResolver::default_data_resolver(),
                     resolve_string: Box::new(|href, _opts| {
+                        if !resolve_hrefs {
+                            return None;
+                        }
                         image_resolver.lock().unwrap().load(href)
                     }),
                 },
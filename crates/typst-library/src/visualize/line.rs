@@ -1,4 +1,4 @@
-use crate::foundations::elem;
+use crate::foundations::{Cast, elem};
 use crate::layout::{Abs, Angle, Axes, Length, Rel};
 use crate::visualize::Stroke;
 
@@ -47,4 +47,37 @@ pub struct LineElem {
     /// ```
     #[fold]
     pub stroke: Stroke,
+
+    /// The marker to draw at the start of the line.
+    ///
+    /// Markers are filled with the stroke's paint and scale with its
+    /// thickness. They are drawn independently of the stroke's `cap`, `join`,
+    /// and `dash`.
+    ///
+    /// ```example
+    /// #set line(length: 100%)
+    /// #stack(
+    ///   spacing: 1em,
+    ///   line(end-cap: "arrow"),
+    ///   line(start-cap: "circle", end-cap: "arrow", stroke: 2pt),
+    ///   line(start-cap: "bar", end-cap: "bar", stroke: blue),
+    /// )
+    /// ```
+    pub start_cap: Option<LineMarker>,
+
+    /// The marker to draw at the end of the line.
+    ///
+    /// See [`start-cap`]($line.start-cap) for more details.
+    pub end_cap: Option<LineMarker>,
+}
+
+/// A marker at the start or end of a line or curve.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum LineMarker {
+    /// A filled arrowhead whose tip is at the end of the line.
+    Arrow,
+    /// A filled circle centered at the end of the line.
+    Circle,
+    /// A bar perpendicular to the line through its end.
+    Bar,
 }
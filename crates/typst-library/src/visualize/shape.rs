@@ -1,6 +1,16 @@
+use std::borrow::Cow;
+
+use typst_utils::Numeric;
+
 use crate::foundations::{Cast, Content, Smart, elem};
-use crate::layout::{Abs, Corners, Length, Point, Rect, Rel, Sides, Size, Sizing};
-use crate::visualize::{Curve, FixedStroke, Paint, Stroke};
+use crate::layout::{
+    Abs, Angle, Axes, Corners, Length, Point, Ratio, Rect, Rel, Sides, Size, Sizing,
+    Transform,
+};
+use crate::visualize::{
+    Curve, DashPattern, FixedStroke, LineCap, LineJoin, Paint, PaintAnchor, RatioOrAngle,
+    Stroke,
+};
 
 /// A rectangle with optional content.
 ///
@@ -341,6 +351,356 @@ pub struct Shape {
     pub stroke: Option<FixedStroke>,
 }
 
+impl Shape {
+    /// Splits a shape whose dashed stroke has a gap paint into two shapes,
+    /// which are to be drawn on top of each other.
+    ///
+    /// The first one has the fill and a solid stroke in the gap paint, the
+    /// second one only has the dashed stroke. Returns `None` if the shape's
+    /// gaps are transparent.
+    pub fn split_gaps(&self) -> Option<(Shape, Shape)> {
+        let stroke = self.stroke.as_ref()?;
+        let gaps = Shape { stroke: Some(stroke.gap_stroke()?), ..self.clone() };
+        let dashes = Shape {
+            fill: None,
+            stroke: Some(FixedStroke { gap_paint: None, ..stroke.clone() }),
+            ..self.clone()
+        };
+        Some((gaps, dashes))
+    }
+
+    /// Splits a shape with a double stroke into its fill and the two lines of
+    /// the stroke.
+    ///
+    /// Each line comes with a transform that moves it into place relative to
+    /// the original shape. The lines are single strokes with the original
+    /// thickness, offset by half the thickness plus half the gap to either
+    /// side of the stroked path. Returns `None` if the stroke is not double or
+    /// if the geometry does not support it.
+    pub fn split_double(&self) -> Option<(Option<Shape>, [(Transform, Shape); 2])> {
+        let stroke = self.stroke.as_ref()?;
+        let gap = stroke.double?;
+        let offset = (stroke.thickness + gap) / 2.0;
+        let single = |geometry| Shape {
+            geometry,
+            fill: None,
+            stroke: Some(FixedStroke { double: None, ..stroke.clone() }),
+            ..self.clone()
+        };
+
+        let lines = match &self.geometry {
+            Geometry::Line(target) => {
+                let length = target.hypot();
+                if length.is_zero() {
+                    return None;
+                }
+                let normal = Point::new(-target.y, target.x) * (offset / length);
+                [
+                    (
+                        Transform::translate(normal.x, normal.y),
+                        single(self.geometry.clone()),
+                    ),
+                    (
+                        Transform::translate(-normal.x, -normal.y),
+                        single(self.geometry.clone()),
+                    ),
+                ]
+            }
+            Geometry::Rect(size) => {
+                let sign = Axes::new(size.x.signum(), size.y.signum());
+                let outer = size.map(|v| v.abs() + 2.0 * offset);
+                let inner = size.map(|v| (v.abs() - 2.0 * offset).max(Abs::zero()));
+                [
+                    (
+                        Transform::translate(-offset * sign.x, -offset * sign.y),
+                        single(Geometry::Rect(outer.zip_map(sign, |v, s| v * s))),
+                    ),
+                    (
+                        Transform::translate(offset * sign.x, offset * sign.y),
+                        single(Geometry::Rect(inner.zip_map(sign, |v, s| v * s))),
+                    ),
+                ]
+            }
+            Geometry::Curve(_) => return None,
+        };
+
+        let fill = self.fill.is_some().then(|| Shape { stroke: None, ..self.clone() });
+        Some((fill, lines))
+    }
+
+    /// Splits a shape whose stroke paint is anchored along the stroked path
+    /// into its fill and its stroke.
+    ///
+    /// Returns the fill (if any), a transform that moves the origin to the
+    /// start of the path and rotates the x-axis into the path's initial
+    /// direction, and the stroke, whose geometry is expressed in the
+    /// transformed coordinate system. The stroke is to be drawn with the
+    /// transform applied so that its paint follows the path. Returns `None` if
+    /// the paint is not anchored along the path or if no transform is needed.
+    pub fn split_anchor(&self) -> Option<(Option<Shape>, Transform, Shape)> {
+        let stroke = self.stroke.as_ref()?;
+        if stroke.paint_anchor != Some(PaintAnchor::Along)
+            || matches!(stroke.paint, Paint::Solid(_))
+        {
+            return None;
+        }
+
+        let curve = match &self.geometry {
+            Geometry::Line(target) => {
+                let ts = anchor_transform(Point::zero(), *target)?;
+                let geometry = Geometry::Line(Point::with_x(target.hypot()));
+                return Some(self.split_with(ts, geometry));
+            }
+            Geometry::Rect(size) => Cow::Owned(Curve::rect(*size)),
+            Geometry::Curve(curve) => Cow::Borrowed(curve),
+        };
+
+        let (start, direction) = curve.start_direction()?;
+        let ts = anchor_transform(start, direction)?;
+        let mut curve = curve.into_owned();
+        curve.transform(ts.invert()?);
+        Some(self.split_with(ts, Geometry::Curve(curve)))
+    }
+
+    /// Splits a shape whose gradient stroke is applied along the stroked path
+    /// into its fill and a sequence of short stroke pieces.
+    ///
+    /// The path is flattened into a polyline and divided into stretches of
+    /// equal length, at most [`ALONG_PATH_MAX_PIECES`] of them. The vertices
+    /// within each stretch get a piece reaching from the middle of the
+    /// segment preceding the first vertex to the middle of the one following
+    /// the last vertex. Consecutive pieces thus meet on straight segments,
+    /// while the joins are drawn as usual. Each piece is stroked with the
+    /// solid color that the gradient has at the piece's relative position
+    /// along the path. Returns `None` if the stroke's paint is not a gradient
+    /// that is to be applied along the path.
+    pub fn split_along_path(&self) -> Option<(Option<Shape>, Vec<Shape>)> {
+        let stroke = self.stroke.as_ref()?;
+        let Paint::Gradient(gradient) = &stroke.paint else { return None };
+        if !stroke.gradient_along_path {
+            return None;
+        }
+
+        let curve = match &*self.drawn_geometry() {
+            Geometry::Line(target) => {
+                let mut curve = Curve::new();
+                curve.move_(Point::zero());
+                curve.line(*target);
+                curve
+            }
+            Geometry::Rect(size) => Curve::rect(*size),
+            Geometry::Curve(curve) => curve.clone(),
+        };
+
+        let polylines: Vec<_> = curve
+            .flatten(FLATTEN_TOLERANCE)
+            .into_iter()
+            .map(|(points, closed)| (subdivide(&points, Abs::zero()), closed))
+            .collect();
+        let total: Abs =
+            polylines.iter().map(|(points, _)| polyline_length(points)).sum();
+        if total.is_zero() {
+            return None;
+        }
+
+        let count = (total / ALONG_PATH_PIECE_LENGTH)
+            .ceil()
+            .clamp(1.0, ALONG_PATH_MAX_PIECES as f64);
+        let max = total / count;
+        let dash = stroke.dash.as_ref().map(|dash| dash.along(total));
+        let mut pieces = vec![];
+        let mut offset = Abs::zero();
+        for (points, closed) in polylines {
+            let points = subdivide(&points, max);
+            let n = points.len();
+            if n < 2 {
+                continue;
+            }
+
+            // The distance along the path of each vertex.
+            let mut arcs = Vec::with_capacity(n);
+            arcs.push(offset);
+            for pair in points.windows(2) {
+                offset += (pair[1] - pair[0]).hypot();
+                arcs.push(offset);
+            }
+
+            let mid = |i: usize| (points[i] + points[i + 1]) / 2.0;
+            let last_half = (arcs[n - 1] - arcs[n - 2]) / 2.0;
+            // The stretch that a vertex belongs to. Rounding keeps vertices
+            // that subdivision placed at multiples of `max` apart from each
+            // other despite floating-point errors.
+            let stretch =
+                |i: usize| ((arcs[i] / max).round() as usize).min(count as usize - 1);
+
+            // The last vertex of a closed polyline coincides with its first
+            // one, which is joined to the last segment instead.
+            let vertices = if closed { n - 1 } else { n };
+            let mut first = 0;
+            while first < vertices {
+                let mut last = first;
+                while last + 1 < vertices && stretch(last + 1) == stretch(first) {
+                    last += 1;
+                }
+
+                let mut piece = Curve::new();
+                let start = if first > 0 {
+                    piece.move_(mid(first - 1));
+                    piece.line(points[first]);
+                    (arcs[first - 1] + arcs[first]) / 2.0
+                } else if closed {
+                    piece.move_(mid(n - 2));
+                    piece.line(points[0]);
+                    arcs[n - 1] - last_half
+                } else {
+                    piece.move_(points[0]);
+                    arcs[0]
+                };
+                for &point in &points[first + 1..=last] {
+                    piece.line(point);
+                }
+                if last + 1 < n {
+                    piece.line(mid(last));
+                }
+
+                // Only the ends of open paths keep the stroke's cap. Since a
+                // piece has the same cap at both ends, the inner ends of the
+                // first and last piece are covered by their neighbours.
+                let cap = if closed || (first > 0 && last + 1 < n) {
+                    LineCap::Butt
+                } else {
+                    stroke.cap
+                };
+                let t = (arcs[first] + arcs[last]) / 2.0 / total;
+                let color =
+                    gradient.sample(RatioOrAngle::Ratio(Ratio::new(t.clamp(0.0, 1.0))));
+                let dash = dash.as_ref().map(|dash| DashPattern {
+                    array: dash.array.iter().map(|&l| Rel::from(l)).collect(),
+                    phase: Rel::from(dash.phase + start),
+                });
+
+                pieces.push(Shape {
+                    geometry: Geometry::Curve(piece),
+                    fill: None,
+                    fill_rule: self.fill_rule,
+                    stroke: Some(FixedStroke {
+                        paint: color.into(),
+                        paint_anchor: None,
+                        gradient_along_path: false,
+                        cap,
+                        join_radius: None,
+                        dash,
+                        double: None,
+                        ..stroke.clone()
+                    }),
+                });
+
+                first = last + 1;
+            }
+        }
+
+        let fill = self.fill.is_some().then(|| Shape { stroke: None, ..self.clone() });
+        Some((fill, pieces))
+    }
+
+    /// Splits the shape into its fill and its stroke, with the stroke's
+    /// geometry replaced.
+    fn split_with(
+        &self,
+        ts: Transform,
+        geometry: Geometry,
+    ) -> (Option<Shape>, Transform, Shape) {
+        let fill = self.fill.is_some().then(|| Shape { stroke: None, ..self.clone() });
+        let stroke = Shape { geometry, fill: None, ..self.clone() };
+        (fill, ts, stroke)
+    }
+
+    /// The geometry as it should be drawn.
+    ///
+    /// If the shape is stroked with round joins and a join radius larger than
+    /// half the stroke's thickness, its corners are rounded off accordingly.
+    pub fn drawn_geometry(&self) -> Cow<'_, Geometry> {
+        let Some(FixedStroke {
+            join: LineJoin::Round,
+            join_radius: Some(radius),
+            thickness,
+            ..
+        }) = &self.stroke
+        else {
+            return Cow::Borrowed(&self.geometry);
+        };
+
+        // The round join of the stroke itself already accounts for half the
+        // thickness.
+        let radius = *radius - *thickness / 2.0;
+        if radius <= Abs::zero() {
+            return Cow::Borrowed(&self.geometry);
+        }
+
+        match &self.geometry {
+            Geometry::Line(_) => Cow::Borrowed(&self.geometry),
+            Geometry::Rect(size) => {
+                Cow::Owned(Geometry::Curve(Curve::rect(*size).round_corners(radius)))
+            }
+            Geometry::Curve(curve) => {
+                Cow::Owned(Geometry::Curve(curve.round_corners(radius)))
+            }
+        }
+    }
+}
+
+/// The tolerance with which curves are flattened when applying a gradient
+/// along them.
+const FLATTEN_TOLERANCE: Abs = Abs::raw(0.1);
+
+/// The length of the stretches of a path that are stroked in a single color
+/// when applying a gradient along it, unless the path is so long that there
+/// would be more than [`ALONG_PATH_MAX_PIECES`] of them.
+const ALONG_PATH_PIECE_LENGTH: Abs = Abs::raw(1.0);
+
+/// The maximum number of solid pieces into which a path is split when
+/// applying a gradient along it, not counting extra pieces for the ends of
+/// subpaths.
+const ALONG_PATH_MAX_PIECES: usize = 256;
+
+/// The total length of a polyline.
+fn polyline_length(points: &[Point]) -> Abs {
+    points.windows(2).map(|pair| (pair[1] - pair[0]).hypot()).sum()
+}
+
+/// Subdivides the segments of a polyline so that none of them is longer than
+/// `max`. Drops zero-length segments along the way.
+fn subdivide(points: &[Point], max: Abs) -> Vec<Point> {
+    let mut subdivided: Vec<Point> = Vec::with_capacity(points.len());
+    for &point in points {
+        let Some(&prev) = subdivided.last() else {
+            subdivided.push(point);
+            continue;
+        };
+        let length = (point - prev).hypot();
+        if length.is_zero() {
+            continue;
+        }
+        let steps =
+            if max.is_zero() { 1 } else { (length / max).ceil().max(1.0) as usize };
+        for k in 1..=steps {
+            subdivided.push(prev + (point - prev) * (k as f64 / steps as f64));
+        }
+    }
+    subdivided
+}
+
+/// The transform that moves the origin to `start` and rotates the x-axis into
+/// `direction`. Returns `None` if that is the identity.
+fn anchor_transform(start: Point, direction: Point) -> Option<Transform> {
+    if direction.is_zero() {
+        return None;
+    }
+    let angle = Angle::rad(direction.y.to_raw().atan2(direction.x.to_raw()));
+    let ts = Transform::translate(start.x, start.y).pre_concat(Transform::rotate(angle));
+    (!ts.is_identity()).then_some(ts)
+}
+
 /// A fill rule for curve drawing.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum FillRule {
@@ -383,6 +743,16 @@ impl Geometry {
         }
     }
 
+    /// The length of the geometry's outline, that is the length of the path
+    /// traced by a stroke.
+    pub fn length(&self) -> Abs {
+        match self {
+            Self::Line(end) => end.hypot(),
+            Self::Rect(size) => 2.0 * (size.x.abs() + size.y.abs()),
+            Self::Curve(curve) => curve.length(),
+        }
+    }
+
     /// The bounding box of the geometry.
     pub fn bbox(&self) -> Rect {
         match self {
@@ -3,17 +3,18 @@ use typst_utils::{Numeric, Scalar};
 
 use crate::diag::{HintedStrResult, SourceResult};
 use crate::foundations::{
-    Args, Cast, Dict, Fold, FromValue, NoneValue, Repr, Resolve, Smart, StyleChain,
-    Value, cast, dict, func, scope, ty,
+    Args, Cast, Dict, Fold, FromValue, IntoValue, NoneValue, Repr, Resolve, Smart,
+    StyleChain, Value, cast, dict, func, scope, ty,
 };
-use crate::layout::{Abs, Length};
-use crate::visualize::{Color, Gradient, Paint, Tiling};
+use crate::layout::{Abs, Length, Ratio, Rel};
+use crate::visualize::{Color, Gradient, Paint, RelativeTo, Tiling};
 
 /// Defines how to draw a line.
 ///
 /// A stroke has a _paint_ (a solid color or gradient), a _thickness,_ a line
-/// _cap,_ a line _join,_ a _miter limit,_ and a _dash_ pattern. All of these
-/// values are optional and have sensible defaults.
+/// _cap,_ a line _join_ with an optional _join radius,_ a _miter limit,_ a
+/// _dash_ pattern with an optional _gap paint,_ and can be drawn as a _double_
+/// line. All of these values are optional and have sensible defaults.
 ///
 /// # Example
 /// ```example
@@ -53,16 +54,33 @@ use crate::visualize::{Color, Gradient, Paint, Tiling};
 pub struct Stroke<T: Numeric = Length> {
     /// The stroke's paint.
     pub paint: Smart<Paint>,
+    /// How a gradient or tiling paint is placed along the stroke.
+    pub paint_anchor: Smart<PaintAnchor>,
+    /// Whether a gradient paint follows the length of the stroked path.
+    pub gradient_along_path: Smart<bool>,
     /// The stroke's thickness.
     pub thickness: Smart<T>,
+    /// Whether the stroke is a hairline. Takes precedence over the
+    /// thickness if it is `true`.
+    pub hairline: Smart<bool>,
     /// The stroke's line cap.
     pub cap: Smart<LineCap>,
     /// The stroke's line join.
     pub join: Smart<LineJoin>,
+    /// The radius of round line joins.
+    pub join_radius: Smart<T>,
     /// The stroke's line dash pattern.
     pub dash: Smart<Option<DashPattern<T>>>,
+    /// The paint for the gaps of the dash pattern.
+    pub gap_paint: Smart<Paint>,
+    /// Whether the stroke is drawn as two parallel lines.
+    pub double: Smart<bool>,
+    /// The gap between the two lines of a double stroke.
+    pub double_gap: Smart<T>,
     /// The miter limit.
     pub miter_limit: Smart<Scalar>,
+    /// Whether the stroke is anti-aliased.
+    pub anti_alias: Smart<bool>,
 }
 
 impl Stroke {
@@ -105,11 +123,94 @@ impl Stroke {
         #[external]
         paint: Smart<Paint>,
 
+        /// How a gradient or tiling used as the stroke's paint is placed.
+        ///
+        /// - `{"along"}` places the paint relative to the stroked path: Its
+        ///   origin is at the start of the path and its x-axis points in the
+        ///   path's initial direction. This way, a tiling repeats along the
+        ///   line, no matter in which direction the line is drawn.
+        /// - `{"fixed"}` places the paint in the coordinate system of the
+        ///   parent container, so that adjacent strokes continue the same
+        ///   pattern seamlessly.
+        ///
+        /// In both cases, the paint's own `relative` setting is ignored. If
+        /// set to `{auto}`, the value is inherited, defaulting to placing the
+        /// paint as specified by its `relative` setting. Has no effect on
+        /// solid colors.
+        ///
+        /// ```example
+        /// #let pat = tiling(size: (8pt, 8pt))[
+        ///   #place(rect(width: 4pt, height: 8pt, fill: blue))
+        /// ]
+        ///
+        /// #set line(length: 2cm)
+        /// #stack(
+        ///   dir: ltr,
+        ///   spacing: 1cm,
+        ///   line(angle: 30deg, stroke: (paint: pat, thickness: 6pt)),
+        ///   line(angle: 30deg, stroke: (
+        ///     paint: pat,
+        ///     thickness: 6pt,
+        ///     paint-anchor: "along",
+        ///   )),
+        /// )
+        /// ```
+        #[external]
+        paint_anchor: Smart<PaintAnchor>,
+
+        /// Whether a gradient used as the stroke's paint is applied along the
+        /// length of the stroked path instead of across its bounding box.
+        ///
+        /// If enabled, the start of the gradient is at the start of the path
+        /// and its end at the end of the path, following every bend in
+        /// between. This is useful to draw rainbow paths with [`curve`] or
+        /// polylines. Only the gradient's stops are used in this case, its
+        /// kind and angle are ignored. Has no effect on solid colors and
+        /// tilings.
+        ///
+        /// The path is approximated by short segments that are each stroked
+        /// with a solid color sampled from the gradient. Double strokes are
+        /// not supported with this option and fall back to a single line.
+        ///
+        /// If set to `{auto}`, the value is inherited, defaulting to `{false}`.
+        ///
+        /// ```example
+        /// #curve(
+        ///   stroke: (
+        ///     paint: gradient.linear(..color.map.rainbow),
+        ///     thickness: 4pt,
+        ///     join: "round",
+        ///     gradient-along-path: true,
+        ///   ),
+        ///   curve.move((0pt, 40pt)),
+        ///   curve.line((30pt, 0pt)),
+        ///   curve.line((60pt, 40pt)),
+        ///   curve.cubic((90pt, 0pt), (120pt, 80pt), (150pt, 20pt)),
+        /// )
+        /// ```
+        #[external]
+        gradient_along_path: Smart<bool>,
+
         /// The stroke's thickness.
         ///
+        /// Can also be set to `{"hairline"}` to draw the thinnest line the
+        /// output device can display, that is one physical pixel wide. Such a
+        /// line stays equally thin when zooming in. In PDF, this maps to a line
+        /// width of zero; in SVG, to a non-scaling stroke of one pixel. During
+        /// layout, a hairline takes up no space.
+        ///
         /// If set to `{auto}`, the value is inherited, defaulting to `{1pt}`.
+        ///
+        /// ```example
+        /// #table(
+        ///   columns: 2,
+        ///   stroke: (thickness: "hairline"),
+        ///   [A], [B],
+        ///   [C], [D],
+        /// )
+        /// ```
         #[external]
-        thickness: Smart<Length>,
+        thickness: Smart<Thickness>,
 
         /// How the ends of the stroke are rendered.
         ///
@@ -123,6 +224,35 @@ impl Stroke {
         #[external]
         join: Smart<LineJoin>,
 
+        /// The radius of the outer edge of round joins. Only applicable if
+        /// `join` is `{"round"}`.
+        ///
+        /// Larger radii round off the corners of the stroked path, while the
+        /// stroke's thickness stays the same. Radii smaller than half the
+        /// thickness have no effect.
+        ///
+        /// If set to `{auto}`, the value is inherited, defaulting to half the
+        /// thickness.
+        ///
+        /// ```example
+        /// #let items = (
+        ///   curve.move((0pt, 30pt)),
+        ///   curve.line((15pt, 0pt)),
+        ///   curve.line((30pt, 30pt)),
+        /// )
+        ///
+        /// #set curve(stroke: (thickness: 4pt, join: "round"))
+        /// #stack(
+        ///   dir: ltr,
+        ///   spacing: 1cm,
+        ///   curve(..items),
+        ///   curve(stroke: (join-radius: 6pt), ..items),
+        ///   curve(stroke: (join-radius: 12pt), ..items),
+        /// )
+        /// ```
+        #[external]
+        join_radius: Smart<Length>,
+
         /// The dash pattern to use. This can be:
         ///
         /// - One of the predefined patterns:
@@ -138,10 +268,19 @@ impl Stroke {
         ///   - `{"loosely-dash-dotted"}`
         /// - An [array] with alternating lengths for dashes and gaps. You can
         ///   also use the string `{"dot"}` for a length equal to the line
-        ///   thickness.
+        ///   thickness and [ratios]($ratio) for lengths relative to the length
+        ///   of the stroked path.
         /// - A [dictionary] with the keys `array` (same as the array above),
-        ///   and `phase` (of type [length]), which defines where in the pattern
-        ///   to start drawing.
+        ///   and `phase` (of type [relative length]($relative)), which defines
+        ///   where in the pattern to start drawing. A ratio in the phase is
+        ///   relative to the length of the stroked path.
+        ///
+        /// The lengths in a dash pattern can freely mix these units. They are
+        /// resolved in a fixed order: First, `{"dot"}` is replaced by the
+        /// thickness of the stroke. Then, ratios are resolved relative to the
+        /// length of the stroked path. Absolute lengths are used as they are.
+        /// For example, `{(10pt, 50%, "dot")}` on a 2pt thick line of length
+        /// 100pt results in the lengths `{(10pt, 50pt, 2pt)}`.
         ///
         /// If set to `{auto}`, the value is inherited, defaulting to `{none}`.
         ///
@@ -152,11 +291,83 @@ impl Stroke {
         ///   line(stroke: (dash: "dashed")),
         ///   line(stroke: (dash: (10pt, 5pt, "dot", 5pt))),
         ///   line(stroke: (dash: (array: (10pt, 5pt, "dot", 5pt), phase: 10pt))),
+        ///   line(stroke: (dash: (20%, 5pt))),
+        ///   line(stroke: (dash: (array: (20%, 5pt), phase: 10%))),
+        ///   line(stroke: (dash: (10pt, 50%, "dot"))),
         /// )
         /// ```
         #[external]
         dash: Smart<Option<DashPattern>>,
 
+        /// The color or gradient to use for the gaps of the dash pattern. Only
+        /// applicable if `dash` is set.
+        ///
+        /// This is useful for two-colored dashed lines, like the ones used for
+        /// railways on maps. The gaps are painted as a solid line below the
+        /// dashes.
+        ///
+        /// If set to `{auto}`, the value is inherited, defaulting to
+        /// transparent gaps.
+        ///
+        /// ```example
+        /// #line(
+        ///   length: 100%,
+        ///   stroke: (
+        ///     paint: black,
+        ///     thickness: 4pt,
+        ///     dash: (12pt, 12pt),
+        ///     gap-paint: white.darken(10%),
+        ///   ),
+        /// )
+        /// ```
+        #[external]
+        gap_paint: Smart<Paint>,
+
+        /// Whether to draw the stroke as two parallel lines in the same paint,
+        /// as is common for separators in tables.
+        ///
+        /// Each of the two lines has the stroke's `thickness` and they are
+        /// `double-gap` apart, so that the stroke takes up twice its thickness
+        /// plus the gap in total. The stroke stays centered on the stroked
+        /// path. Both lines get the stroke's caps, joins, and dash pattern.
+        ///
+        /// Double strokes are supported on lines and rectangles without
+        /// rounded corners whose sides all have the same stroke. Other shapes,
+        /// like circles, polygons, and curves, are stroked with a single line
+        /// and a warning is emitted.
+        ///
+        /// If set to `{auto}`, the value is inherited, defaulting to `{false}`.
+        ///
+        /// ```example
+        /// #table(
+        ///   columns: 2,
+        ///   stroke: none,
+        ///   table.hline(y: 1, stroke: (double: true)),
+        ///   [*Name*], [*Count*],
+        ///   [Apples], [3],
+        ///   [Pears], [5],
+        /// )
+        /// ```
+        #[external]
+        double: Smart<bool>,
+
+        /// The gap between the two lines of a double stroke. Only applicable if
+        /// `double` is `{true}`.
+        ///
+        /// If set to `{auto}`, the value is inherited, defaulting to the
+        /// stroke's thickness or `{1pt}` for hairlines.
+        ///
+        /// ```example
+        /// #set line(length: 100%)
+        /// #stack(
+        ///   spacing: 1em,
+        ///   line(stroke: (double: true)),
+        ///   line(stroke: (thickness: 2pt, double: true, double-gap: 4pt)),
+        /// )
+        /// ```
+        #[external]
+        double_gap: Smart<Length>,
+
         /// Number at which protruding sharp bends are rendered with a bevel
         /// instead or a miter join. The higher the number, the sharper an angle
         /// can be before it is bevelled. Only applicable if `join` is
@@ -186,6 +397,28 @@ impl Stroke {
         /// ```
         #[external]
         miter_limit: Smart<f64>,
+
+        /// Whether to anti-alias the stroke when rendering it to pixels.
+        ///
+        /// Disabling anti-aliasing draws the stroke with sharp, aliased edges.
+        /// This is useful for pixel art or grid lines that should stay crisp
+        /// in PNG export, similar to how `{"pixelated"}`
+        /// [scaling]($image.scaling) works for images. In SVG export, this
+        /// maps to `shape-rendering="crispEdges"`. PDF viewers decide on
+        /// anti-aliasing themselves, so this has no effect in PDF export.
+        ///
+        /// If set to `{auto}`, the value is inherited, defaulting to `{true}`.
+        ///
+        /// ```example
+        /// #set line(length: 100%, stroke: 0.7pt)
+        /// #stack(
+        ///   spacing: 4pt,
+        ///   line(),
+        ///   line(stroke: (anti-alias: false)),
+        /// )
+        /// ```
+        #[external]
+        anti_alias: Smart<bool>,
     ) -> SourceResult<Stroke> {
         if let Some(stroke) = args.eat::<Stroke>()? {
             return Ok(stroke);
@@ -196,13 +429,35 @@ impl Stroke {
         }
 
         let paint = take::<Paint>(args, "paint")?;
-        let thickness = take::<Length>(args, "thickness")?;
+        let paint_anchor = take::<PaintAnchor>(args, "paint-anchor")?;
+        let gradient_along_path = take::<bool>(args, "gradient-along-path")?;
+        let (thickness, hairline) = Thickness::split(take(args, "thickness")?);
         let cap = take::<LineCap>(args, "cap")?;
         let join = take::<LineJoin>(args, "join")?;
+        let join_radius = take::<Length>(args, "join-radius")?;
         let dash = take::<Option<DashPattern>>(args, "dash")?;
+        let gap_paint = take::<Paint>(args, "gap-paint")?;
+        let double = take::<bool>(args, "double")?;
+        let double_gap = take::<Length>(args, "double-gap")?;
         let miter_limit = take::<f64>(args, "miter-limit")?.map(Scalar::new);
+        let anti_alias = take::<bool>(args, "anti-alias")?;
 
-        Ok(Self { paint, thickness, cap, join, dash, miter_limit })
+        Ok(Self {
+            paint,
+            paint_anchor,
+            gradient_along_path,
+            thickness,
+            hairline,
+            cap,
+            join,
+            join_radius,
+            dash,
+            gap_paint,
+            double,
+            double_gap,
+            miter_limit,
+            anti_alias,
+        })
     }
 }
 
@@ -214,9 +469,13 @@ impl<T: Numeric> Stroke<T> {
     {
         Stroke {
             paint: self.paint,
+            paint_anchor: self.paint_anchor,
+            gradient_along_path: self.gradient_along_path,
             thickness: self.thickness.map(&f),
+            hairline: self.hairline,
             cap: self.cap,
             join: self.join,
+            join_radius: self.join_radius.map(&f),
             dash: self.dash.map(|dash| {
                 dash.map(|dash| DashPattern {
                     array: dash
@@ -225,12 +484,17 @@ impl<T: Numeric> Stroke<T> {
                         .map(|l| match l {
                             DashLength::Length(v) => DashLength::Length(f(v)),
                             DashLength::LineWidth => DashLength::LineWidth,
+                            DashLength::Ratio(v) => DashLength::Ratio(v),
                         })
                         .collect(),
-                    phase: f(dash.phase),
+                    phase: dash.phase.map(&f),
                 })
             }),
+            gap_paint: self.gap_paint,
+            double: self.double,
+            double_gap: self.double_gap.map(&f),
             miter_limit: self.miter_limit,
+            anti_alias: self.anti_alias,
         }
     }
 }
@@ -238,7 +502,12 @@ impl<T: Numeric> Stroke<T> {
 impl Stroke<Abs> {
     /// Unpack the stroke, filling missing fields from the `default`.
     pub fn unwrap_or(self, default: FixedStroke) -> FixedStroke {
-        let thickness = self.thickness.unwrap_or(default.thickness);
+        let hairline = self.hairline.unwrap_or(default.hairline);
+        let thickness = if hairline {
+            Abs::zero()
+        } else {
+            self.thickness.unwrap_or(default.thickness)
+        };
         let dash = self
             .dash
             .map(|dash| {
@@ -249,13 +518,41 @@ impl Stroke<Abs> {
             })
             .unwrap_or(default.dash);
 
+        let double = match self.double {
+            Smart::Auto => default.double.map(|gap| self.double_gap.unwrap_or(gap)),
+            Smart::Custom(false) => None,
+            Smart::Custom(true) => {
+                Some(self.double_gap.custom().or(default.double).unwrap_or(if hairline {
+                    Abs::pt(1.0)
+                } else {
+                    thickness
+                }))
+            }
+        };
+
+        let paint_anchor = self.paint_anchor.custom().or(default.paint_anchor);
+        let paint = self.paint.unwrap_or(default.paint);
+        let paint = match paint_anchor {
+            Some(anchor) => anchor.apply(paint),
+            None => paint,
+        };
+
         FixedStroke {
-            paint: self.paint.unwrap_or(default.paint),
+            paint,
+            paint_anchor,
+            gradient_along_path: self
+                .gradient_along_path
+                .unwrap_or(default.gradient_along_path),
             thickness,
+            hairline,
             cap: self.cap.unwrap_or(default.cap),
             join: self.join.unwrap_or(default.join),
+            join_radius: self.join_radius.custom().or(default.join_radius),
             dash,
+            gap_paint: self.gap_paint.custom().or(default.gap_paint),
+            double,
             miter_limit: self.miter_limit.unwrap_or(default.miter_limit),
+            anti_alias: self.anti_alias.unwrap_or(default.anti_alias),
         }
     }
 
@@ -267,19 +564,51 @@ impl Stroke<Abs> {
     }
 }
 
-impl<T: Numeric + Repr> Repr for Stroke<T> {
+impl Repr for Stroke {
+    /// The thickness, join radius, and double gap keep the unit they were
+    /// written in, so that e.g. `{2mm + red}` round-trips. See
+    /// [`Length::repr_exact`] for lengths with mixed units.
     fn repr(&self) -> EcoString {
         let mut r = EcoString::new();
-        let Self { paint, thickness, cap, join, dash, miter_limit } = &self;
-        if cap.is_auto() && join.is_auto() && dash.is_auto() && miter_limit.is_auto() {
+        let Self {
+            paint,
+            paint_anchor,
+            gradient_along_path,
+            thickness,
+            hairline,
+            cap,
+            join,
+            join_radius,
+            dash,
+            gap_paint,
+            double,
+            double_gap,
+            miter_limit,
+            anti_alias,
+        } = &self;
+        if paint_anchor.is_auto()
+            && gradient_along_path.is_auto()
+            && hairline.is_auto()
+            && cap.is_auto()
+            && join.is_auto()
+            && join_radius.is_auto()
+            && dash.is_auto()
+            && gap_paint.is_auto()
+            && double.is_auto()
+            && double_gap.is_auto()
+            && miter_limit.is_auto()
+            && anti_alias.is_auto()
+        {
             match (&self.paint, &self.thickness) {
                 (Smart::Custom(paint), Smart::Custom(thickness)) => {
-                    r.push_str(&thickness.repr());
+                    r.push_str(&thickness.repr_exact());
                     r.push_str(" + ");
                     r.push_str(&paint.repr());
                 }
                 (Smart::Custom(paint), Smart::Auto) => r.push_str(&paint.repr()),
-                (Smart::Auto, Smart::Custom(thickness)) => r.push_str(&thickness.repr()),
+                (Smart::Auto, Smart::Custom(thickness)) => {
+                    r.push_str(&thickness.repr_exact())
+                }
                 (Smart::Auto, Smart::Auto) => r.push_str("1pt + black"),
             }
         } else {
@@ -291,10 +620,27 @@ impl<T: Numeric + Repr> Repr for Stroke<T> {
                 r.push_str(&paint.repr());
                 sep = ", ";
             }
-            if let Smart::Custom(thickness) = &thickness {
+            if let Smart::Custom(paint_anchor) = &paint_anchor {
+                r.push_str(sep);
+                r.push_str("paint-anchor: ");
+                r.push_str(&paint_anchor.repr());
+                sep = ", ";
+            }
+            if let Smart::Custom(gradient_along_path) = &gradient_along_path {
+                r.push_str(sep);
+                r.push_str("gradient-along-path: ");
+                r.push_str(&gradient_along_path.repr());
+                sep = ", ";
+            }
+            if *hairline == Smart::Custom(true) {
                 r.push_str(sep);
                 r.push_str("thickness: ");
-                r.push_str(&thickness.repr());
+                r.push_str(&Thickness::Hairline.into_value().repr());
+                sep = ", ";
+            } else if let Smart::Custom(thickness) = &thickness {
+                r.push_str(sep);
+                r.push_str("thickness: ");
+                r.push_str(&thickness.repr_exact());
                 sep = ", ";
             }
             if let Smart::Custom(cap) = &cap {
@@ -309,6 +655,12 @@ impl<T: Numeric + Repr> Repr for Stroke<T> {
                 r.push_str(&join.repr());
                 sep = ", ";
             }
+            if let Smart::Custom(join_radius) = &join_radius {
+                r.push_str(sep);
+                r.push_str("join-radius: ");
+                r.push_str(&join_radius.repr_exact());
+                sep = ", ";
+            }
             if let Smart::Custom(dash) = &dash {
                 r.push_str(sep);
                 r.push_str("dash: ");
@@ -319,10 +671,34 @@ impl<T: Numeric + Repr> Repr for Stroke<T> {
                 }
                 sep = ", ";
             }
+            if let Smart::Custom(gap_paint) = &gap_paint {
+                r.push_str(sep);
+                r.push_str("gap-paint: ");
+                r.push_str(&gap_paint.repr());
+                sep = ", ";
+            }
+            if let Smart::Custom(double) = &double {
+                r.push_str(sep);
+                r.push_str("double: ");
+                r.push_str(&double.repr());
+                sep = ", ";
+            }
+            if let Smart::Custom(double_gap) = &double_gap {
+                r.push_str(sep);
+                r.push_str("double-gap: ");
+                r.push_str(&double_gap.repr_exact());
+                sep = ", ";
+            }
             if let Smart::Custom(miter_limit) = &miter_limit {
                 r.push_str(sep);
                 r.push_str("miter-limit: ");
                 r.push_str(&miter_limit.get().repr());
+                sep = ", ";
+            }
+            if let Smart::Custom(anti_alias) = &anti_alias {
+                r.push_str(sep);
+                r.push_str("anti-alias: ");
+                r.push_str(&anti_alias.repr());
             }
             r.push(')');
         }
@@ -332,13 +708,30 @@ impl<T: Numeric + Repr> Repr for Stroke<T> {
 
 impl<T: Numeric + Fold> Fold for Stroke<T> {
     fn fold(self, outer: Self) -> Self {
+        // The thickness and the hairline flag are inherited together so that
+        // an inner thickness overrides an outer hairline and vice versa.
+        let (thickness, hairline) = if self.thickness.is_auto() && self.hairline.is_auto()
+        {
+            (outer.thickness, outer.hairline)
+        } else {
+            (self.thickness, self.hairline)
+        };
+
         Self {
             paint: self.paint.or(outer.paint),
-            thickness: self.thickness.or(outer.thickness),
+            paint_anchor: self.paint_anchor.or(outer.paint_anchor),
+            gradient_along_path: self.gradient_along_path.or(outer.gradient_along_path),
+            thickness,
+            hairline,
             cap: self.cap.or(outer.cap),
             join: self.join.or(outer.join),
+            join_radius: self.join_radius.or(outer.join_radius),
             dash: self.dash.or(outer.dash),
+            gap_paint: self.gap_paint.or(outer.gap_paint),
+            double: self.double.or(outer.double),
+            double_gap: self.double_gap.or(outer.double_gap),
             miter_limit: self.miter_limit.or(outer.miter_limit),
+            anti_alias: self.anti_alias.or(outer.anti_alias),
         }
     }
 }
@@ -349,11 +742,19 @@ impl Resolve for Stroke {
     fn resolve(self, styles: StyleChain) -> Self::Output {
         Stroke {
             paint: self.paint,
+            paint_anchor: self.paint_anchor,
+            gradient_along_path: self.gradient_along_path,
             thickness: self.thickness.resolve(styles),
+            hairline: self.hairline,
             cap: self.cap,
             join: self.join,
+            join_radius: self.join_radius.resolve(styles),
             dash: self.dash.resolve(styles),
+            gap_paint: self.gap_paint,
+            double: self.double,
+            double_gap: self.double_gap.resolve(styles),
             miter_limit: self.miter_limit,
+            anti_alias: self.anti_alias,
         }
     }
 }
@@ -384,20 +785,49 @@ cast! {
         }
 
         let paint = take::<Paint>(&mut dict, "paint")?;
-        let thickness = take::<Length>(&mut dict, "thickness")?;
+        let paint_anchor = take::<PaintAnchor>(&mut dict, "paint-anchor")?;
+        let gradient_along_path = take::<bool>(&mut dict, "gradient-along-path")?;
+        let (thickness, hairline) = Thickness::split(take(&mut dict, "thickness")?);
         let cap = take::<LineCap>(&mut dict, "cap")?;
         let join = take::<LineJoin>(&mut dict, "join")?;
+        let join_radius = take::<Length>(&mut dict, "join-radius")?;
         let dash = take::<Option<DashPattern>>(&mut dict, "dash")?;
+        let gap_paint = take::<Paint>(&mut dict, "gap-paint")?;
+        let double = take::<bool>(&mut dict, "double")?;
+        let double_gap = take::<Length>(&mut dict, "double-gap")?;
         let miter_limit = take::<f64>(&mut dict, "miter-limit")?;
-        dict.finish(&["paint", "thickness", "cap", "join", "dash", "miter-limit"])?;
+        let anti_alias = take::<bool>(&mut dict, "anti-alias")?;
+        dict.finish(&[
+            "paint",
+            "paint-anchor",
+            "gradient-along-path",
+            "thickness",
+            "cap",
+            "join",
+            "join-radius",
+            "dash",
+            "gap-paint",
+            "double",
+            "double-gap",
+            "miter-limit",
+            "anti-alias",
+        ])?;
 
         Self {
             paint,
+            paint_anchor,
+            gradient_along_path,
             thickness,
+            hairline,
             cap,
             join,
+            join_radius,
             dash,
+            gap_paint,
+            double,
+            double_gap,
             miter_limit: miter_limit.map(Scalar::new),
+            anti_alias,
         }
     },
 }
@@ -407,6 +837,74 @@ cast! {
     self => self.map(Length::from).into_value(),
 }
 
+/// The thickness of a stroke as specified by the user: Either a length or a
+/// hairline.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Thickness {
+    /// A fixed thickness.
+    Length(Length),
+    /// The thinnest line the output device can display.
+    Hairline,
+}
+
+impl Thickness {
+    /// Splits the thickness into the `thickness` and `hairline` fields of a
+    /// stroke.
+    fn split(thickness: Smart<Self>) -> (Smart<Length>, Smart<bool>) {
+        match thickness {
+            Smart::Auto => (Smart::Auto, Smart::Auto),
+            Smart::Custom(Self::Length(length)) => (Smart::Custom(length), Smart::Auto),
+            Smart::Custom(Self::Hairline) => (Smart::Auto, Smart::Custom(true)),
+        }
+    }
+}
+
+cast! {
+    Thickness,
+    self => match self {
+        Self::Length(v) => v.into_value(),
+        Self::Hairline => "hairline".into_value(),
+    },
+    "hairline" => Self::Hairline,
+    v: Length => Self::Length(v),
+}
+
+/// How a gradient or tiling paint is placed on a stroke.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum PaintAnchor {
+    /// The paint's origin is at the start of the stroked path and its x-axis
+    /// follows the path's initial direction.
+    Along,
+    /// The paint is placed relative to the parent container.
+    Fixed,
+}
+
+impl PaintAnchor {
+    /// Overrides the relative placement of the paint according to the anchor.
+    fn apply(self, paint: Paint) -> Paint {
+        let relative = match self {
+            Self::Along => RelativeTo::Self_,
+            Self::Fixed => RelativeTo::Parent,
+        };
+        match paint {
+            Paint::Solid(_) => paint,
+            Paint::Gradient(gradient) => {
+                Paint::Gradient(gradient.with_relative(relative))
+            }
+            Paint::Tiling(tiling) => Paint::Tiling(tiling.with_relative(relative)),
+        }
+    }
+}
+
+impl Repr for PaintAnchor {
+    fn repr(&self) -> EcoString {
+        match self {
+            Self::Along => "along".repr(),
+            Self::Fixed => "fixed".repr(),
+        }
+    }
+}
+
 /// The line cap of a stroke
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum LineCap {
@@ -453,11 +951,12 @@ impl Repr for LineJoin {
 
 /// A line dash pattern.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
-pub struct DashPattern<T: Numeric = Length, DT = DashLength<T>> {
+pub struct DashPattern<T: Numeric = Length, DT = DashLength<T>, PT = Rel<T>> {
     /// The dash array.
     pub array: Vec<DT>,
-    /// The dash phase.
-    pub phase: T,
+    /// The dash phase. Until resolved with [`DashPattern::along`], it may be
+    /// relative to the length of the stroked path.
+    pub phase: PT,
 }
 
 impl<T: Numeric + Repr, DT: Repr> Repr for DashPattern<T, DT> {
@@ -470,15 +969,30 @@ impl<T: Numeric + Repr, DT: Repr> Repr for DashPattern<T, DT> {
             r.push_str(&elem.repr())
         }
         r.push_str("), phase: ");
-        r.push_str(&self.phase.repr());
+        if self.phase.rel.is_zero() {
+            r.push_str(&self.phase.abs.repr());
+        } else if self.phase.abs.is_zero() {
+            r.push_str(&self.phase.rel.repr());
+        } else {
+            r.push_str(&self.phase.repr());
+        }
         r.push(')');
         r
     }
 }
 
-impl<T: Numeric + Default> From<Vec<DashLength<T>>> for DashPattern<T> {
+impl<T: Numeric> From<Vec<DashLength<T>>> for DashPattern<T> {
     fn from(array: Vec<DashLength<T>>) -> Self {
-        Self { array, phase: T::default() }
+        Self { array, phase: Rel::zero() }
+    }
+}
+
+impl<T: Numeric> DashPattern<T> {
+    /// Whether a dash length or the phase is relative to the length of the
+    /// stroked path.
+    pub fn is_relative(&self) -> bool {
+        self.array.iter().any(|l| matches!(l, DashLength::Ratio(_)))
+            || !self.phase.rel.is_zero()
     }
 }
 
@@ -497,7 +1011,16 @@ impl Resolve for DashPattern {
 // https://tex.stackexchange.com/questions/45275/tikz-get-values-for-predefined-dash-patterns
 cast! {
     DashPattern,
-    self => dict! { "array" => self.array, "phase" => self.phase }.into_value(),
+    self => {
+        let phase = if self.phase.rel.is_zero() {
+            self.phase.abs.into_value()
+        } else if self.phase.abs.is_zero() {
+            self.phase.rel.into_value()
+        } else {
+            self.phase.into_value()
+        };
+        dict! { "array" => self.array, "phase" => phase }.into_value()
+    },
 
     "solid" => Vec::new().into(),
     "dotted" => vec![DashLength::LineWidth, Abs::pt(2.0).into()].into(),
@@ -510,11 +1033,11 @@ cast! {
     "densely-dash-dotted" => vec![Abs::pt(3.0).into(), Abs::pt(1.0).into(), DashLength::LineWidth, Abs::pt(1.0).into()].into(),
     "loosely-dash-dotted" => vec![Abs::pt(3.0).into(), Abs::pt(4.0).into(), DashLength::LineWidth, Abs::pt(4.0).into()].into(),
 
-    array: Vec<DashLength> => Self { array, phase: Length::zero() },
+    array: Vec<DashLength> => Self { array, phase: Rel::zero() },
     mut dict: Dict => {
         let array: Vec<DashLength> = dict.take("array")?.cast()?;
         let phase = dict.take("phase").ok().map(Value::cast)
-            .transpose()?.unwrap_or(Length::zero());
+            .transpose()?.unwrap_or(Rel::zero());
         dict.finish(&["array", "phase"])?;
         Self {
             array,
@@ -523,18 +1046,37 @@ cast! {
     },
 }
 
+impl DashPattern<Abs, Rel<Abs>> {
+    /// Resolves the dash lengths and phase for a stroked path of the given
+    /// length, which relative dash lengths and phases are relative to.
+    ///
+    /// Dots must already have been replaced by the line width, which happens
+    /// when unpacking a [`Stroke`] into a [`FixedStroke`].
+    pub fn along(&self, length: Abs) -> DashPattern<Abs, Abs, Abs> {
+        DashPattern {
+            array: self.array.iter().map(|l| l.relative_to(length)).collect(),
+            phase: self.phase.relative_to(length),
+        }
+    }
+}
+
 /// The length of a dash in a line dash pattern.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum DashLength<T: Numeric = Length> {
     LineWidth,
     Length(T),
+    /// A length relative to the length of the stroked path.
+    Ratio(Ratio),
 }
 
 impl<T: Numeric> DashLength<T> {
-    fn finish(self, line_width: T) -> T {
+    /// Replaces a dot with the line width. This is the first step in resolving
+    /// a dash length, followed by [`DashPattern::along`] for ratios.
+    fn finish(self, line_width: T) -> Rel<T> {
         match self {
-            Self::LineWidth => line_width,
-            Self::Length(l) => l,
+            Self::LineWidth => line_width.into(),
+            Self::Length(l) => l.into(),
+            Self::Ratio(r) => r.into(),
         }
     }
 }
@@ -544,6 +1086,7 @@ impl<T: Numeric + Repr> Repr for DashLength<T> {
         match self {
             Self::LineWidth => "dot".repr(),
             Self::Length(v) => v.repr(),
+            Self::Ratio(v) => v.repr(),
         }
     }
 }
@@ -555,6 +1098,7 @@ impl Resolve for DashLength {
         match self {
             Self::LineWidth => DashLength::LineWidth,
             Self::Length(v) => DashLength::Length(v.resolve(styles)),
+            Self::Ratio(v) => DashLength::Ratio(v),
         }
     }
 }
@@ -570,9 +1114,11 @@ cast! {
     self => match self {
         Self::LineWidth => "dot".into_value(),
         Self::Length(v) => v.into_value(),
+        Self::Ratio(v) => v.into_value(),
     },
     "dot" => Self::LineWidth,
     v: Length => Self::Length(v),
+    v: Ratio => Self::Ratio(v),
 }
 
 /// A fully specified stroke of a geometric shape.
@@ -580,16 +1126,36 @@ cast! {
 pub struct FixedStroke {
     /// The stroke's paint.
     pub paint: Paint,
-    /// The stroke's thickness.
+    /// How the paint is placed on the stroke. If `None`, it is placed as
+    /// specified by its relative setting.
+    pub paint_anchor: Option<PaintAnchor>,
+    /// Whether a gradient paint follows the length of the stroked path. See
+    /// [`Shape::split_along_path`](super::Shape::split_along_path).
+    pub gradient_along_path: bool,
+    /// The stroke's thickness. Zero for hairlines.
     pub thickness: Abs,
+    /// Whether the stroke should be drawn as thin as the output device
+    /// permits, regardless of the thickness and any transformations.
+    pub hairline: bool,
     /// The stroke's line cap.
     pub cap: LineCap,
     /// The stroke's line join.
     pub join: LineJoin,
-    /// The stroke's line dash pattern.
-    pub dash: Option<DashPattern<Abs, Abs>>,
+    /// The radius of the outer edge of round joins, if it differs from half
+    /// the thickness. See [`Curve::round_corners`](super::Curve::round_corners).
+    pub join_radius: Option<Abs>,
+    /// The stroke's line dash pattern. Dash lengths may be relative to the
+    /// length of the stroked path, see [`DashPattern::along`].
+    pub dash: Option<DashPattern<Abs, Rel<Abs>>>,
+    /// The paint for the gaps of the dash pattern. Transparent if `None`.
+    pub gap_paint: Option<Paint>,
+    /// The gap between the two lines of a double stroke. A single line is
+    /// drawn if `None`.
+    pub double: Option<Abs>,
     /// The miter limit. Defaults to 4.0, same as `tiny-skia`.
     pub miter_limit: Scalar,
+    /// Whether the stroke is anti-aliased when rasterized.
+    pub anti_alias: bool,
 }
 
 impl FixedStroke {
@@ -601,17 +1167,37 @@ impl FixedStroke {
             ..Default::default()
         }
     }
+
+    /// The solid stroke that fills the gaps of this stroke's dash pattern.
+    ///
+    /// Returns `None` if the stroke has no dash pattern or no gap paint.
+    pub fn gap_stroke(&self) -> Option<FixedStroke> {
+        self.dash.as_ref()?;
+        Some(FixedStroke {
+            paint: self.gap_paint.clone()?,
+            dash: None,
+            gap_paint: None,
+            ..self.clone()
+        })
+    }
 }
 
 impl Default for FixedStroke {
     fn default() -> Self {
         Self {
             paint: Paint::Solid(Color::BLACK),
+            paint_anchor: None,
+            gradient_along_path: false,
             thickness: Abs::pt(1.0),
+            hairline: false,
             cap: LineCap::Butt,
             join: LineJoin::Miter,
+            join_radius: None,
             dash: None,
+            gap_paint: None,
+            double: None,
             miter_limit: Scalar::new(4.0),
+            anti_alias: true,
         }
     }
 }
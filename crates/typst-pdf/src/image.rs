@@ -37,6 +37,11 @@ pub(crate) fn handle_image(
 
     let interpolate = image.scaling() == Smart::Custom(ImageScaling::Smooth);
 
+    // TODO: Write the image's rendering intent (`image.intent()`) to the
+    // `/Intent` entry of the image XObject. krilla's images currently have no
+    // way to express it, so until that is supported, viewers fall back to
+    // their default intent.
+
     gc.image_spans.insert(span);
 
     let mut handle = tags::image(gc, fc, &mut surface, image, size);
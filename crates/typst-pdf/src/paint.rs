@@ -44,18 +44,21 @@ pub(crate) fn convert_stroke(
     surface: &mut Surface,
     state: &State,
     size: Size,
+    length: Abs,
 ) -> SourceResult<Stroke> {
     let (paint, opacity) =
         convert_paint(fc, &stroke.paint, on_text, surface, state, size)?;
 
     Ok(Stroke {
         paint,
-        width: stroke.thickness.to_f32(),
+        // A line width of zero denotes the thinnest line that can be rendered
+        // at device resolution.
+        width: if stroke.hairline { 0.0 } else { stroke.thickness.to_f32() },
         miter_limit: stroke.miter_limit.get() as f32,
         line_join: stroke.join.to_krilla(),
         line_cap: stroke.cap.to_krilla(),
         opacity: NormalizedF32::new(opacity as f32 / 255.0).unwrap(),
-        dash: stroke.dash.as_ref().map(convert_dash),
+        dash: stroke.dash.as_ref().map(|dash| convert_dash(&dash.along(length))),
     })
 }
 
@@ -358,7 +361,7 @@ fn convert_gradient_stops(gradient: &Gradient) -> Vec<Stop> {
     stops
 }
 
-fn convert_dash(dash: &DashPattern<Abs, Abs>) -> StrokeDash {
+fn convert_dash(dash: &DashPattern<Abs, Abs, Abs>) -> StrokeDash {
     StrokeDash {
         array: dash.array.iter().map(|e| e.to_f32()).collect(),
         offset: dash.phase.to_f32(),
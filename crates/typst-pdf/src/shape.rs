@@ -21,20 +21,90 @@ pub(crate) fn handle_shape(
     let surface = handle.surface();
 
     surface.set_location(span.into_raw());
+    let mut surface = defer(surface, |s| s.reset_location());
+
+    if let Some((fill, pieces)) = shape.split_along_path() {
+        if let Some(fill) = fill {
+            draw_anchored_shape(fc, &fill, &mut surface, gc)?;
+        }
+        for piece in &pieces {
+            draw_anchored_shape(fc, piece, &mut surface, gc)?;
+        }
+        Ok(())
+    } else if let Some((fill, lines)) = shape.split_double() {
+        if let Some(fill) = fill {
+            draw_anchored_shape(fc, &fill, &mut surface, gc)?;
+        }
+        for (ts, line) in &lines {
+            fc.push();
+            fc.state_mut().pre_concat(*ts);
+            let result = draw_anchored_shape(fc, line, &mut surface, gc);
+            fc.pop();
+            result?;
+        }
+        Ok(())
+    } else {
+        draw_anchored_shape(fc, shape, &mut surface, gc)
+    }
+}
+
+/// Draws a shape in the current state's coordinate system, moving its stroke
+/// into the coordinate system of its paint anchor if needed.
+fn draw_anchored_shape(
+    fc: &mut FrameContext,
+    shape: &Shape,
+    surface: &mut Surface,
+    gc: &mut GlobalContext,
+) -> SourceResult<()> {
+    if let Some((fill, ts, stroke)) = shape.split_anchor() {
+        if let Some(fill) = fill {
+            draw_split_shape(fc, &fill, surface, gc)?;
+        }
+        fc.push();
+        fc.state_mut().pre_concat(ts);
+        let result = draw_split_shape(fc, &stroke, surface, gc);
+        fc.pop();
+        result
+    } else {
+        draw_split_shape(fc, shape, surface, gc)
+    }
+}
+
+/// Draws a shape in the current state's coordinate system, drawing the gaps
+/// of its dashed stroke separately if needed.
+fn draw_split_shape(
+    fc: &mut FrameContext,
+    shape: &Shape,
+    surface: &mut Surface,
+    gc: &mut GlobalContext,
+) -> SourceResult<()> {
     surface.push_transform(&fc.state().transform().to_krilla());
-    let mut surface = defer(surface, |s| {
-        s.pop();
-        s.reset_location();
-    });
+    let mut surface = defer(surface, |s| s.pop());
+
+    if let Some((gaps, dashes)) = shape.split_gaps() {
+        draw_shape(fc, &gaps, &mut surface, gc)?;
+        draw_shape(fc, &dashes, &mut surface, gc)
+    } else {
+        draw_shape(fc, shape, &mut surface, gc)
+    }
+}
 
-    if let Some(path) = convert_geometry(&shape.geometry) {
+/// Draws the fill and stroke of a shape onto the surface.
+fn draw_shape(
+    fc: &mut FrameContext,
+    shape: &Shape,
+    surface: &mut Surface,
+    gc: &mut GlobalContext,
+) -> SourceResult<()> {
+    let geometry = shape.drawn_geometry();
+    if let Some(path) = convert_geometry(&geometry) {
         let fill = if let Some(paint) = &shape.fill {
             Some(paint::convert_fill(
                 gc,
                 paint,
                 shape.fill_rule,
                 false,
-                &mut surface,
+                surface,
                 fc.state(),
                 shape.geometry.bbox_size(),
             )?)
@@ -43,7 +113,11 @@ pub(crate) fn handle_shape(
         };
 
         let stroke = shape.stroke.as_ref().and_then(|stroke| {
-            if stroke.thickness.to_f32() > 0.0 { Some(stroke) } else { None }
+            if stroke.thickness.to_f32() > 0.0 || stroke.hairline {
+                Some(stroke)
+            } else {
+                None
+            }
         });
 
         let stroke = if let Some(stroke) = &stroke {
@@ -51,9 +125,10 @@ pub(crate) fn handle_shape(
                 gc,
                 stroke,
                 false,
-                &mut surface,
+                surface,
                 fc.state(),
                 shape.geometry.bbox_size(),
+                geometry.length(),
             )?;
 
             Some(stroke)
@@ -5,7 +5,7 @@ use bytemuck::TransparentWrapper;
 use krilla::surface::{Location, Surface};
 use krilla::text::GlyphId;
 use typst_library::diag::{SourceResult, bail};
-use typst_library::layout::Size;
+use typst_library::layout::{Abs, Size};
 use typst_library::text::{Font, Glyph, TextItem};
 use typst_library::visualize::FillRule;
 use typst_syntax::Span;
@@ -35,14 +35,15 @@ pub(crate) fn handle_text(
         fc.state(),
         Size::zero(),
     )?;
-    let stroke =
-        if let Some(stroke) = t.stroke.as_ref().map(|s| {
-            paint::convert_stroke(gc, s, true, surface, fc.state(), Size::zero())
-        }) {
-            Some(stroke?)
-        } else {
-            None
-        };
+    // Relative dash lengths are rejected for text strokes, so there is no need
+    // for a path length.
+    let stroke = if let Some(stroke) = t.stroke.as_ref().map(|s| {
+        paint::convert_stroke(gc, s, true, surface, fc.state(), Size::zero(), Abs::zero())
+    }) {
+        Some(stroke?)
+    } else {
+        None
+    };
     let text = t.text.as_str();
     let size = t.size;
     let glyphs: &[PdfGlyph] = TransparentWrapper::wrap_slice(t.glyphs.as_slice());
@@ -1,12 +1,14 @@
 use hayro::{FontData, FontQuery, InterpreterSettings, RenderSettings, StandardFont};
-use image::imageops::FilterType;
-use image::{GenericImageView, Rgba};
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, GenericImageView, Rgba};
 use std::sync::Arc;
 use tiny_skia as sk;
 use tiny_skia::IntSize;
 use typst_library::foundations::Smart;
 use typst_library::layout::Size;
-use typst_library::visualize::{Image, ImageKind, ImageScaling, PdfImage};
+use typst_library::visualize::{
+    Image, ImageKind, ImageScaling, PdfImage, RenderingIntent,
+};
 
 use crate::{AbsExt, State};
 
@@ -67,24 +69,69 @@ fn build_texture(image: &Image, w: u32, h: u32) -> Option<Arc<sk::Pixmap>> {
             let h = texture.height();
 
             let buf;
-            let dynamic = raster.dynamic();
-            let resized = if (w, h) == (dynamic.width(), dynamic.height()) {
-                // Small optimization to not allocate in case image is not resized.
-                dynamic
+            // Colors are only converted if an intent was set explicitly.
+            // Otherwise, the image is painted as is.
+            let managed = match (image.intent(), raster.icc()) {
+                (Smart::Custom(intent), Some(icc)) => {
+                    to_srgb(raster.dynamic(), icc, intent)
+                }
+                _ => None,
+            };
+            let dynamic: &DynamicImage = match &managed {
+                Some(managed) => managed,
+                None => raster.dynamic(),
+            };
+            let unchanged = (w, h) == (dynamic.width(), dynamic.height());
+            let upscale = w > dynamic.width();
+            let filter = match image.scaling() {
+                Smart::Custom(ImageScaling::Pixelated) => FilterType::Nearest,
+                _ if upscale => FilterType::CatmullRom,
+                _ => FilterType::Lanczos3, // downscale
+            };
+
+            if dynamic.color().has_alpha() {
+                // Resample with premultiplied alpha. Otherwise, the color of
+                // fully transparent pixels, which is often black, bleeds into
+                // the visible pixels around them.
+                let mut premultiplied = dynamic.to_rgba8();
+                for Rgba(pixel) in premultiplied.pixels_mut() {
+                    let [r, g, b, a] = *pixel;
+                    let color = sk::ColorU8::from_rgba(r, g, b, a).premultiply();
+                    *pixel = [color.red(), color.green(), color.blue(), color.alpha()];
+                }
+
+                let resized = if unchanged {
+                    premultiplied
+                } else {
+                    imageops::resize(&premultiplied, w, h, filter)
+                };
+
+                for (&Rgba([r, g, b, a]), dest) in
+                    resized.pixels().zip(texture.pixels_mut())
+                {
+                    // Resampling filters may overshoot, so we need to clamp
+                    // the color components to the alpha.
+                    *dest = sk::PremultipliedColorU8::from_rgba(
+                        r.min(a),
+                        g.min(a),
+                        b.min(a),
+                        a,
+                    )?;
+                }
             } else {
-                let upscale = w > dynamic.width();
-                let filter = match image.scaling() {
-                    Smart::Custom(ImageScaling::Pixelated) => FilterType::Nearest,
-                    _ if upscale => FilterType::CatmullRom,
-                    _ => FilterType::Lanczos3, // downscale
+                let resized = if unchanged {
+                    // Small optimization to not allocate in case image is not
+                    // resized.
+                    dynamic
+                } else {
+                    buf = dynamic.resize_exact(w, h, filter);
+                    &buf
                 };
-                buf = dynamic.resize_exact(w, h, filter);
-                &buf
-            };
 
-            for ((_, _, src), dest) in resized.pixels().zip(texture.pixels_mut()) {
-                let Rgba([r, g, b, a]) = src;
-                *dest = sk::ColorU8::from_rgba(r, g, b, a).premultiply();
+                for ((_, _, src), dest) in resized.pixels().zip(texture.pixels_mut()) {
+                    let Rgba([r, g, b, a]) = src;
+                    *dest = sk::ColorU8::from_rgba(r, g, b, a).premultiply();
+                }
             }
 
             texture
@@ -105,6 +152,38 @@ fn build_texture(image: &Image, w: u32, h: u32) -> Option<Arc<sk::Pixmap>> {
     Some(Arc::new(texture))
 }
 
+/// Converts the colors of a raster image from its ICC profile to sRGB with the
+/// given rendering intent.
+///
+/// Returns `None` if the profile can't be applied to the image, in which case
+/// the image is painted without color management.
+fn to_srgb(
+    dynamic: &DynamicImage,
+    icc: &[u8],
+    intent: RenderingIntent,
+) -> Option<DynamicImage> {
+    // The profile of a grey-scale image can't be applied to RGB data.
+    if !dynamic.color().has_color() {
+        return None;
+    }
+
+    let input = qcms::Profile::new_from_slice(icc, false)?;
+    let mut output = qcms::Profile::new_sRGB();
+    output.precache_output_transform();
+
+    let intent = match intent {
+        RenderingIntent::Perceptual => qcms::Intent::Perceptual,
+        RenderingIntent::RelativeColorimetric => qcms::Intent::RelativeColorimetric,
+        RenderingIntent::AbsoluteColorimetric => qcms::Intent::AbsoluteColorimetric,
+        RenderingIntent::Saturation => qcms::Intent::Saturation,
+    };
+
+    let transform = qcms::Transform::new(&input, &output, qcms::DataType::RGBA8, intent)?;
+    let mut rgba = dynamic.to_rgba8();
+    transform.apply(&mut rgba);
+    Some(DynamicImage::ImageRgba8(rgba))
+}
+
 // Keep this in sync with `typst-svg`!
 fn build_pdf_texture(pdf: &PdfImage, w: u32, h: u32) -> Option<sk::Pixmap> {
     let select_standard_font = move |font: StandardFont| -> Option<(FontData, u32)> {
@@ -5,12 +5,45 @@ use typst_library::visualize::{
     Shape,
 };
 
-use crate::{AbsExt, State, paint};
+use crate::{AbsExt, State, paint, to_sk_transform};
 
 /// Render a geometrical shape into the canvas.
 pub fn render_shape(canvas: &mut sk::Pixmap, state: State, shape: &Shape) -> Option<()> {
+    if let Some((fill, pieces)) = shape.split_along_path() {
+        if let Some(fill) = fill {
+            render_shape(canvas, state, &fill);
+        }
+        for piece in &pieces {
+            render_shape(canvas, state, piece);
+        }
+        return Some(());
+    }
+
+    if let Some((fill, lines)) = shape.split_double() {
+        if let Some(fill) = fill {
+            render_shape(canvas, state, &fill);
+        }
+        for (ts, line) in &lines {
+            render_shape(canvas, state.pre_concat(to_sk_transform(ts)), line);
+        }
+        return Some(());
+    }
+
+    if let Some((fill, ts, stroke)) = shape.split_anchor() {
+        if let Some(fill) = fill {
+            render_shape(canvas, state, &fill);
+        }
+        return render_shape(canvas, state.pre_concat(to_sk_transform(&ts)), &stroke);
+    }
+
+    if let Some((gaps, dashes)) = shape.split_gaps() {
+        render_shape(canvas, state, &gaps);
+        return render_shape(canvas, state, &dashes);
+    }
+
     let ts = state.transform;
-    let path = match &shape.geometry {
+    let geometry = shape.drawn_geometry();
+    let path = match &*geometry {
         Geometry::Line(target) => {
             let mut builder = sk::PathBuilder::new();
             builder.line_to(target.x.to_f32(), target.y.to_f32());
@@ -48,7 +81,7 @@ pub fn render_shape(canvas: &mut sk::Pixmap, state: State, shape: &Shape) -> Opt
             None,
         );
 
-        if matches!(shape.geometry, Geometry::Rect(_)) {
+        if matches!(*geometry, Geometry::Rect(_)) {
             paint.anti_alias = false;
         }
 
@@ -59,14 +92,26 @@ pub fn render_shape(canvas: &mut sk::Pixmap, state: State, shape: &Shape) -> Opt
         canvas.fill_path(&path, &paint, rule, ts, state.mask);
     }
 
-    if let Some(FixedStroke { paint, thickness, cap, join, dash, miter_limit }) =
-        &shape.stroke
+    if let Some(FixedStroke {
+        paint,
+        thickness,
+        hairline,
+        cap,
+        join,
+        dash,
+        miter_limit,
+        anti_alias,
+        ..
+    }) = &shape.stroke
     {
+        // A width of zero makes tiny-skia draw a one pixel wide hairline.
         let width = thickness.to_f32();
 
-        // Don't draw zero-pt stroke.
-        if width > 0.0 {
-            let dash = dash.as_ref().and_then(to_sk_dash_pattern);
+        // Don't draw zero-pt stroke unless it is a hairline.
+        if width > 0.0 || *hairline {
+            let dash = dash
+                .as_ref()
+                .and_then(|dash| to_sk_dash_pattern(&dash.along(geometry.length())));
 
             let bbox = shape.geometry.bbox_size();
             let offset_bbox = if !matches!(shape.geometry, Geometry::Line(..)) {
@@ -98,7 +143,7 @@ pub fn render_shape(canvas: &mut sk::Pixmap, state: State, shape: &Shape) -> Opt
                 });
 
             let mut pixmap = None;
-            let paint = paint::to_sk_paint(
+            let mut paint = paint::to_sk_paint(
                 paint,
                 state,
                 offset_bbox,
@@ -107,6 +152,7 @@ pub fn render_shape(canvas: &mut sk::Pixmap, state: State, shape: &Shape) -> Opt
                 &mut pixmap,
                 gradient_map,
             );
+            paint.anti_alias &= *anti_alias;
             let stroke = sk::Stroke {
                 width,
                 line_cap: to_sk_line_cap(*cap),
@@ -170,7 +216,7 @@ pub fn to_sk_line_join(join: LineJoin) -> sk::LineJoin {
     }
 }
 
-pub fn to_sk_dash_pattern(dash: &DashPattern<Abs, Abs>) -> Option<sk::StrokeDash> {
+pub fn to_sk_dash_pattern(dash: &DashPattern<Abs, Abs, Abs>) -> Option<sk::StrokeDash> {
     // tiny-skia only allows dash patterns with an even number of elements,
     // while pdf allows any number.
     let pattern_len = dash.array.len();
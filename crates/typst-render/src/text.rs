@@ -86,13 +86,26 @@ fn render_outline_glyph(
         );
         canvas.fill_path(&path, &paint, rule, ts, state.mask);
 
-        if let Some(FixedStroke { paint, thickness, cap, join, dash, miter_limit }) =
-            &text.stroke
-            && thickness.to_f32() > 0.0
+        if let Some(FixedStroke {
+            paint,
+            thickness,
+            hairline,
+            cap,
+            join,
+            dash,
+            miter_limit,
+            anti_alias,
+            ..
+        }) = &text.stroke
+            && (thickness.to_f32() > 0.0 || *hairline)
         {
-            let dash = dash.as_ref().and_then(shape::to_sk_dash_pattern);
+            // Relative dash lengths are rejected for text strokes, as there
+            // is no meaningful path length for glyph outlines.
+            let dash = dash
+                .as_ref()
+                .and_then(|dash| shape::to_sk_dash_pattern(&dash.along(Abs::zero())));
 
-            let paint = paint::to_sk_paint(
+            let mut paint = paint::to_sk_paint(
                 paint,
                 state_ts,
                 Size::zero(),
@@ -101,6 +114,7 @@ fn render_outline_glyph(
                 &mut pixmap,
                 None,
             );
+            paint.anti_alias &= *anti_alias;
             let stroke = sk::Stroke {
                 width: thickness.to_f32() / scale, // When we scale the path, we need to scale the stroke width, too.
                 line_cap: shape::to_sk_line_cap(*cap),
@@ -4,7 +4,7 @@ use base64::Engine;
 use ecow::{EcoString, eco_format};
 use hayro::{FontData, FontQuery, InterpreterSettings, StandardFont};
 use image::{ImageEncoder, codecs::png::PngEncoder};
-use typst_library::foundations::Smart;
+use typst_library::foundations::{Bytes, Smart};
 use typst_library::layout::{Abs, Axes};
 use typst_library::visualize::{
     ExchangeFormat, Image, ImageKind, ImageScaling, PdfImage, RasterFormat,
@@ -54,39 +54,43 @@ pub fn convert_image_scaling(scaling: Smart<ImageScaling>) -> Option<&'static st
 /// `data:image/{format};base64,`.
 #[comemo::memoize]
 pub fn convert_image_to_base64_url(image: &Image) -> EcoString {
-    let (mut buf, strbuf);
-    let (format, data): (&str, &[u8]) = match image.kind() {
+    let (format, data) = convert_image_to_bytes(image);
+    let mut url = eco_format!("data:image/{format};base64,");
+    let data = base64::engine::general_purpose::STANDARD.encode(data);
+    url.push_str(&data);
+    url
+}
+
+/// Encode an image into a file format that browsers can display. Returns the
+/// MIME subtype of the format (e.g. `png` or `svg+xml`) and the file's data.
+#[comemo::memoize]
+pub fn convert_image_to_bytes(image: &Image) -> (&'static str, Bytes) {
+    match image.kind() {
         ImageKind::Raster(raster) => match raster.format() {
-            RasterFormat::Exchange(format) => (
+            // If a later frame of an animated image is selected, the raw data
+            // doesn't match, so we re-encode the frame below.
+            RasterFormat::Exchange(format) if raster.frame() == 0 => (
                 match format {
                     ExchangeFormat::Png => "png",
                     ExchangeFormat::Jpg => "jpeg",
                     ExchangeFormat::Gif => "gif",
                     ExchangeFormat::Webp => "webp",
                 },
-                raster.data(),
+                raster.data().clone(),
             ),
-            RasterFormat::Pixel(_) => ("png", {
-                buf = vec![];
+            RasterFormat::Exchange(_) | RasterFormat::Pixel(_) => ("png", {
+                let mut buf = vec![];
                 let mut encoder = PngEncoder::new(&mut buf);
                 if let Some(icc_profile) = raster.icc() {
                     encoder.set_icc_profile(icc_profile.to_vec()).ok();
                 }
                 raster.dynamic().write_with_encoder(encoder).unwrap();
-                buf.as_slice()
+                Bytes::new(buf)
             }),
         },
-        ImageKind::Svg(svg) => ("svg+xml", svg.data()),
-        ImageKind::Pdf(pdf) => {
-            strbuf = pdf_to_svg(pdf);
-            ("svg+xml", strbuf.as_bytes())
-        }
-    };
-
-    let mut url = eco_format!("data:image/{format};base64,");
-    let data = base64::engine::general_purpose::STANDARD.encode(data);
-    url.push_str(&data);
-    url
+        ImageKind::Svg(svg) => ("svg+xml", svg.data().clone()),
+        ImageKind::Pdf(pdf) => ("svg+xml", Bytes::from_string(pdf_to_svg(pdf))),
+    }
 }
 
 // Keep this in sync with `typst-png`!
@@ -5,7 +5,9 @@ mod paint;
 mod shape;
 mod text;
 
-pub use image::{convert_image_scaling, convert_image_to_base64_url};
+pub use image::{
+    convert_image_scaling, convert_image_to_base64_url, convert_image_to_bytes,
+};
 use rustc_hash::FxHashMap;
 use typst_library::introspection::Introspector;
 use typst_library::model::Destination;
@@ -52,6 +54,8 @@ pub fn svg_html_frame(
     frame: &Frame,
     text_size: Abs,
     id: Option<&str>,
+    title: Option<&str>,
+    description: Option<&str>,
     link_points: &[(Point, EcoString)],
     introspector: &Introspector,
 ) -> String {
@@ -75,8 +79,31 @@ pub fn svg_html_frame(
                 frame.height() / text_size,
             ),
         );
+        if title.is_some() || description.is_some() {
+            xml.write_attribute("role", "img");
+        }
+        if let Some(id) = id {
+            if title.is_some() {
+                xml.write_attribute_fmt("aria-labelledby", format_args!("{id}-title"));
+            }
+            if description.is_some() {
+                xml.write_attribute_fmt("aria-describedby", format_args!("{id}-desc"));
+            }
+        }
     });
 
+    // The title must be the first child to act as the SVG's accessible name.
+    for (name, text) in [("title", title), ("desc", description)] {
+        if let Some(text) = text {
+            renderer.xml.start_element(name);
+            if let Some(id) = id {
+                renderer.xml.write_attribute_fmt("id", format_args!("{id}-{name}"));
+            }
+            renderer.xml.write_text(text);
+            renderer.xml.end_element();
+        }
+    }
+
     let state = State::new(frame.size(), Transform::identity());
     renderer.render_frame(&state, frame);
 
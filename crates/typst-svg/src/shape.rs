@@ -10,6 +10,41 @@ use crate::{SVGRenderer, State, SvgMatrix, SvgPathBuilder};
 impl SVGRenderer<'_> {
     /// Render a shape element.
     pub(super) fn render_shape(&mut self, state: &State, shape: &Shape) {
+        if let Some((fill, pieces)) = shape.split_along_path() {
+            if let Some(fill) = fill {
+                self.render_shape(state, &fill);
+            }
+            for piece in &pieces {
+                self.render_shape(state, piece);
+            }
+            return;
+        }
+
+        if let Some((fill, lines)) = shape.split_double() {
+            if let Some(fill) = fill {
+                self.render_shape(state, &fill);
+            }
+            for (ts, line) in &lines {
+                self.render_shape(&state.pre_concat(*ts), line);
+            }
+            return;
+        }
+
+        if let Some((fill, ts, stroke)) = shape.split_anchor() {
+            if let Some(fill) = fill {
+                self.render_shape(state, &fill);
+            }
+            self.render_shape(&state.pre_concat(ts), &stroke);
+            return;
+        }
+
+        if let Some((gaps, dashes)) = shape.split_gaps() {
+            self.render_shape(state, &gaps);
+            self.render_shape(state, &dashes);
+            return;
+        }
+
+        let geometry = shape.drawn_geometry();
         self.xml.start_element("path");
         self.xml.write_attribute("class", "typst-shape");
 
@@ -29,6 +64,7 @@ impl SVGRenderer<'_> {
                 stroke,
                 self.shape_fill_size(state, &stroke.paint, shape),
                 self.shape_paint_transform(state, &stroke.paint, shape),
+                geometry.length(),
             );
         }
 
@@ -36,7 +72,7 @@ impl SVGRenderer<'_> {
             self.xml.write_attribute("transform", &SvgMatrix(state.transform));
         }
 
-        let path = convert_geometry_to_path(&shape.geometry);
+        let path = convert_geometry_to_path(&geometry);
         self.xml.write_attribute("d", &path);
         self.xml.end_element();
     }
@@ -108,6 +144,7 @@ impl SVGRenderer<'_> {
         stroke: &FixedStroke,
         size: Size,
         fill_transform: Transform,
+        length: Abs,
     ) {
         match &stroke.paint {
             Paint::Solid(color) => self.xml.write_attribute("stroke", &color.encode()),
@@ -121,7 +158,12 @@ impl SVGRenderer<'_> {
             }
         }
 
-        self.xml.write_attribute("stroke-width", &stroke.thickness.to_pt());
+        if stroke.hairline {
+            self.xml.write_attribute("stroke-width", "1px");
+            self.xml.write_attribute("vector-effect", "non-scaling-stroke");
+        } else {
+            self.xml.write_attribute("stroke-width", &stroke.thickness.to_pt());
+        }
         self.xml.write_attribute(
             "stroke-linecap",
             match stroke.cap {
@@ -140,7 +182,13 @@ impl SVGRenderer<'_> {
         );
         self.xml
             .write_attribute("stroke-miterlimit", &stroke.miter_limit.get());
+        if !stroke.anti_alias {
+            // SVG has no way to only affect the stroke, so a fill of the same
+            // shape is drawn without anti-aliasing, too.
+            self.xml.write_attribute("shape-rendering", "crispEdges");
+        }
         if let Some(dash) = &stroke.dash {
+            let dash = dash.along(length);
             self.xml.write_attribute("stroke-dashoffset", &dash.phase.to_pt());
             self.xml.write_attribute(
                 "stroke-dasharray",
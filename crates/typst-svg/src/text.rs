@@ -211,6 +211,7 @@ impl SVGRenderer<'_> {
                 stroke,
                 Size::new(Abs::pt(width), Abs::pt(height)),
                 self.text_paint_transform(state, &stroke.paint),
+                Abs::zero(),
             );
         }
         self.xml.end_element();
@@ -109,6 +109,26 @@
 #test((1, 2, 3).at(2, default: 5), 3)
 #test((1, 2, 3).at(3, default: 5), 5)
 
+--- array-at-range ---
+// Test extracting subslices with `at`.
+#test((1, 2, 3, 4).at((1, 3)), (2, 3))
+#test((1, 2, 3, 4).at((-3, -1)), (2, 3))
+#test((1, 2, 3, 4).at((2, none)), (3, 4))
+#test((1, 2, 3, 4).at((3, 1)), ())
+#test((1, 2, 3).at((2, 5), default: none), none)
+
+--- array-at-range-out-of-bounds ---
+// Error: 2-22 array index out of bounds (index: 5, len: 3) and no default value was specified
+#(1, 2, 3).at((1, 5))
+
+--- array-at-range-invalid ---
+// Error: 15-24 array must contain exactly two entries
+#(1, 2, 3).at((1, 2, 3))
+
+--- array-at-range-invalid-end ---
+// Error: 15-23 expected integer or none, found string
+#(1, 2, 3).at((1, "a"))
+
 --- array-remove-with-default ---
 // Test remove with default value.
 
@@ -212,6 +232,49 @@
   test(array, (0, 2, 3, 4, 5))
 }
 
+--- array-move-item ---
+// Test the `move-item` method.
+#{
+  let array = (0, 1, 2, 3, 4)
+  array.move-item(1, 3)
+  test(array, (0, 2, 3, 1, 4))
+  array.move-item(3, 0)
+  test(array, (1, 0, 2, 3, 4))
+  array.move-item(-1, 1)
+  test(array, (1, 4, 0, 2, 3))
+  array.move-item(2, 2)
+  test(array, (1, 4, 0, 2, 3))
+}
+
+--- array-move-item-out-of-bounds ---
+// Error: 2:2-2:23 array index out of bounds (index: 3, len: 3)
+#let array = (1, 2, 3)
+#array.move-item(0, 3)
+
+--- array-swap ---
+// Test the `swap` method.
+#{
+  let array = (1, 2, 3)
+  array.swap(0, 2)
+  test(array, (3, 2, 1))
+  array.swap(-1, 1)
+  test(array, (3, 1, 2))
+  array.swap(-3, 0)
+  test(array, (3, 1, 2))
+  array.swap(1, 1)
+  test(array, (3, 1, 2))
+}
+
+--- array-swap-out-of-bounds ---
+// Error: 2:2-2:18 array index out of bounds (index: 3, len: 3)
+#let array = (1, 2, 3)
+#array.swap(0, 3)
+
+--- array-swap-out-of-bounds-negative ---
+// Error: 2:2-2:19 array index out of bounds (index: -4, len: 3)
+#let array = (1, 2, 3)
+#array.swap(-4, 0)
+
 --- array-insert-missing-index ---
 // Error: 2:2-2:18 missing argument: index
 #let numbers = ()
@@ -248,17 +311,75 @@
 #test(("Bye", "💘", "Apart").position(s => s == "❤️"), none)
 #test(("A", "B", "CDEF", "G").position(v => v.len() > 2), 2)
 
+--- array-find-last ---
+// Test the `find-last` method.
+#test((1, 2, 3, 4).find-last(calc.even), 4)
+#test((1, 2, 3, 4).find-last(x => x < 3), 2)
+#test((1, 3, 5).find-last(calc.even), none)
+#test(().find-last(x => true), none)
+
+--- array-find-last-not-bool ---
+// Error: 19-25 expected boolean, found integer
+#(1, 2).find-last(x => x)
+
+--- array-index-of ---
+// Test the `index-of` method.
+#test((1, 2, 3, 2).index-of(2), 1)
+#test((1, 2, 3, 2).index-of(2, start: 2), 3)
+#test((1, 2, 3, 2).index-of(2, start: -1), 3)
+#test((1, 2, 3).index-of(2, start: 3), none)
+#test((1, 2, 3).index-of(4), none)
+#test((1, 2.0, "a").index-of(2), 1)
+#test(().index-of(1), none)
+
+--- array-index-of-out-of-bounds ---
+// Error: 2-33 array index out of bounds (index: 4, len: 3)
+#(1, 2, 3).index-of(1, start: 4)
+
 --- array-filter ---
 // Test the `filter` method.
 #test(().filter(calc.even), ())
 #test((1, 2, 3, 4).filter(calc.even), (2, 4))
 #test((7, 3, 2, 5, 1).filter(x => x < 5), (3, 2, 1))
 
+--- array-take-while ---
+#test(().take-while(x => true), ())
+#test((1, 2, 3, 1).take-while(x => x < 3), (1, 2))
+#test((1, 2, 3).take-while(x => x < 5), (1, 2, 3))
+#test((5, 1, 2).take-while(x => x < 3), ())
+#test(("a", "b", "", "c").take-while(s => s != ""), ("a", "b"))
+
+--- array-take-while-not-bool ---
+// Error: 20-26 expected boolean, found integer
+#(1, 2).take-while(x => x)
+
+--- array-drop-while ---
+#test(().drop-while(x => true), ())
+#test((1, 2, 3, 1).drop-while(x => x < 3), (3, 1))
+#test((1, 2, 3).drop-while(x => x < 5), ())
+#test((5, 1, 2).drop-while(x => x < 3), (5, 1, 2))
+#test((0, 0, 1, 0).drop-while(x => x == 0), (1, 0))
+
+--- array-drop-while-not-bool ---
+// Error: 20-30 expected boolean, found string
+#(1, 2).drop-while(x => "yes")
+
 --- array-map ---
 // Test the `map` method.
 #test(().map(x => x * 2), ())
 #test((2, 3).map(x => x * 2), (4, 6))
 
+--- array-flat-map ---
+// Test the `flat-map` method.
+#test(().flat-map(x => (x, x)), ())
+#test((1, 2).flat-map(n => (n, n * 10)), (1, 10, 2, 20))
+#test((1, 2, 3).flat-map(n => if n == 2 { () } else { n }), (1, 3))
+#test((1, 2).flat-map(n => ((n,),)), ((1,), (2,)))
+
+--- array-flat-map-error ---
+// Error: 23-30 cannot add integer and string
+#(1, 2).flat-map(n => n + "a")
+
 --- array-fold ---
 // Test the `fold` method.
 #test(().fold("hi", grid), "hi")
@@ -278,6 +399,20 @@
 // Error: 2-10 cannot calculate sum of empty array with no default
 #().sum()
 
+--- array-accumulate ---
+// Test the `accumulate` method.
+#test(().accumulate(), ())
+#test((5,).accumulate(), (5,))
+#test((1, 2, 3, 4).accumulate(), (1, 3, 6, 10))
+#test((1pt, 2pt).accumulate(), (1pt, 3pt))
+#test(("a", "b", "c").accumulate(), ("a", "ab", "abc"))
+#test((3, 1, 4, 1, 5).accumulate(op: calc.max), (3, 3, 4, 4, 5))
+#test((1, 2, 3, 4).accumulate(op: (acc, x) => acc * x), (1, 2, 6, 24))
+
+--- array-accumulate-bad-values ---
+// Error: 2-23 cannot add integer and string
+#(1, "a").accumulate()
+
 --- array-product ---
 // Test the `product` method.
 #test(().product(default: 0), 0)
@@ -289,10 +424,42 @@
 // Error: 2-14 cannot calculate product of empty array with no default
 #().product()
 
+--- array-average ---
+// Test the `average` method.
+#test(().average(default: 0), 0)
+#test((2, 4, 6).average(), 4)
+#test((1, 2).average(), 1.5)
+#test((1cm, 3cm).average(), 2cm)
+#test((1, 2, 3, 4).average(key: x => x * x), 7.5)
+#test(("a", "bcd").average(key: str.len), 2)
+
+--- array-average-empty ---
+// Error: 2-13 cannot calculate average of empty array with no default
+#().average()
+
+--- array-average-not-divisible ---
+// Error: 2-21 cannot divide string by integer
+#("a", "b").average()
+
 --- array-rev ---
 // Test the `rev` method.
 #test(range(3).rev(), (2, 1, 0))
 
+--- array-shuffle ---
+// Test the `shuffle` method.
+#test(().shuffle(), ())
+#test((1,).shuffle(seed: 5), (1,))
+#test(range(10).shuffle(seed: 42), (8, 3, 6, 5, 4, 0, 9, 2, 1, 7))
+#test(range(5).shuffle(), (2, 3, 0, 1, 4))
+#test(range(5).shuffle(), range(5).shuffle(seed: 0))
+#test(range(5).shuffle(seed: 1), (1, 0, 3, 4, 2))
+#test(range(100).shuffle(seed: 7), range(100).shuffle(seed: 7))
+#test(range(100).shuffle(seed: 7).sorted(), range(100))
+
+--- array-shuffle-negative-seed ---
+// Error: 23-25 number must be at least zero
+#(1, 2).shuffle(seed: -1)
+
 --- array-join ---
 // Test the `join` method.
 #test(().join(), none)
@@ -305,6 +472,18 @@
 #test(("hello",).join(default: "EMPTY", ", "), "hello")
 #test(("hello", "world").join(default: "EMPTY", ", "), "hello, world")
 
+--- array-join-separators ---
+// Test joining with a different separator between each pair of items.
+#test(("a", "b", "c", "d").join(separators: ("-", "+", "*")), "a-b+c*d")
+#test(("a", "b", "c", "d").join(separators: ("-", "+")), "a-b+c+d")
+#test(("a", "b", "c", "d").join(", ", separators: ("-",)), "a-b, c, d")
+#test(("a", "b", "c", "d").join(separators: ("-", "+"), last: " & "), "a-b+c & d")
+#test(("a", "b").join(separators: ("-", "+"), last: " & "), "a & b")
+#test(("a", "b", "c").join(", ", separators: ()), "a, b, c")
+#test(("a", "b", "c").join(separators: ()), "abc")
+#test(("a",).join(separators: ("-",)), "a")
+#test(("usr", "local", "bin").join(separators: ("/",)), "usr/local/bin")
+
 --- array-join-bad-values ---
 // Error: 2-22 cannot join boolean with boolean
 #(true, false).join()
@@ -324,6 +503,57 @@
 #test((1, 2).intersperse("a"), (1, "a", 2))
 #test((1, 2, "b").intersperse("a"), (1, "a", 2, "a", "b"))
 
+--- array-interleave ---
+// Test the `interleave` method.
+#test(().interleave(), ())
+#test((1, 2).interleave(), (1, 2))
+#test((1, 2).interleave((10, 20)), (1, 10, 2, 20))
+#test((1, 2).interleave((10, 20), ("a", "b")), (1, 10, "a", 2, 20, "b"))
+#test((1, 2, 3).interleave((10,)), (1, 10))
+#test((1,).interleave((10, 20, 30)), (1, 10))
+#test((1, 2, 3).interleave((10, 20), ("a",)), (1, 10, "a"))
+#test((1, 2).interleave(()), ())
+#test((1, 2, 3).interleave((10,), exhaustive: true), (1, 10, 2, 3))
+#test((1,).interleave((), (10, 20), exhaustive: true), (1, 10, 20))
+
+--- array-interleave-not-array ---
+// Error: 20-21 expected array, found integer
+#(1, 2).interleave(3)
+
+--- array-product-with ---
+// Test the `product-with` method.
+#test((1, 2).product-with(("a", "b")), ((1, "a"), (1, "b"), (2, "a"), (2, "b")))
+#test((1, 2).product-with(), ((1,), (2,)))
+#test((1, 2).product-with(()), ())
+#test(().product-with((1, 2)), ())
+#test(
+  (1, 2).product-with(("a",), (true, false)),
+  ((1, "a", true), (1, "a", false), (2, "a", true), (2, "a", false)),
+)
+#test(range(3).product-with(range(4), range(5)).len(), 60)
+
+--- array-product-with-too-many ---
+// Error: 2-39 cartesian product has too many combinations
+// Hint: 2-39 at most 1000000 combinations are supported
+#range(1001).product-with(range(1000))
+
+--- array-product-with-not-array ---
+// Error: 22-23 expected array, found integer
+#(1, 2).product-with(3)
+
+--- array-pad ---
+// Test the `pad-start` and `pad-end` methods.
+#test((1, 2).pad-end(4, 0), (1, 2, 0, 0))
+#test((1, 2).pad-start(4, 0), (0, 0, 1, 2))
+#test((1, 2, 3).pad-end(2, 0), (1, 2, 3))
+#test((1, 2, 3).pad-start(3, none), (1, 2, 3))
+#test(().pad-end(2, "a"), ("a", "a"))
+#test(().pad-start(0, "a"), ())
+
+--- array-pad-negative ---
+// Error: 17-19 number must be at least zero
+#(1, 2).pad-end(-1, 0)
+
 --- array-chunks ---
 // Test the `chunks` method.
 #test(().chunks(10), ())
@@ -344,6 +574,31 @@
 // Error: 19-21 number must be positive
 #(1, 2, 3).chunks(-5)
 
+--- array-split-into ---
+// Test the `split-into` method.
+#test(().split-into(2), ((), ()))
+#test((1, 2, 3).split-into(1), ((1, 2, 3),))
+#test((1, 2, 3, 4, 5).split-into(2), ((1, 2, 3), (4, 5)))
+#test((1, 2, 3, 4, 5, 6).split-into(3), ((1, 2), (3, 4), (5, 6)))
+#test((1, 2, 3, 4, 5, 6, 7).split-into(3), ((1, 2, 3), (4, 5), (6, 7)))
+#test((1, 2).split-into(3), ((1,), (2,), ()))
+
+--- array-split-into-zero ---
+// Error: 23-24 number must be positive
+#(1, 2, 3).split-into(0)
+
+--- array-chunks-by ---
+#test(().chunks-by(x => x), ())
+#test((1,).chunks-by(x => x), ((1,),))
+#test((1, 1, 2, 3, 3).chunks-by(x => x), ((1, 1), (2,), (3, 3)))
+#test((1, 3, 2, 4, 5).chunks-by(calc.odd), ((1, 3), (2, 4), (5,)))
+#test((1, 1.0, 2).chunks-by(x => x), ((1, 1.0), (2,)))
+#test(("a", "b", "A").chunks-by(lower), (("a",), ("b",), ("A",)))
+
+--- array-chunks-by-missing-key ---
+// Error: 2-23 missing argument: key
+#(1, 2, 3).chunks-by()
+
 --- array-windows ---
 // Test the `windows` method.
 #test(().windows(5), ())
@@ -351,6 +606,37 @@
 #test((1, 2, 3, 4, 5).windows(3), ((1, 2, 3), (2, 3, 4), (3, 4, 5)))
 #test((1, 2, 3, 4, 5, 6, 7, 8).windows(5), ((1, 2, 3, 4, 5), (2, 3, 4, 5, 6), (3, 4, 5, 6, 7), (4, 5, 6, 7, 8)))
 
+--- array-window-reduce ---
+// Test the `window-reduce` method.
+#test(().window-reduce(2, w => w.sum()), ())
+#test((1, 2, 3).window-reduce(5, w => w.sum()), ())
+#test((1, 2, 3, 4, 5).window-reduce(2, w => w.sum()), (3, 5, 7, 9))
+#test((1, 2, 3, 4, 5).window-reduce(3, w => w.sum()), (6, 9, 12))
+#test((1, 2, 3).window-reduce(1, w => w), ((1,), (2,), (3,)))
+#test(("a", "b", "c").window-reduce(2, w => w.join()), ("ab", "bc"))
+
+--- array-window-reduce-size-zero ---
+// Error: 26-27 number must be positive
+#(1, 2, 3).window-reduce(0, w => w)
+
+--- array-window-reduce-error ---
+// Error: 33-50 cannot add integer and string
+#(1, "a").window-reduce(2, w => w.at(0) + w.at(1))
+
+--- array-step-by ---
+// Test the `step-by` method.
+#test(().step-by(2), ())
+#test((0, 1, 2, 3, 4, 5).step-by(2), (0, 2, 4))
+#test((0, 1, 2, 3, 4, 5).step-by(1), (0, 1, 2, 3, 4, 5))
+#test((0, 1, 2, 3, 4, 5).step-by(10), (0,))
+#test((0, 1, 2, 3, 4, 5).step-by(2, start: 1), (1, 3, 5))
+#test((0, 1, 2, 3, 4, 5).step-by(3, start: 2), (2, 5))
+#test((0, 1, 2).step-by(2, start: 3), ())
+
+--- array-step-by-zero ---
+// Error: 20-21 number must be positive
+#(1, 2, 3).step-by(0)
+
 --- array-windows-size-zero ---
 // Error: 20-21 number must be positive
 #(1, 2, 3).windows(0)
@@ -406,6 +692,19 @@
 // Error: 24-36 array has different length (4) from first array (2)
 #(1, 2).zip((1, 2, 3), (1, 2, 3, 4), exact: true)
 
+--- array-zip-fill ---
+// Test the `fill` argument of the `zip` method.
+#test((1, 2).zip((10,), fill: 0), ((1, 10), (2, 0)))
+#test((1,).zip((10, 20), fill: none), ((1, 10), (none, 20)))
+#test((1, 2).zip((10, 20), fill: 0), ((1, 10), (2, 20)))
+#test(().zip((1,), ("a", "b", "c"), fill: 0), ((0, 1, "a"), (0, 0, "b"), (0, 0, "c")))
+#test(().zip((), fill: 0), ())
+#test((1, 2).zip(fill: 0), ((1,), (2,)))
+
+--- array-zip-fill-exact ---
+// Error: 2-40 cannot use `exact` and `fill` at the same time
+#(1, 2).zip((1,), exact: true, fill: 0)
+
 --- array-enumerate ---
 // Test the `enumerate` method.
 #test(().enumerate(), ())
@@ -431,6 +730,75 @@
 #test(("Hello", "World", "Hi", "There").dedup(key: x => x.len()), ("Hello", "Hi"))
 #test(("Hello", "World", "Hi", "There").dedup(key: x => x.at(0)), ("Hello", "World", "There"))
 
+--- array-unique ---
+// Test the `unique` method.
+#test(().unique(), ())
+#test((1,).unique(), (1,))
+#test((1, 1).unique(), (1,))
+#test((1, 2, 1).unique(), (1, 2))
+#test(("Jane", "John", "Eric", "John").unique(), ("Jane", "John", "Eric"))
+#test((none, auto, none, true, auto, false, true).unique(), (none, auto, true, false))
+#test((<a>, <b>, <a>).unique(), (<a>, <b>))
+
+--- array-unique-key ---
+// Test the `unique` method with the `key` argument.
+#test((1, 2, 3, 4, 5, 6).unique(key: x => calc.rem(x, 3)), (1, 2, 3))
+#test(("Hello", "World", "Hi", "There").unique(key: x => x.at(0)), ("Hello", "World", "There"))
+
+--- array-unique-fallback ---
+// Keys that are equal across types fall back to pairwise comparison.
+#test((1, 1.0, 2, 2.0, 3.5).unique(), (1, 2, 3.5))
+#test((1pt, 1pt + 0%, 2pt).unique(), (1pt, 2pt))
+#test(((1, 2), (1, 2), (1,)).unique(), ((1, 2), (1,)))
+
+--- array-unique-large ---
+// Deduplicating large arrays stays fast.
+#let values = range(100000).map(i => str(calc.rem(i * 7919, 1000)))
+#test(values.unique().len(), 1000)
+#test(values.unique().slice(0, 3), ("0", "919", "838"))
+
+--- array-tally ---
+// Test the `tally` method.
+#test(().tally(), (:))
+#test(("a", "b", "a").tally(), (a: 2, b: 1))
+#test(("b", "a", "b", "c").tally().keys(), ("b", "a", "c"))
+#test((1, 2, 3, 4, 5).tally(key: x => if calc.even(x) { "even" } else { "odd" }), (odd: 3, even: 2))
+#test(("apple", "avocado", "banana").tally(key: s => s.first()), (a: 2, b: 1))
+
+--- array-tally-not-string ---
+// Error: 2-19 expected string, found integer
+#(1, 2, 1).tally()
+
+--- array-tally-key-not-string ---
+// Error: 20-30 expected string, found integer
+#(1, 2).tally(key: x => x * 2)
+
+--- array-ranked ---
+// Test the `ranked` method.
+#test(().ranked(), ())
+#test(("a", "b", "a", "c", "a", "b").ranked(), (("a", 3), ("b", 2), ("c", 1)))
+#test(("x", "y", "z", "y", "x").ranked(), (("x", 2), ("y", 2), ("z", 1)))
+#test((3, 1, 2).ranked(), ((3, 1), (1, 1), (2, 1)))
+#test((1, 1.0, 2.0, 2).ranked(), ((1, 2), (2.0, 2)))
+#test(((1, 2), (3,), (1, 2)).ranked(), (((1, 2), 2), ((3,), 1)))
+#test((1, 2, 3, 4, 5).ranked(key: calc.even), ((false, 3), (true, 2)))
+
+--- array-ranked-key-error ---
+// Error: 26-33 cannot add integer and string
+#(1, 2).ranked(key: x => x + "a")
+
+--- array-index-map ---
+// Test the `index-map` method.
+#test(().index-map(), (:))
+#test(("a", "b", "c").index-map(), (a: 0, b: 1, c: 2))
+#test(("b", "a", "b").index-map(), (b: 0, a: 1))
+#test(("b", "a", "b").index-map(last: true), (b: 2, a: 1))
+#test(("b", "a", "b").index-map().keys(), ("b", "a"))
+
+--- array-index-map-not-string ---
+// Error: 2-23 expected string, found integer
+#(1, 2, 1).index-map()
+
 --- array-to-dict ---
 // Test the `to-dict` method.
 #test(().to-dict(), (:))
@@ -473,6 +841,29 @@
 // Error: 42-52 unexpected argument
 #((k: "a", v: 2), (k: "b", v: 1)).sorted(it => it.v)
 
+--- array-is-sorted ---
+#test(().is-sorted(), true)
+#test((1,).is-sorted(), true)
+#test((1, 2, 3).is-sorted(), true)
+#test((1, 1, 2).is-sorted(), true)
+#test((1, 3, 2).is-sorted(), false)
+#test((3, 2, 1).is-sorted(), false)
+#test(("a", "b", "c").is-sorted(), true)
+#test((1, 2.5, 3).is-sorted(), true)
+#test(("ccc", "a", "bb").is-sorted(), false)
+#test(("a", "bb", "ccc").is-sorted(key: s => s.len()), true)
+#test((3, 2, 1).is-sorted(key: x => -x), true)
+#test(((1, "b"), (1, "c"), (2, "a")).is-sorted(), true)
+#test((5, 3, 8, 1).sorted().is-sorted(), true)
+
+--- array-is-sorted-uncomparable ---
+// Error: 2-29 cannot compare content and content
+#([Hi], [There]).is-sorted()
+
+--- array-is-sorted-bad-key ---
+// Error: 35-40 cannot divide by zero
+#(1, 2, 0, 3).is-sorted(key: x => 5 / x)
+
 --- issue-3014-mix-array-dictionary ---
 // Error: 8-17 expected expression, found named pair
 #(box, fill: red)
@@ -552,3 +943,15 @@
 --- array-reduce-unexpected-argument ---
 // Error: 19-21 unexpected argument
 #(1, 2, 3).reduce(() => none)
+
+--- array-reduce-right ---
+// Test the `reduce-right` method.
+#test(().reduce-right(grid), none)
+#test((1,).reduce-right((s, x) => s + x), 1)
+#test((1, 2, 3, 4).reduce-right((s, x) => s + x), 10)
+#test(("a", "b", "c").reduce-right((acc, x) => acc + x), "cba")
+#test((1, 2, 3).reduce-right((acc, x) => (acc, x)), ((3, 2), 1))
+
+--- array-reduce-right-unexpected-argument ---
+// Error: 25-27 unexpected argument
+#(1, 2, 3).reduce-right(() => none)
@@ -134,6 +134,14 @@
 #test("c" in dict, false)
 #test(dict, (a: 3, b: 1))
 
+--- dict-deep-len ---
+#test((:).deep-len(), 0)
+#test((a: 1, b: 2).deep-len(), 2)
+#test((a: 1, b: (c: 2, d: (e: 3, f: 4))).deep-len(), 4)
+#test((a: (b: (c: (d: 1)))).len(), 1)
+#test((a: (b: (c: (d: 1)))).deep-len(), 1)
+#test((a: (:), b: (1, 2, (c: 3))).deep-len(), 1)
+
 --- dict-from-module ---
 // Test dictionary constructor
 #test(type(dictionary(sys).at("version")), version)
@@ -173,6 +181,188 @@
   test(dict.keys(), ("a", "b", "c", "d"))
 }
 
+--- dict-pairs-sorted ---
+#test((:).pairs-sorted(), ())
+#test((b: 2, c: 3, a: 1).pairs-sorted(), (("a", 1), ("b", 2), ("c", 3)))
+#test(("b": 1, "B": 2, "ab": 3, "a": 4).pairs-sorted().map(p => p.first()), ("B", "a", "ab", "b"))
+#test((ab: 1, c: 2, def: 3).pairs-sorted(key: k => -k.len()), (("def", 3), ("ab", 1), ("c", 2)))
+
+--- dict-pairs-sorted-uncomparable ---
+// Error: 2-43 cannot compare content and content
+#(a: 1, b: 2).pairs-sorted(key: k => [#k])
+
+--- dict-sorted-by-value ---
+#test((:).sorted-by-value(), ())
+#test((a: 3, b: 1, c: 2).sorted-by-value(), (("b", 1), ("c", 2), ("a", 3)))
+#test((a: 1, b: 0, c: 1).sorted-by-value(), (("b", 0), ("a", 1), ("c", 1)))
+#test((a: "x", b: "abc", c: "yz").sorted-by-value(key: str.len), (("a", "x"), ("c", "yz"), ("b", "abc")))
+#test((a: 3, b: 1, c: 2).sorted-by-value(key: v => -v).map(p => p.first()), ("a", "c", "b"))
+
+--- dict-sorted-by-value-uncomparable ---
+// Error: 2-46 cannot compare content and content
+#(a: 1, b: 2).sorted-by-value(key: v => [#v])
+
+--- dict-zip ---
+#test((:).zip((:)), (:))
+#test((a: 1).zip((:)), (:))
+#test((a: 1, b: 2).zip((b: 3, a: 4)), (a: (1, 4), b: (2, 3)))
+#test((a: 1, b: 2).zip((b: 3, c: 4)), (b: (2, 3)))
+#test((a: 1, b: 2).zip((b: 3, c: 4), fill: true), (a: (1, none), b: (2, 3), c: (none, 4)))
+#test((b: 1, a: 2).zip((a: 3, b: 4)).keys(), ("b", "a"))
+#test((a: 1).zip((c: 2, b: 3), fill: true).keys(), ("a", "c", "b"))
+
+--- dict-rename-keys ---
+#test((:).rename-keys((a: "b")), (:))
+#test((a: 1, b: 2).rename-keys((:)), (a: 1, b: 2))
+#test((a: 1, b: 2, c: 3).rename-keys((b: "x")), (a: 1, x: 2, c: 3))
+#test((a: 1, b: 2, c: 3).rename-keys((b: "x")).keys(), ("a", "x", "c"))
+#test((a: 1, b: 2).rename-keys((a: "b", b: "a")), (b: 1, a: 2))
+#test((a: 1).rename-keys((z: "y")), (a: 1))
+#test(("First Name": "Ada").rename-keys(("First Name": "first")), (first: "Ada"))
+
+--- dict-rename-keys-collision ---
+// Error: 2-36 duplicate key "b" after renaming
+#(a: 1, b: 2).rename-keys((a: "b"))
+
+--- dict-rename-keys-not-string ---
+// Error: 2-28 expected string as new key for "a", found integer
+#(a: 1).rename-keys((a: 2))
+
+--- dict-with-defaults ---
+#test((a: 1).with-defaults((a: 9, b: 2)), (a: 1, b: 2))
+#test((:).with-defaults((a: 1)), (a: 1))
+#test((a: 1).with-defaults((:)), (a: 1))
+#test((b: 1).with-defaults((a: 2, b: 3, c: 4)).keys(), ("b", "a", "c"))
+#test((a: (x: 1)).with-defaults((a: (y: 2))), (a: (x: 1)))
+#test((a: none).with-defaults((a: 1)), (a: none))
+
+--- dict-compact ---
+#test((a: 1, b: none, c: 3).compact(), (a: 1, c: 3))
+#test((a: none, b: none).compact(), (:))
+#test((:).compact(), (:))
+#test((a: 1, b: auto).compact(), (a: 1, b: auto))
+#test((c: 1, b: none, a: 2).compact().keys(), ("c", "a"))
+#test((a: (b: none)).compact(), (a: (b: none)))
+#test((a: "", b: 0, c: false).compact().len(), 3)
+
+--- dict-invert ---
+#test((:).invert(), (:))
+#test((a: "x", b: "y").invert(), (x: "a", y: "b"))
+#test((a: "x", b: "y", c: "x").invert(), (x: "c", y: "b"))
+#test((a: "x", b: "y", c: "x").invert().keys(), ("x", "y"))
+#test((a: "x", b: "y").invert(unique: true), (x: "a", y: "b"))
+#test((a: "b", b: "a").invert().invert(), (a: "b", b: "a"))
+
+--- dict-invert-duplicate ---
+// Error: 2-47 keys "a" and "c" have the same value "x"
+#(a: "x", b: "y", c: "x").invert(unique: true)
+
+--- dict-invert-not-string ---
+// Error: 2-25 expected string as value for "b", found integer
+#(a: "x", b: 1).invert()
+
+--- dict-group-by ---
+#let stock = (apple: 3, kiwi: 0, pear: 2, plum: 0)
+#test(
+  stock.group-by((k, v) => if v > 0 { "available" } else { "sold-out" }),
+  (available: (apple: 3, pear: 2), sold-out: (kiwi: 0, plum: 0)),
+)
+#test(
+  stock.group-by((k, v) => k.first()).keys(),
+  ("a", "k", "p"),
+)
+#test(stock.group-by((k, v) => k.first()).p, (pear: 2, plum: 0))
+#test((:).group-by((k, v) => k), (:))
+
+--- dict-group-by-not-string ---
+// Error: 24-35 expected string, found integer
+#(a: 1, b: 2).group-by((k, v) => v)
+
+--- dict-retain ---
+#{
+  let stock = (apple: 3, kiwi: 0, pear: 2, plum: 0, fig: 1)
+  stock.retain((k, v) => v > 0)
+  test(stock, (apple: 3, pear: 2, fig: 1))
+  test(stock.keys(), ("apple", "pear", "fig"))
+  stock.retain((k, v) => k != "pear")
+  test(stock.keys(), ("apple", "fig"))
+  stock.retain((k, v) => false)
+  test(stock, (:))
+}
+
+--- dict-retain-nested ---
+#{
+  let data = (inner: (a: 1, b: 2, c: 3))
+  data.inner.retain((k, v) => calc.odd(v))
+  test(data, (inner: (a: 1, c: 3)))
+  data.at("inner").retain((k, v) => k != "a")
+  test(data, (inner: (c: 3)))
+}
+
+--- dict-retain-not-bool ---
+#let stock = (apple: 3)
+// Error: 15-26 expected boolean, found integer
+#stock.retain((k, v) => v)
+
+--- dict-update ---
+#{
+  let counts = (apple: 1)
+  counts.update("apple", v => v + 1)
+  test(counts, (apple: 2))
+  counts.update("pear", v => v + 1, default: 0)
+  test(counts, (apple: 2, pear: 1))
+  counts.update("apple", v => str(v))
+  test(counts.keys(), ("apple", "pear"))
+  test(counts.apple, "2")
+}
+
+--- dict-update-nested ---
+#{
+  let data = (inner: (hits: 1))
+  data.inner.update("hits", v => v * 10)
+  test(data, (inner: (hits: 10)))
+}
+
+--- dict-update-accessor ---
+#{
+  let data = (inner: (hits: 1), list: ((hits: 2),))
+  data.at("inner").update("hits", v => v * 10)
+  data.list.at(0).update("hits", v => v + 1)
+  test(data, (inner: (hits: 10), list: ((hits: 3),)))
+}
+
+--- dict-update-temporary ---
+// Error: 2:3-2:9 cannot mutate a temporary value
+#{
+  (a: 1).update("a", v => v + 1)
+}
+
+--- dict-update-counter-accessor ---
+// Counters in dictionaries keep their own `update` method, even if they are
+// only reachable through an accessor.
+#let counters = (list: (counter("dict-update-accessor"),))
+#let bump() = counters.list.at(0).update(n => n + 2)
+#bump()
+#context test(counters.list.at(0).get(), (2,))
+
+--- dict-update-counter ---
+// Counters and states still use their own `update` method.
+#let c = counter("dict-update")
+#c.update(3)
+#context test(c.get(), (3,))
+
+--- dict-update-captured-state ---
+// Captured states are read-only bindings, but can still be updated.
+#let s = state("dict-update", 0)
+#let bump() = s.update(v => v + 1)
+#bump()
+#context test(s.get(), 1)
+
+--- dict-update-missing-key ---
+#let stock = (apple: 3)
+// Error: 2-34 dictionary does not contain key "pear" and no default value was specified
+#stock.update("pear", v => v + 1)
+
 --- dict-temporary-lvalue ---
 // Error: 3-15 cannot mutate a temporary value
 #((key: "val").other = "some")
@@ -63,6 +63,12 @@ sequence(
 // Colors and strokes.
 #t(rgb("f7a205"), `rgb("#f7a205")`)
 #t(2pt + rgb("f7a205"), `2pt + rgb("#f7a205")`)
+#t(2mm + red, `2mm + rgb("#ff4136")`)
+#t(stroke(thickness: 1.5cm), `1.5cm`)
+#t(stroke(thickness: 15mm), `15mm`)
+#t(stroke(thickness: 1in), `1in`)
+#t(stroke(2mm + 1em), `2mm + 1em`)
+#t(stroke(paint: red, thickness: 2mm, cap: "round"), `(paint: rgb("#ff4136"), thickness: 2mm, cap: "round")`)
 #t(blue, `rgb("#0074d9")`)
 #t(color.linear-rgb(blue), `color.linear-rgb(0%, 17.46%, 69.39%)`)
 #t(oklab(blue), `oklab(56.22%, -0.05, -0.17)`)
@@ -89,3 +95,27 @@ sequence(
   gradient.linear(blue, red, space: rgb, angle: 45deg),
   `gradient.linear(angle: 45deg, space: rgb, (rgb("#0074d9"), 0%), (rgb("#ff4136"), 100%))`,
 )
+
+--- repr-stroke-round-trip ---
+// Stroke lengths keep the unit they were written in and round-trip through
+// `eval`. Plain lengths are still displayed in points.
+#for length in (2pt, 2mm, 1cm, 1.5cm, 15mm, 1in, 2mm + 1em) {
+  test(stroke(eval(repr(stroke(length)))), stroke(length))
+}
+#test(repr(stroke(1.5cm)), "1.5cm")
+#test(repr(stroke(1in + blue)), "1in + rgb(\"#0074d9\")")
+#test(repr(stroke(2 * 1.5cm)), "3cm")
+#test(repr(stroke(1cm + 1cm)), "2cm")
+#test(repr(2mm), "5.67pt")
+#test(eval(repr(2mm + red)), 2mm + red)
+#test(
+  repr(stroke(join: "round", join-radius: 1.5mm, double: true, double-gap: 1cm)),
+  "(join: \"round\", join-radius: 1.5mm, double: true, double-gap: 1cm)",
+)
+
+--- repr-stroke-mixed-units ---
+// Lengths that were written in different units are displayed in points.
+#test(repr(stroke(2mm + 1pt)), "6.67pt")
+#test(repr(stroke(1cm + 5mm)), "42.52pt")
+#test(repr(stroke(1cm + 1em)), "1cm + 1em")
+#test(repr(stroke(1cm / 3)), "9.45pt")
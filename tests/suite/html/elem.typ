@@ -20,6 +20,15 @@ Text
 #html.elem("multi-word-component")[Hi]
 #html.elem("element-")[Hi]
 
+--- html-elem-display html ---
+// Custom elements are block-level by default, but can take part in
+// paragraphs. Conversely, inline elements can be forced to break paragraphs.
+#html.elem("my-badge", display: "inline")[New] release notes.
+
+Before #html.elem("span", display: "block")[Block span] after.
+
+#html.elem("span", display: auto)[Auto span] text.
+
 --- html-elem-invalid ---
 // Error: 12-24 the character "@" is not valid in a tag name
 #html.elem("my@element")
@@ -35,3 +44,105 @@ Text
 --- html-elem-custom-reserved html ---
 // Error: 12-28 name is reserved and not valid for a custom element
 #html.elem("annotation-xml")
+
+--- html-elem-template html ---
+#html.elem("template")[
+  #html.elem("div", attrs: (class: "card"))[
+    #html.elem("slot", attrs: (name: "title"))
+  ]
+  Fallback _text_
+]
+
+--- html-elem-foreign html ---
+// Inline SVG and MathML keep camel-case names and use self-closing tags.
+Icon: #html.elem("svg", attrs: (viewBox: "0 0 10 10", preserveAspectRatio: "xMidYMid meet"))[
+  #html.elem("circle", attrs: (cx: "5", cy: "5", r: "4"))
+  #html.elem("text", attrs: (x: "1", y: "9"))[A & B]
+  #html.elem("foreignObject", attrs: (width: "10", height: "10"))[
+    #html.elem("div")[Hi]
+  ]
+]
+
+Formula: #html.elem("math")[#html.elem("mi")[x]#html.elem("mspace", attrs: (width: "1em"))]
+
+--- html-elem-attrs-fold html ---
+// Attributes from set rules and the element are merged in the order they were
+// added, without duplicate classes.
+#show html.elem.where(tag: "div"): set html.elem(attrs: (class: "card"))
+#show html.elem.where(tag: "div"): set html.elem(attrs: (class: "wide card", title: "Box"))
+#html.elem("div", attrs: (class: "note card"))[Hello]
+
+--- html-elem-attrs-id-conflict html ---
+#show html.elem.where(tag: "div"): set html.elem(attrs: (id: "styled"))
+// Warning: 2-45 element has conflicting `id` attributes
+// Hint: 2-45 the explicitly given id `own` takes precedence over `styled`
+#html.elem("div", attrs: (id: "own"))[Hello]
+
+--- html-elem-attr-quotes html ---
+#html.elem("div", attrs: (
+  title: "it's",
+  data-config: "{\"key\": 1}",
+  data-mixed: "say \"it's\"",
+))[Quotes]
+
+--- html-elem-attr-bool html ---
+#html.elem("div", attrs: (hidden: true, inert: true))[Hidden]
+#html.elem("div", attrs: (hidden: false, inert: false, title: "Shown"))[Shown]
+#html.elem("span", attrs: (aria-hidden: "true", inert: true))[Decoration]
+#html.div(inert: true)[Typed]
+
+--- html-elem-attr-bool-invalid html ---
+// Error: 26-37 expected string or boolean, found integer
+#html.elem("div", attrs: (hidden: 1))
+
+--- html-elem-head-duplicates html ---
+// Warning: 3:5-3:49 duplicate `<meta charset>` element in `<head>`
+// Hint: 3:5-3:49 only the last one is kept
+// Warning: 4:5-4:71 duplicate `<meta name="viewport">` element in `<head>`
+// Hint: 4:5-4:71 only the last one is kept
+// Warning: 5:5-5:28 duplicate `<title>` element in `<head>`
+// Hint: 5:5-5:28 only the last one is kept
+#html.html({
+  html.head({
+    html.elem("meta", attrs: (charset: "ascii"))
+    html.elem("meta", attrs: (name: "viewport", content: "width=500"))
+    html.elem("title")[Old]
+    html.elem("meta", attrs: (charset: "utf-8"))
+    html.elem("meta", attrs: (name: "Viewport", content: "width=device-width"))
+    html.elem("title")[New]
+    html.elem("meta", attrs: (name: "description", content: "Kept"))
+  })
+  html.body[Hi]
+})
+
+--- html-elem-head-charset-inserted html ---
+#html.html({
+  html.head(html.elem("title")[Title])
+  html.body[Hi]
+})
+
+--- html-elem-head-charset-moved html ---
+#html.html({
+  html.head({
+    html.elem("meta", attrs: (name: "viewport", content: "width=device-width"))
+    html.elem("title")[Title]
+    html.elem("meta", attrs: (charset: "UTF-8"))
+  })
+  html.body[Hi]
+})
+
+--- html-elem-head-charset-unsupported html ---
+// Warning: 3:5-3:54 document declares unsupported character encoding `iso-8859-1`
+// Hint: 3:5-3:54 HTML export always produces UTF-8
+// Hint: 3:5-3:54 the declaration was replaced with `<meta charset="utf-8">`
+// Warning: 4:5-4:100 document declares unsupported character encoding `Shift_JIS`
+// Hint: 4:5-4:100 HTML export always produces UTF-8
+// Hint: 4:5-4:100 the declaration was replaced with `<meta charset="utf-8">`
+#html.html({
+  html.head({
+    html.elem("meta", attrs: (charset: "iso-8859-1"))
+    html.elem("meta", attrs: (http-equiv: "Content-Type", content: "text/html; charset=Shift_JIS"))
+    html.elem("title")[Title]
+  })
+  html.body[Hi]
+})
@@ -2,6 +2,11 @@
 A rectangle:
 #html.frame(rect())
 
+--- html-frame-title html ---
+#html.frame(title: "A box", description: "An empty rectangle.", rect())
+
+#html.frame(title: "Another box", rect())
+
 --- html-frame-in-layout ---
 // Ensure that HTML frames are transparent in layout. This is less important for
 // actual paged export than for _nested_ HTML frames, which take the same code
@@ -178,6 +178,13 @@ A#"  "B#"   C"
   ```.text,
 )
 
+--- html-script-json-ld html ---
+// JSON data is kept verbatim, except for closing tags, which are split up.
+#html.script(
+  type: "application/ld+json",
+  `{"@context": "https://schema.org", "name": "</script> & <b>"}`.text,
+)
+
 --- html-style html ---
 // This should be pretty and indented.
 #html.style(
@@ -160,6 +160,56 @@
 // Error: 19-50 `src` must not start or end with a comma
 #html.img(srcset: ((src: "img.png,", width: 50),))
 
+--- html-srcset ---
+#test(html.srcset((src: "a.png")), "a.png")
+#test(
+  html.srcset((src: "/a-480.png", width: 480), (src: "/a-960.png", width: 960)),
+  "/a-480.png 480w, /a-960.png 960w",
+)
+#test(
+  html.srcset((src: "a.png"), (src: "a@2x.png", density: 2), (src: "a@1.5x.png", density: 1.5)),
+  "a.png, a@2x.png 2x, a@1.5x.png 1.5x",
+)
+
+--- html-srcset-elem html ---
+#html.div(html.elem("img", attrs: (
+  src: "tiger.jpg",
+  srcset: html.srcset((src: "tiger.jpg", density: 1), (src: "tiger-hd.jpg", density: 2)),
+)))
+
+--- html-srcset-mixed-width ---
+// Error: 42-56 image candidate must specify a `width`
+// Hint: 42-56 if one candidate specifies a width, all of them must
+#html.srcset((src: "a.png", width: 100), (src: "b.png"))
+
+--- html-srcset-duplicate-density ---
+// Error: 42-70 duplicate image candidate descriptor
+#html.srcset((src: "a.png", density: 2), (src: "b.png", density: 2.0))
+
+--- html-srcset-duplicate-implicit-density ---
+// Error: 30-56 duplicate image candidate descriptor
+#html.srcset((src: "a.png"), (src: "b.png", density: 1))
+
+--- html-srcset-whitespace ---
+// Error: 14-47 `src` must not contain whitespace
+// Hint: 14-47 percent-encode spaces in URLs as `%20`
+#html.srcset((src: "my image.png", width: 100),)
+
+--- html-srcset-empty ---
+// Error: 2-15 expected at least one image candidate
+#html.srcset()
+
+--- html-preload html ---
+#html.preload("/fonts/body.woff2", "font")
+#html.preload("/style.css", "style")
+#html.preload("/app.js", "script", crossorigin: true)
+#html.preconnect("https://fonts.example.com", crossorigin: true)
+#html.preconnect("https://cdn.example.com")
+
+--- html-preload-invalid-destination html ---
+// Error: 26-38 expected "audio", "document", "embed", "fetch", "font", "image", "json", "object", "script", "style", "track", "video", or "worker"
+#html.preload("/app.js", "javascript")
+
 --- html-typed-sizes-string-size html ---
 // Error: 18-66 expected length, found string
 // Hint: 18-66 CSS lengths that are not expressible as Typst lengths are not yet supported
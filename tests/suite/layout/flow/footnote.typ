@@ -63,6 +63,12 @@ An "A" #footnote[A "B"]
 #show par: set smartquote(quotes: ("[", "]"))
 An "A" #footnote[A "B"]
 
+--- footnote-html-across-pagebreak html ---
+// Without pages, all footnotes are collected at the end of the document.
+A #footnote[One]
+#pagebreak()
+B #footnote[Two]
+
 --- footnote-break-across-pages ---
 #set page(height: 200pt)
 
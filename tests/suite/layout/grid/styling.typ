@@ -10,6 +10,19 @@
   [A], [B], [C], [], [], [D \ E \ F \ \ \ G], [H],
 )
 
+--- grid-fill-func-body ---
+// The cell's body is passed to functions with three parameters.
+// Error: 29-40 panicked with: [A]
+#grid(fill: (x, y, body) => panic(body), [A])
+
+--- grid-fill-func-body-empty-cell ---
+// Error: 57-68 panicked with: 1, 0
+#grid(columns: 2, fill: (x, y, body) => if body == [] { panic(x, y) }, [A])
+
+--- grid-fill-func-with-body ---
+// Error: 33-44 panicked with: [B]
+#grid(fill: ((a, x, y, body) => panic(body)).with(none), [B])
+
 --- grid-stroke-none ---
 #grid(columns: 3, stroke: none, fill: green, [A], [B], [C])
 
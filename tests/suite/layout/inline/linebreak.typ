@@ -163,3 +163,20 @@ pleasing way.
 --- linebreak-default-ignorables ---
 #set text(font: "Noto Sans Math")
 \u{2295}\u{FE00} vs \u{2295}\u{FE00}
+
+--- linebreak-html-poem html ---
+Roses are red, \
+violets are blue.
+
+Sugar is sweet, \
+and so are you. \
+
+--- linebreak-html-address html ---
+*Typst GmbH* \
+Example Street 1 \ \
+12345 Berlin
+
+--- linebreak-html-justify html ---
+// Warning: 4-28 justified line breaks are not supported in HTML export
+// Hint: 4-28 the line break is exported as a regular `<br>` element
+A #linebreak(justify: true) B
@@ -151,3 +151,28 @@ First
 #pagebreak(to: "odd")
 #pagebreak(weak: true)
 Odd
+
+--- pagebreak-html html ---
+A
+
+#pagebreak()
+
+B
+
+#pagebreak(weak: true)
+
+C
+
+--- pagebreak-html-sections html ---
+#set pagebreak(sections: true)
+A
+
+#pagebreak()
+
+B
+
+#pagebreak(weak: true)
+
+C
+
+#pagebreak()
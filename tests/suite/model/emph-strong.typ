@@ -72,3 +72,14 @@ Normal
 // Warning: 11-13 no text within stars
 // Hint: 11-13 using multiple consecutive stars (e.g. **) has no additional effect
 **not bold**
+
+--- emph-strong-html html ---
+// Strong and emphasized text turn into semantic elements rather than styled
+// spans.
+*Strong* and _emphasized_ text.
+
+*Strong with _nested emphasis_ inside.*
+
+_Emphasis with *nested strength* inside._
+
+*One* *Two* _A_ _B_ *_both_*
@@ -69,6 +69,16 @@ a + 0.
      + Deep
 + Normal
 
+--- enum-numbering-pattern-mixed-case ---
+// Each level keeps the case of its counting symbol, and levels beyond the
+// pattern reuse the last one.
+#set enum(numbering: "A.a.I.i")
++ First
+  + Second
+    + Third
+      + Fourth
+        + Fifth
+
 --- enum-numbering-full ---
 // Test full numbering.
 #set enum(numbering: "1.a.", full: true)
@@ -112,6 +122,15 @@ a + 0.
   [Ahead],
 )
 
+--- enum-html-numbering-nested html ---
+#set enum(numbering: "I.a.i.")
+#enum(
+  enum(enum[Deep], [Nested]),
+  enum.item(5)[Five],
+)
+#enum(numbering: "1)", start: 2, reversed: true, [B], [A])
+#list(list[Inner], [Outer])
+
 --- enum-numbering-closure-nested ---
 // Test numbering with closure and nested lists.
 #set enum(numbering: n => super[#n])
@@ -300,3 +300,11 @@ HI#footnote.entry(clearance: 2.5em)[There]
 )
 
 #c
+
+--- figure-html-caption-position html ---
+#figure([Below], caption: [Caption below])
+#figure([Above], caption: figure.caption(position: top)[Caption above])
+#figure([Plain])
+
+--- figure-html-alt html ---
+#figure(alt: "A red square", [Square], caption: [Shapes])
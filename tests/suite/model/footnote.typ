@@ -160,6 +160,19 @@ Ref @fn
     .map(v => upper(v) + footnote(v))
 )
 
+--- footnote-in-table-repeated-header ---
+// Footnotes in repeated headers and footers keep the number of their first
+// occurrence and only produce a single entry.
+#set page(height: 100pt)
+#let note = footnote[Shared]
+#table(
+  columns: 2,
+  table.header[Key #note][Value #footnote[Values]],
+  ..range(12).map(str),
+  table.footer[Sum #footnote[Total]][#note],
+)
+#table(table.header[Other #note], [A])
+
 --- issue-multiple-footnote-in-one-line ---
 // Test that the logic that keeps footnote entry together with
 // their markers also works for multiple footnotes in a single
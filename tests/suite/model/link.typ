@@ -129,6 +129,14 @@ See #metadata(none) <t8>
 --- link-html-here html ---
 #context link(here())[Go]
 
+--- link-html-url-encoding html ---
+// URLs are percent-encoded, but existing escapes are kept.
+#link("資料/報告書 2024.pdf")[報告書]
+
+#link("https://example.com/a%20b?q=日本")[Encoded]
+
+#link("https://example.com/100%")[Percent]
+
 --- link-html-nested-empty html ---
 #[#metadata(none) <a> #metadata(none) <b> Hi] <c>
 
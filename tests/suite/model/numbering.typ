@@ -49,6 +49,7 @@
 // Chinese.
 #t(pat: "一", step: 2, 9, "九", "十一", "十三", "十五", "十七", "十九")
 #t(pat: "壹", step: 2, 9, "玖", "拾壹", "拾叁", "拾伍", "拾柒", "拾玖")
+#t(pat: "壱", "零", "壱", "弐", "参", 10, "壱拾", "壱拾壱", 100, "壱百", 10000, "壱萬")
 
 // Japanese.
 #t(pat: "イ", "-", "イ", "ロ", "ハ", 47, "ス", "イイ", "イロ", "イハ", 2256, "スス", "イイイ")
@@ -87,6 +88,267 @@
 #t(pat: "⓵", 1, "⓵")
 #t(pat: "⓵", 10, "⓾")
 
+// Parenthesized number.
+#t(pat: "⑴", 1, "⑴")
+#t(pat: "⑴", 20, "⒇")
+#t(pat: "⑴", 21, "21")
+#t(pat: "⑴", 0, "0")
+
+// Fullwidth number.
+#t(pat: "１", 0, "０")
+#t(pat: "１", 1, "１")
+#t(pat: "１.", 2024, "２０２４.")
+
 --- numbering-negative ---
 // Error: 17-19 number must be at least zero
 #numbering("1", -1)
+
+--- numbering-roman-without-overline ---
+#test(numbering("I", 5000), "V̅")
+#test(numbering("I", 5000, overline: false), "MMMMM")
+#test(numbering("i", 4444, overline: false), "mmmmcdxliv")
+#test(numbering("I.i", 4000, 1999, overline: false), "MMMM.mcmxcix")
+#test(numbering("1.I", 4000, 4000, overline: false), "4000.MMMM")
+
+--- numbering-roman-unicode ---
+// Roman numeral symbols are used up to 12, regular Roman numerals beyond.
+#test(numbering("Ⅰ", 1), "Ⅰ")
+#test(numbering("Ⅰ", 12), "Ⅻ")
+#test(numbering("Ⅰ", 13), "XIII")
+#test(numbering("(ⅰ)", 4), "(ⅳ)")
+#test(numbering("ⅰ", 40), "xl")
+#test(numbering("ⅰ", 2024), "mmxxiv")
+#test(numbering("Ⅰ.ⅰ", 3, 11), "Ⅲ.ⅺ")
+#test(numbering("Ⅰ", 0), "N")
+
+--- numbering-roman-unicode-without-overline ---
+// In ASCII-only mode, neither Roman numeral symbols nor overlines are used.
+#test(numbering("Ⅰ", 4000), "I̅V̅")
+#test(numbering("Ⅰ", 4, overline: false), "IV")
+#test(numbering("Ⅰ", 4000, overline: false), "MMMM")
+#test(numbering("(ⅰ)", 4001, overline: false), "(mmmmi)")
+#test(numbering("Ⅰ.ⅰ", 12, 5000, overline: false), "XII.mmmmm")
+#test(numbering("ⅰ", 4999, overline: false), "mmmmcmxcix")
+#test(numbering("Ⅰ", 6000, overline: false), "MMMMMM")
+#test(numbering("I", 4, overline: false), "IV")
+#test(numbering("ⅰ", 12345, overline: false), "mmmmmmmmmmmmcccxlv")
+
+--- numbering-repeat-suffix ---
+#test(numbering("1)", 1, 2, 3), "1)2)3)")
+#test(numbering("1)", 1, 2, 3, repeat-suffix: false), "1.2.3)")
+#test(numbering("1", 1, 2, 3, repeat-suffix: false), "1.2.3")
+#test(numbering("1.", 1, 2, 3), "1.2.3.")
+#test(numbering("1.", 1, 2, 3, repeat-suffix: false), "1.2.3.")
+#test(numbering("1.1.1.", 1, 2, 3, 4, 5), "1.2.3.4.5.")
+#test(numbering("1.1.1.", 1, 2, 3, 4, 5, repeat-suffix: false), "1.2.3.4.5.")
+#test(numbering("(1.a)", 1, 2, 3, 4), "(1.b.c.d)")
+#test(numbering("A)", 1, 2, 3, repeat-suffix: false), "A.B.C)")
+// Levels beyond a counting symbol without prefix reuse the last separator.
+#test(numbering("1-A1)", 1, 2, 3, 4), "1-B3)4)")
+#test(numbering("1-A1)", 1, 2, 3, 4, repeat-suffix: false), "1-B3-4)")
+#test(numbering("1.a1)", 1, 2, 3, 4, 5), "1.b3)4)5)")
+#test(numbering("1.a1)", 1, 2, 3, 4, 5, repeat-suffix: false), "1.b3.4.5)")
+
+--- numbering-mixed-case ---
+// Each counting symbol keeps its own case, even when mixed in one pattern.
+#test(numbering("A.a", 1, 2), "A.b")
+#test(numbering("a.A", 1, 2), "a.B")
+#test(numbering("A.a", 27, 28), "AA.ab")
+#test(numbering("A.a.I.i", 1, 2, 3, 4), "A.b.III.iv")
+#test(numbering("A.a", 1, 2, 3, 4), "A.b.c.d")
+#test(numbering("a.A", 1, 2, 3), "a.B.C")
+#test(numbering("A.a.A.a.A", 1, 2, 3, 4, 5, 6), "A.b.C.d.E.F")
+#test(numbering("I.i", 4000, 4000, overline: false), "MMMM.mmmm")
+
+--- numbering-multi-byte-affixes ---
+// Prefixes and suffixes may contain emoji and combining sequences.
+#test(numbering("📖 1.", 3), "📖 3.")
+#test(numbering("👨‍👩‍👧-1-🇯🇵", 12), "👨‍👩‍👧-12-🇯🇵")
+#test(numbering("e\u{301}(a)", 2), "e\u{301}(b)")
+// Combining marks after a counting symbol attach to the number.
+#test(numbering("e\u{301}a\u{308}.", 2), "e\u{301}b\u{308}.")
+#test(numbering("👨‍👩‍👧 1 🇯🇵", 12), "👨‍👩‍👧 12 🇯🇵")
+#test(numbering("1\u{fe0f}\u{20e3}", 4), "4\u{fe0f}\u{20e3}")
+#test(numbering("§ 1.a", 2, 3), "§ 2.c")
+
+--- numbering-multi-byte-combinations ---
+// Patterns mixing counting symbols with emoji, joiners, and combining marks
+// are applied without panicking.
+#let parts = ("", "1", "a", "¹", "👍", "\u{301}", "\u{200d}", "क", "ক", "\u{9be}", ".")
+#for a in parts {
+  for b in parts {
+    for c in parts {
+      let pattern = a + b + c
+      if pattern.contains(regex("[1a¹ক]")) {
+        for n in (0, 1, 27, 4000) {
+          test(type(numbering(pattern, n, n)), str)
+        }
+      }
+    }
+  }
+}
+
+--- numbering-multi-byte-invalid ---
+// Error: 12-30 invalid numbering pattern
+#numbering("\u{301}\u{200d}👍", 1)
+
+--- numbering-symbols ---
+#let fruits = ("🍎", "🍌", "👨‍👩‍👧", "🇯🇵")
+#test(numbering(fruits, 1), "🍎")
+#test(numbering(fruits, 3), "👨‍👩‍👧")
+#test(numbering(fruits, 4), "🇯🇵")
+#test(numbering(fruits, 5), "🍎🍎")
+#test(numbering(fruits, 8), "🇯🇵🇯🇵")
+#test(numbering(fruits, 1, 2, 3), "🍎🍌👨‍👩‍👧")
+
+--- numbering-symbols-empty ---
+// Error: 12-14 array of numbering symbols must not be empty
+#numbering((), 1)
+
+--- numbering-symbols-empty-symbol ---
+// Error: 12-21 numbering symbols must not be empty
+#numbering(("a", ""), 1)
+
+--- numbering-alphabet ---
+#let abc = (alphabet: ("a", "b", "c"))
+#test(numbering(abc, 0), "-")
+#test(numbering(abc, 1), "a")
+#test(numbering(abc, 3), "c")
+#test(numbering(abc, 4), "aa")
+#test(numbering(abc, 12), "cc")
+#test(numbering(abc, 13), "aaa")
+#test(numbering(abc, 1, 2, 3), "abc")
+
+// The result matches the built-in alphabetic numbering.
+#let latin = (alphabet: "abcdefghijklmnopqrstuvwxyz".clusters())
+#for n in (1, 26, 27, 52, 703, 18278) {
+  test(numbering(latin, n), numbering("a", n))
+}
+
+// Symbols may consist of multiple characters.
+#let suits = (alphabet: ("♠", "♥", "👨‍👩‍👧"))
+#test(numbering(suits, 5), "♠♥")
+#test(numbering(suits, 9), "♥👨‍👩‍👧")
+
+// A single symbol results in a unary system.
+#test(numbering((alphabet: ("|",)), 4), "||||")
+
+--- numbering-alphabet-empty ---
+// Error: 12-26 numbering alphabet must not be empty
+#numbering((alphabet: ()), 1)
+
+--- numbering-alphabet-empty-symbol ---
+// Error: 12-33 numbering symbols must not be empty
+#numbering((alphabet: ("a", "")), 1)
+
+--- numbering-alphabet-missing-key ---
+// Error: 12-33 dictionary does not contain key "alphabet"
+#numbering((symbols: ("a", "b")), 1)
+
+--- numbering-ordinal ---
+#context {
+  test(numbering("¹", 1), "1st")
+  test(numbering("¹", 2), "2nd")
+  test(numbering("¹", 3), "3rd")
+  test(numbering("¹", 4), "4th")
+  test(numbering("¹", 11), "11th")
+  test(numbering("¹", 12), "12th")
+  test(numbering("¹", 13), "13th")
+  test(numbering("¹", 21), "21st")
+  test(numbering("¹", 112), "112th")
+  test(numbering("¹", 123), "123rd")
+  test(numbering("¹.¹", 1, 2), "1st.2nd")
+}
+
+--- numbering-ordinal-lang ---
+#set text(lang: "fr")
+#context test(numbering("¹.¹", 1, 2), "1er.2e")
+#set text(lang: "de")
+#context test(numbering("¹", 3), "3.")
+#set text(lang: "ko")
+#context test(numbering("¹", 3), "3")
+
+--- numbering-counting-symbol-affix ---
+// Characters that are counting symbols are not shown literally in patterns,
+// but can still be added with `prefix` and `suffix` or a numbering function.
+#test(numbering("1¹", 2, 3), "23rd")
+#test(numbering("1", 3, suffix: "¹"), "3¹")
+#test(numbering("1", 2, prefix: "〇", suffix: "１"), "〇2１")
+#test(numbering(n => "⑴" + str(n) + "壱", 4), "⑴4壱")
+
+--- numbering-group-digits ---
+// Test grouping the digits of large numbers.
+#test(numbering("1", 1234567, group-digits: true), "1,234,567")
+#test(numbering("1", 123, group-digits: true), "123")
+#test(numbering("1", 1000, group-digits: true), "1,000")
+#test(numbering("1", 1234567), "1234567")
+#test(numbering("1", 1, group-digits: true), "1")
+#test(numbering("1", 123456, group-digits: true), "123,456")
+#test(numbering("١", 1234, group-digits: true), "١٬٢٣٤")
+#test(numbering("۱", 1234567, group-digits: true), "۱٬۲۳۴٬۵۶۷")
+#test(numbering("१", 1234567, group-digits: true), "१२,३४,५६७")
+#test(numbering("1.1", 1000, 2000, group-digits: true), "1,000.2,000")
+#test(numbering("A", 1000, group-digits: true), "ALL")
+
+--- numbering-group-digits-lang ---
+// Digit separators depend on the text language.
+#set text(lang: "de")
+#context test(numbering("1", 1234567, group-digits: true), "1.234.567")
+#set text(lang: "fa")
+#context test(numbering("۱", 1234, group-digits: true), "۱٬۲۳۴")
+
+--- numbering-japanese-kanji ---
+#test(numbering("〇", 0), "〇")
+#test(numbering("〇", 10), "十")
+#test(numbering("〇", 11), "十一")
+#test(numbering("〇", 100), "百")
+#test(numbering("〇", 101), "百一")
+#test(numbering("〇", 1000), "千")
+#test(numbering("〇", 10000), "一万")
+#test(numbering("〇", 23456), "二万三千四百五十六")
+#test(numbering("〇", 100000001), "一億一")
+#test(numbering("第〇章", 12), "第十二章")
+#test(numbering("壱", 3), "参")
+#test(numbering("壱", 10), "壱拾")
+#test(numbering("壱", 123), "壱百弐拾参")
+#test(numbering("壱", 10000), "壱萬")
+#test(numbering("一", 101), "一百零一")
+
+--- numbering-japanese-kanji-lang ---
+// The text language doesn't change the kanji rules.
+#set text(lang: "ja")
+#context test(numbering("一", 101), "一百零一")
+#set text(lang: "zh")
+#context test(numbering("〇", 101), "百一")
+
+--- numbering-affix ---
+#test(numbering("1", 3, prefix: "No. "), "No. 3")
+#test(numbering("1.1", 1, 2, prefix: "§", suffix: "."), "§1.2.")
+#test(numbering(("*", "†"), 2, suffix: ")"), "†)")
+#context test(numbering("1", 3, prefix: (en: "Chapter ", ja: "第")), "Chapter 3")
+#context test(numbering("1", 3, suffix: (ja: "章")), "3")
+#set text(lang: "ja")
+#context test(numbering("1", 3, prefix: (en: "Chapter ", ja: "第"), suffix: (ja: "章")), "第3章")
+#context test(numbering("1", 12, suffix: (en: " pages", ja: " ページ")), "12 ページ")
+
+--- numbering-affix-heading html ---
+#let chapter(..nums) = numbering(
+  "1", ..nums,
+  prefix: (en: "Chapter ", ja: "第"),
+  suffix: (ja: "章"),
+)
+#set heading(numbering: chapter)
+#set text(lang: "ja")
+= 序論
+#set text(lang: "en")
+= Methods
+
+--- numbering-affix-invalid-lang ---
+// Error: 28-43 expected two or three letter language code (ISO 639-1/2/3)
+#numbering("1", 1, prefix: (japanese: "第"))
+
+--- numbering-affix-no-context ---
+// Error: 2-38 can only be used when context is known
+// Hint: 2-38 try wrapping this in a `context` expression
+// Hint: 2-38 the `context` expression should wrap everything that depends on this function
+#numbering("1", 1, prefix: (ja: "第"))
@@ -277,6 +277,14 @@ A
 #set text(lang: "de")
 #figure([C], caption: figure.caption(separator: " ~ ")[Das C])
 
+--- outline-html-nav-label html ---
+#set text(lang: "ja")
+#outline(title: none)
+
+= 序論
+== 背景
+= 結論
+
 --- issue-2048-outline-multiline ---
 // Without the word joiner between the dots and the page number,
 // the page number would be alone in its line.
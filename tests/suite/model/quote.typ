@@ -110,6 +110,13 @@ When you said that #quote[he surely meant that #quote[she intended to say #quote
   Compose papers faster
 ]
 
+--- quote-html-inline-block html ---
+Inline: #quote(attribution: link("https://typst.app/home")[typst.app])[Compose papers faster]
+
+Unquoted: #quote(quotes: false)[plain]
+
+#quote(block: true, quotes: true)[Quoted block]
+
 --- quote-par ---
 // Ensure that an inline quote is part of a paragraph, but a block quote
 // does not result in paragraphs.
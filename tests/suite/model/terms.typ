@@ -116,3 +116,14 @@ Not in list
 / Term A: 1
 / Term B: / Term C: 2
           / Term D: 3
+
+--- terms-html-multiple-descriptions html ---
+/ Apple: A fruit.
+/ Apple: A company.
+/ Pear: Another fruit.
+
+--- terms-html-nested html ---
+#terms(
+  ([Fruits], terms(([Apple], [Red.]), ([Pear], [Green.]))),
+  ([Vegetables], [None yet.]),
+)
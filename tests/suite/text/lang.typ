@@ -83,3 +83,16 @@
 // Error: 17-24 expected two or three letter language code (ISO 639-1/2/3)
 // Hint: 17-24 you should leave only "en" in the `lang` parameter and specify "gb" in the `region` parameter
 #set text(lang: "en-gb")
+
+--- text-dir-html html ---
+// Right-to-left paragraphs and headings are marked with a `dir` attribute.
+#set text(lang: "ar")
+= مقدمة
+هذا عربي.
+
+#set text(lang: "en")
+= Introduction
+This is English.
+
+#set text(dir: rtl)
+Explicitly right to left.
@@ -521,6 +521,15 @@ This is ```typ *inline*```.
 ]
 ```
 
+--- raw-html-language-class html ---
+// Without a theme, only the language class is emitted for client-side
+// highlighting.
+#set raw(theme: none)
+Inline `x + 1` and ```rs let x = 1;```.
+```py
+x = 1 + 2
+```
+
 --- raw-html-inline-spaces html ---
 This has `double  spaces  inside`, which should be kept.
 
@@ -181,3 +181,32 @@
     curve.line((10pt, 10pt)),
   ),
 )
+
+--- curve-markers ---
+#let c = curve(
+  start-cap: "circle",
+  end-cap: "arrow",
+  curve.move((0pt, 10pt)),
+  curve.line((20pt, 10pt)),
+)
+#test(c.start-cap, "circle")
+#test(c.end-cap, "arrow")
+#test(curve(curve.line((10pt, 0pt))).end-cap, none)
+
+--- curve-markers-render ---
+// Markers follow the direction of the curve at its ends.
+#curve(
+  start-cap: "circle",
+  end-cap: "arrow",
+  stroke: 1.5pt,
+  curve.move((0pt, 30pt)),
+  curve.cubic((20pt, -10pt), (50pt, 50pt), (70pt, 10pt)),
+)
+#curve(
+  start-cap: "bar",
+  end-cap: "arrow",
+  stroke: blue,
+  curve.move((0pt, 0pt)),
+  curve.line((30pt, 20pt)),
+  curve.line((60pt, 0pt)),
+)
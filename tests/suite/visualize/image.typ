@@ -124,6 +124,37 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
   ```.text
 ))
 
+--- image-svg-linked-no-resolve ---
+// Linked images are left out if href resolution is disabled, so that a missing
+// file does not produce an error.
+#let svg = bytes(
+  ```
+  <svg xmlns="http://www.w3.org/2000/svg" width="10" height="10">
+    <image href="do-not-add-image-with-this-name.png" width="10" height="10" />
+  </svg>
+  ```.text
+)
+#context test(measure(image(svg, width: 10pt, resolve-hrefs: false)).width, 10pt)
+#set image(resolve-hrefs: false)
+#context test(measure(image(svg, width: 10pt)).width, 10pt)
+
+--- image-svg-linked-no-resolve-url ---
+// URLs are not an error either if href resolution is disabled.
+#context test(
+  measure(image(
+    bytes(
+      ```
+      <svg xmlns="http://www.w3.org/2000/svg" width="10" height="10">
+        <image href="https://somedomain.com/image.png" />
+      </svg>
+      ```.text
+    ),
+    width: 10pt,
+    resolve-hrefs: false,
+  )).width,
+  10pt,
+)
+
 --- image-svg-linked-url ---
 // Error: 8-7:2 failed to load linked image https://somedomain.com/image.png in SVG (URLs are not allowed)
 #image(bytes(
@@ -204,6 +235,19 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
   width: 1cm,
 )
 
+--- image-background ---
+// Transparent pixels are composited onto the background, over a pattern that
+// would otherwise shine through.
+#let data = bytes((
+  0xFF, 0x00, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0x00, 0xFF, 0xFF,
+  0xFF, 0x00, 0x00, 0x80, 0x00, 0xFF, 0x00, 0x80, 0x00, 0x00, 0xFF, 0x80,
+  0xFF, 0x00, 0x00, 0x10, 0x00, 0xFF, 0x00, 0x10, 0x00, 0x00, 0xFF, 0x10,
+))
+#let format = (encoding: "rgba8", width: 3, height: 3)
+#set image(width: 1cm, scaling: "pixelated")
+#box(fill: black, image(data, format: format))
+#box(fill: black, image(data, format: format, background: yellow))
+
 --- image-pixmap-luma8 ---
 #image(
   bytes(range(16).map(x => x * 16)),
@@ -272,6 +316,28 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 // Error: 8-29 file not found (searched at tests/suite/visualize/path/does/not/exist)
 #image("path/does/not/exist")
 
+--- image-fallback-file-not-found ---
+#context test(
+  measure(image("path/does/not/exist.png", fallback: rect(width: 10pt))).width,
+  10pt,
+)
+
+--- image-fallback-decode-error ---
+#set image(fallback: box(width: 7pt, height: 3pt))
+#context test(measure(image(bytes("not an image"), format: "png")).width, 7pt)
+#context test(measure(image("/assets/images/f2t.jpg")).width > 7pt, true)
+
+--- image-fallback-file-not-found-set-rule ---
+#set image(fallback: rect(width: 5pt))
+#context test(measure(image("path/does/not/exist.png")).width, 5pt)
+
+--- image-fallback-none ---
+// Error: 8-29 file not found (searched at tests/suite/visualize/path/does/not/exist)
+#image("path/does/not/exist", fallback: none)
+
+--- image-fallback-html html ---
+#image("path/does/not/exist.png", fallback: [Missing image])
+
 --- image-bad-format ---
 // Error: 2-37 unknown image format
 #image("/assets/plugins/hello.wasm")
@@ -362,6 +428,32 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
   format: "rgba8",
 )
 
+--- image-intent ---
+#let img = image("/assets/images/tiger.jpg", intent: "relative-colorimetric")
+#test(img.intent, "relative-colorimetric")
+#test(image("/assets/images/tiger.jpg").has("intent"), false)
+
+--- image-intent-render render ---
+// The perceptual and colorimetric intents map the dark and saturated colors
+// differently, so the two images should not look the same.
+#let icc = read("/crates/typst-pdf/src/icc/sRGB-v4.icc", encoding: none)
+#let pixels = bytes((
+  0xFF, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0xFF,
+  0x20, 0x10, 0x10, 0x10, 0x20, 0x10, 0x10, 0x10, 0x20,
+  0x08, 0x08, 0x08, 0x40, 0x40, 0x40, 0xF0, 0xF0, 0xF0,
+))
+#let format = (encoding: "rgb8", width: 3, height: 3)
+#set image(width: 1cm, icc: icc, scaling: "pixelated")
+#image(pixels, format: format, intent: "perceptual")
+#image(pixels, format: format, intent: "absolute-colorimetric")
+
+--- image-intent-invalid ---
+#image(
+  "/assets/images/tiger.jpg",
+  // Error: 11-21 expected "perceptual", "relative-colorimetric", "absolute-colorimetric", "saturation", or auto
+  intent: "colorful",
+)
+
 --- issue-measure-image ---
 // Test that image measurement doesn't turn `inf / some-value` into 0pt.
 #context {
@@ -417,6 +509,78 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 // Hint: 2-49 the document only has 1 page
 #image("/assets/images/matplotlib.pdf", page: 2)
 
+--- image-gif-frame ---
+// A 1x1 GIF with a red and a blue frame.
+#let gif = bytes((
+  0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+  0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x21, 0xF9, 0x04, 0x00, 0x0A,
+  0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+  0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x21, 0xF9, 0x04, 0x00, 0x0A, 0x00,
+  0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00,
+  0x02, 0x02, 0x4C, 0x01, 0x00, 0x3B,
+))
+#context test(measure(image(gif, frame: 2, width: 10pt)).width, 10pt)
+
+--- image-gif-invalid-frame ---
+#let gif = bytes((
+  0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0x80, 0x00,
+  0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x21, 0xF9, 0x04, 0x00, 0x0A,
+  0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+  0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x21, 0xF9, 0x04, 0x00, 0x0A, 0x00,
+  0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00,
+  0x02, 0x02, 0x4C, 0x01, 0x00, 0x3B,
+))
+// Error: 2-22 frame 3 does not exist
+// Hint: 2-22 the image only has 2 frames
+#image(gif, frame: 3)
+
+--- image-jpg-invalid-frame ---
+// Error: 2-45 frame 2 does not exist
+// Hint: 2-45 the image only has 1 frame
+#image("/assets/images/tiger.jpg", frame: 2)
+
 --- issue-6869-image-zero-sized ---
 // Primarily to ensure that it does not crash in PDF export.
 #image("/assets/images/f2t.jpg", width: 0pt, height: 0pt)
+
+--- image-info-raster ---
+// f2t.jpg is 48x80 at 220dpi.
+#let info = image.info("/assets/images/f2t.jpg")
+#test(info.width, 48.0)
+#test(info.height, 80.0)
+#test(info.dpi, 220.0)
+#test(info.format, "jpg")
+#test(info.pages, none)
+
+--- image-info-pixels ---
+#let info = image.info(
+  bytes(range(24)),
+  format: (encoding: "rgb8", width: 4, height: 2),
+)
+#test(info.width, 4.0)
+#test(info.height, 2.0)
+#test(info.dpi, none)
+#test(info.format, (encoding: "rgb8", width: 4, height: 2))
+#test(info.pages, none)
+
+--- image-info-svg ---
+#let info = image.info(bytes(
+  `<svg xmlns="http://www.w3.org/2000/svg" width="30" height="20"></svg>`.text
+))
+#test(info.width, 30.0)
+#test(info.height, 20.0)
+#test(info.dpi, 96.0)
+#test(info.format, "svg")
+#test(info.pages, none)
+
+--- image-info-pdf ---
+#let info = image.info("/assets/images/matplotlib.pdf")
+#test(info.format, "pdf")
+#test(info.dpi, 72.0)
+#test(info.pages, 1)
+#test(type(info.width), float)
+#test(image.info("/assets/images/diagrams.pdf").pages >= 3, true)
+
+--- image-info-bad-format ---
+// Error: 2-42 unknown image format
+#image.info("/assets/plugins/hello.wasm")
@@ -95,3 +95,21 @@
 --- line-infinite-length ---
 // Error: 2-54 cannot create line with infinite length
 #line(start: (0pt, 0pt), end: (float.inf * 1pt, 0pt))
+
+--- line-markers ---
+#test(line(end-cap: "arrow").end-cap, "arrow")
+#test(line(start-cap: "circle").start-cap, "circle")
+#test(line().start-cap, none)
+#set line(start-cap: "bar")
+#context test(line.start-cap, "bar")
+
+--- line-markers-render ---
+// Markers at the ends of lines scale with the stroke thickness.
+#set line(length: 80%)
+#line(end-cap: "arrow")
+#line(start-cap: "circle", end-cap: "bar", stroke: 2pt + blue)
+#line(start-cap: "arrow", end-cap: "arrow", angle: 20deg, stroke: 1pt + red)
+
+--- line-markers-invalid ---
+// Error: 16-26 expected "arrow", "circle", "bar", or none
+#line(end-cap: "triangle")
@@ -16,17 +16,24 @@
 #test(stroke(cap: "round", thickness: auto).thickness, auto)
 
 --- stroke-constructor-unknown-key ---
-// Error: 9-21 unexpected key "foo", valid keys are "paint", "thickness", "cap", "join", "dash", and "miter-limit"
+// Error: 9-21 unexpected key "foo", valid keys are "paint", "paint-anchor", "gradient-along-path", "thickness", "cap", "join", "join-radius", "dash", "gap-paint", "double", "double-gap", "miter-limit", and "anti-alias"
 #stroke((foo: "bar"))
 
 --- stroke-fields-simple ---
 // Test stroke fields for simple strokes.
 #test((1em + blue).paint, blue)
+#test((1em + blue).paint-anchor, auto)
+#test((1em + blue).gradient-along-path, auto)
 #test((1em + blue).thickness, 1em)
 #test((1em + blue).cap, auto)
 #test((1em + blue).join, auto)
+#test((1em + blue).join-radius, auto)
 #test((1em + blue).dash, auto)
+#test((1em + blue).gap-paint, auto)
+#test((1em + blue).double, auto)
+#test((1em + blue).double-gap, auto)
 #test((1em + blue).miter-limit, auto)
+#test((1em + blue).anti-alias, auto)
 
 --- stroke-fields-complex ---
 // Test complex stroke fields.
@@ -90,6 +97,228 @@
 
 #text(stroke: 1pt + gradient.linear(..color.map.rainbow), v)
 
+--- stroke-hairline ---
+// Test hairline strokes.
+#test(stroke(thickness: "hairline").thickness, "hairline")
+#test(stroke((paint: red, thickness: "hairline")).thickness, "hairline")
+#test(stroke((paint: red, thickness: "hairline")).paint, red)
+#test(repr(stroke(thickness: "hairline")), "(thickness: \"hairline\")")
+#test(stroke(thickness: "hairline") == stroke(thickness: 0pt), false)
+
+--- stroke-hairline-render ---
+// Hairlines stay one device pixel wide regardless of the zoom level.
+#set line(length: 100%)
+#line(stroke: (thickness: "hairline"))
+#line(stroke: (paint: blue, thickness: "hairline", dash: "dashed"))
+#rect(width: 50%, height: 20pt, stroke: (thickness: "hairline"))
+
+--- stroke-dash-ratio ---
+// Test dash lengths relative to the length of the stroked path.
+#test(stroke(dash: (50%, 50%)).dash, (array: (50%, 50%), phase: 0pt))
+#test(stroke(dash: (10%, 2pt, "dot", 1em)).dash, (array: (10%, 2pt, "dot", 1em), phase: 0pt))
+#test(
+  repr(stroke(dash: (array: (25%, "dot"), phase: 1pt))),
+  "(dash: (array: (25%, \"dot\"), phase: 1pt))",
+)
+
+--- stroke-dash-phase-ratio ---
+// Test dash phases relative to the length of the stroked path.
+#test(stroke(dash: (array: (10pt, 5pt), phase: 25%)).dash.phase, 25%)
+#test(stroke(dash: (array: (10pt, 5pt), phase: 10% + 2pt)).dash.phase, 10% + 2pt)
+#test(stroke(dash: (array: (10pt, 5pt), phase: 2pt)).dash.phase, 2pt)
+#test(
+  repr(stroke(dash: (array: (20%, 5pt), phase: 50%))),
+  "(dash: (array: (20%, 5pt), phase: 50%))",
+)
+
+--- stroke-dash-ratio-render ---
+// Relative dash lengths and phases are resolved against the length of each
+// stroked path, after dots became the line width.
+#set line(length: 100%)
+#line(stroke: (dash: (10%, 10%)))
+#line(stroke: (thickness: 2pt, dash: (10%, 2pt, "dot", 1em)))
+#line(stroke: (dash: (array: (20%, 5%), phase: 10%)))
+#rect(width: 50%, height: 20pt, stroke: (dash: (12.5%, 12.5%)))
+// An odd pattern is repeated: (10pt, 50%, "dot") on a 2pt thick line.
+#line(length: 100pt, stroke: (thickness: 2pt, dash: (10pt, 50%, "dot")))
+
+--- stroke-dash-ratio-text ---
+// Error: 15-34 text strokes cannot have relative dash lengths
+// Hint: 15-34 use absolute lengths like `2pt` instead
+#text(stroke: (dash: (50%, 2pt)))[A]
+
+--- stroke-dash-phase-ratio-text ---
+// Error: 19-58 text strokes cannot have relative dash lengths
+// Hint: 19-58 use absolute lengths like `2pt` instead
+#set text(stroke: (dash: (array: (2pt, 1pt), phase: 10%)))
+
+--- stroke-dash-phase-invalid ---
+// Error: 15-38 expected relative length, found string
+#stroke(dash: (array: (), phase: "a"))
+
+--- stroke-join-radius ---
+// Test the radius of round joins.
+#test(stroke(join: "round", join-radius: 5pt).join-radius, 5pt)
+#test(stroke((join: "round", join-radius: 1em)).join-radius, 1em)
+#test(stroke(join: "round").join-radius, auto)
+#test(
+  repr(stroke(join: "round", join-radius: 5pt)),
+  "(join: \"round\", join-radius: 5pt)",
+)
+#test(rect(stroke: (join-radius: 2pt)).stroke.join-radius, 2pt)
+
+--- stroke-join-radius-render ---
+// Round joins with an explicit radius and with the default half width.
+#let zigzag(stroke) = curve(
+  stroke: stroke,
+  curve.move((0pt, 20pt)),
+  curve.line((15pt, 0pt)),
+  curve.line((30pt, 20pt)),
+  curve.line((45pt, 0pt)),
+)
+#stack(
+  dir: ltr,
+  spacing: 10pt,
+  zigzag((thickness: 6pt, join: "round")),
+  zigzag((thickness: 6pt, join: "round", join-radius: 1pt)),
+  zigzag((thickness: 6pt, join: "round", join-radius: 2.5pt)),
+)
+
+--- stroke-gap-paint ---
+// Test the paint for the gaps of dashed strokes.
+#test(stroke(dash: "dashed", gap-paint: red).gap-paint, red)
+#test(stroke((dash: "dotted", gap-paint: blue)).gap-paint, blue)
+#test(stroke(dash: "dashed").gap-paint, auto)
+#test(
+  repr(stroke(dash: (4pt, 4pt), gap-paint: rgb("#ff0000"))),
+  "(dash: (array: (4pt, 4pt), phase: 0pt), gap-paint: rgb(\"#ff0000\"))",
+)
+#test(rect(stroke: (gap-paint: green)).stroke.gap-paint, green)
+
+--- stroke-gap-paint-render ---
+// The gaps of dashed strokes are filled with the gap paint.
+#set line(length: 100%)
+#line(stroke: (thickness: 2pt, dash: "dashed", gap-paint: red))
+#line(stroke: (thickness: 2pt, paint: blue, dash: "dotted", gap-paint: yellow))
+#rect(
+  width: 50%,
+  height: 20pt,
+  stroke: (thickness: 2pt, dash: (4pt, 2pt), gap-paint: green),
+)
+
+--- stroke-double ---
+// Test double strokes.
+#test(stroke(double: true).double, true)
+#test(stroke((double: true, double-gap: 2pt)).double-gap, 2pt)
+#test(stroke(double: true).double-gap, auto)
+#test(
+  repr(stroke(thickness: 1pt, double: true, double-gap: 0.5em)),
+  "(thickness: 1pt, double: true, double-gap: 0.5em)",
+)
+#test(line(stroke: (double: true)).stroke.double, true)
+
+--- stroke-double-render ---
+// Double strokes on lines and rectangles.
+#set line(length: 100%)
+#line(stroke: (thickness: 3pt, double: true))
+#line(stroke: (thickness: 3pt, paint: blue, double: true, double-gap: 2pt))
+#rect(width: 50%, height: 20pt, stroke: (thickness: 3pt, double: true))
+// The fill stays inside the inner line.
+#rect(width: 50%, height: 20pt, fill: aqua, stroke: (thickness: 1pt, double: true))
+
+--- stroke-double-invalid ---
+// Error: 17-22 expected boolean or auto, found string
+#stroke(double: "yes")
+
+--- stroke-double-unsupported ---
+// Warning: 2-45 double strokes are only supported on lines and rectangles
+// Hint: 2-45 this shape is stroked with a single line instead
+#circle(radius: 5pt, stroke: (double: true))
+// Warning: 2-43 double strokes are only supported on lines and rectangles
+// Hint: 2-43 this shape is stroked with a single line instead
+#rect(radius: 2pt, stroke: (double: true))
+
+--- stroke-paint-anchor ---
+// Test the placement of tiling and gradient paints on strokes.
+#let pat = tiling(size: (4pt, 4pt), square(size: 2pt, fill: red))
+#test(stroke(paint: pat, paint-anchor: "along").paint-anchor, "along")
+#test(stroke((paint: pat, paint-anchor: "fixed")).paint-anchor, "fixed")
+#test(stroke(paint: pat).paint-anchor, auto)
+#test(
+  repr(stroke(paint: red, paint-anchor: "along", thickness: 2pt)),
+  "(paint: rgb(\"#ff4136\"), paint-anchor: \"along\", thickness: 2pt)",
+)
+#test(line(stroke: (paint-anchor: "fixed")).stroke.paint-anchor, "fixed")
+
+--- stroke-paint-anchor-render ---
+// Tiling paints placed relative to the page and along the stroked path.
+#let pat = tiling(size: (4pt, 4pt), square(size: 2pt, fill: red))
+#set line(length: 100%)
+#line(stroke: (paint: pat, thickness: 4pt, paint-anchor: "fixed"))
+#line(stroke: (paint: pat, thickness: 4pt, paint-anchor: "along"))
+#circle(radius: 15pt, stroke: (paint: pat, thickness: 4pt, paint-anchor: "along"))
+
+--- stroke-paint-anchor-invalid ---
+// Error: 23-31 expected "along", "fixed", or auto
+#stroke(paint-anchor: "middle")
+
+--- stroke-gradient-along-path ---
+// Test applying gradients along the stroked path.
+#let rainbow = gradient.linear(..color.map.rainbow)
+#test(stroke(paint: rainbow, gradient-along-path: true).gradient-along-path, true)
+#test(stroke((paint: rainbow, gradient-along-path: false)).gradient-along-path, false)
+#test(stroke(paint: rainbow).gradient-along-path, auto)
+#test(
+  repr(stroke(paint: red, gradient-along-path: true, thickness: 2pt)),
+  "(paint: rgb(\"#ff4136\"), gradient-along-path: true, thickness: 2pt)",
+)
+#test(line(stroke: (gradient-along-path: true)).stroke.gradient-along-path, true)
+
+--- stroke-gradient-along-path-render ---
+// The gradient follows short and long, open and closed paths alike.
+#let along = (
+  paint: gradient.linear(..color.map.rainbow),
+  thickness: 4pt,
+  gradient-along-path: true,
+)
+#set page(width: 120pt, height: auto)
+#line(length: 8pt, stroke: along)
+#line(length: 100%, stroke: along + (cap: "round"))
+#curve(
+  stroke: along + (join: "round"),
+  curve.move((0pt, 20pt)),
+  curve.cubic((30pt, -10pt), (60pt, 50pt), (100pt, 10pt)),
+  curve.line((40pt, 40pt)),
+)
+#circle(radius: 20pt, stroke: along)
+#rect(width: 40pt, height: 40pt, fill: aqua, stroke: along)
+
+--- stroke-gradient-along-path-invalid ---
+// Error: 30-35 expected boolean or auto, found string
+#stroke(gradient-along-path: "yes")
+
+--- stroke-anti-alias ---
+// Test disabling anti-aliasing for strokes.
+#test(stroke(anti-alias: false).anti-alias, false)
+#test(stroke((paint: red, anti-alias: true)).anti-alias, true)
+#test(stroke(paint: red).anti-alias, auto)
+#test(
+  repr(stroke(paint: red, thickness: 1pt, anti-alias: false)),
+  "(paint: rgb(\"#ff4136\"), thickness: 1pt, anti-alias: false)",
+)
+#test(line(stroke: (anti-alias: false)).stroke.anti-alias, false)
+
+--- stroke-anti-alias-render ---
+// Diagonal strokes with and without anti-aliasing.
+#set line(length: 100%, angle: 10deg)
+#line(stroke: (thickness: 2pt))
+#line(stroke: (thickness: 2pt, anti-alias: false))
+#circle(radius: 15pt, stroke: (thickness: 2pt, anti-alias: false))
+
+--- stroke-anti-alias-invalid ---
+// Error: 21-26 expected boolean or auto, found string
+#stroke(anti-alias: "off")
+
 --- stroke-folding ---
 // Test stroke folding.
 #let sq(..args) = box(square(size: 10pt, ..args))